@@ -0,0 +1,114 @@
+//! Example of a server with managed parameters using the Foxglove SDK.
+//!
+//! Usage:
+//! ```text
+//! cargo run -p example-param-store
+//! ```
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use foxglove::websocket::{ParameterDescriptor, ParameterStore, ParameterValue};
+use foxglove::WebSocketServer;
+use tokio_util::sync::CancellationToken;
+
+#[derive(Debug, Parser)]
+struct Cli {
+    #[arg(short, long, default_value_t = 8765)]
+    port: u16,
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+    /// JSON file to load parameters from, and save them to when they change.
+    #[arg(long)]
+    params_file: Option<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() {
+    let env = env_logger::Env::default().default_filter_or("debug");
+    env_logger::init_from_env(env);
+
+    let args = Cli::parse();
+
+    // Declare parameters with their defaults. Values set by clients are validated against the
+    // declared type and range.
+    let store = ParameterStore::new();
+    store
+        .declare(
+            ParameterDescriptor::new("gain", 1.0)
+                .with_description("Controller gain")
+                .with_range(0.0, 10.0),
+        )
+        .expect("Failed to declare parameter");
+    store
+        .declare(ParameterDescriptor::new("enabled", true))
+        .expect("Failed to declare parameter");
+    store
+        .declare(ParameterDescriptor::new("weights", vec![1.0, 2.0, 3.0]).with_range(0.0, 1e3))
+        .expect("Failed to declare parameter");
+    store
+        .declare(ParameterDescriptor::new("elapsed", 0.0).with_read_only(true))
+        .expect("Failed to declare parameter");
+
+    // Restore the values from a previous session, and persist changes made by clients.
+    if let Some(path) = args.params_file {
+        if path.exists() {
+            store.load(&path).expect("Failed to load parameters");
+        }
+        store
+            .save_on_change(path)
+            .expect("Failed to configure parameter file");
+    }
+
+    let server = WebSocketServer::new()
+        .name("param store")
+        .parameter_store(store.clone())
+        .bind(args.host, args.port)
+        .start()
+        .await
+        .expect("Failed to start server");
+
+    let shutdown = watch_ctrl_c();
+    tokio::select! {
+        () = shutdown.cancelled() => (),
+        () = watch_gain(&store) => (),
+        () = update_elapsed(&store) => (),
+    };
+
+    server.stop().await;
+}
+
+/// Prints the gain whenever it is changed by a client.
+async fn watch_gain(store: &ParameterStore) {
+    let mut gain = store.watch("gain").expect("gain is declared");
+    while gain.changed().await.is_ok() {
+        if let ParameterValue::Number(value) = *gain.borrow_and_update() {
+            println!("gain changed to {value}");
+        }
+    }
+}
+
+/// Publishes the elapsed time to subscribed clients.
+async fn update_elapsed(store: &ParameterStore) {
+    let start = Instant::now();
+    let mut interval = tokio::time::interval(Duration::from_secs(1));
+    loop {
+        interval.tick().await;
+        store
+            .set("elapsed", start.elapsed().as_secs_f64())
+            .expect("Failed to set parameter");
+    }
+}
+
+fn watch_ctrl_c() -> CancellationToken {
+    let token = CancellationToken::new();
+    tokio::spawn({
+        let token = token.clone();
+        async move {
+            tokio::signal::ctrl_c().await.ok();
+            token.cancel();
+        }
+    });
+    token
+}
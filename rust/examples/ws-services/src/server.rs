@@ -47,6 +47,17 @@ pub async fn main(config: Config) -> Result<()> {
         ])
         .context("Failed to register services")?;
 
+    // Async handlers are spawned on the server's runtime. Calls that exceed the timeout are
+    // failed automatically, and the handler's future is dropped.
+    server
+        .add_services([Service::builder("/sleep_async", empty_schema())
+            .timeout(Duration::from_millis(500))
+            .async_handler_fn(|_, _| async {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                anyhow::Ok(Bytes::new())
+            })])
+        .context("Failed to register services")?;
+
     // A single handler function can be shared by multiple services.
     server
         .add_services(
@@ -58,6 +69,21 @@ pub async fn main(config: Config) -> Result<()> {
         )
         .context("Failed to register services")?;
 
+    // Services with typed requests and responses derive their schemas from the request and
+    // response types, and take care of decoding requests and encoding responses.
+    server
+        .add_services([Service::typed_builder::<IntBinRequest, IntBinResponse>(
+            "/IntBin/div",
+            "/custom_srvs/IntBinOps",
+        )
+        .handler_fn(|req| {
+            req.a
+                .checked_div(req.b)
+                .map(|result| IntBinResponse { result })
+                .ok_or("division by zero")
+        })])
+        .context("Failed to register services")?;
+
     // A stateful handler might be written as a type that implements `Handler` (or `SyncHandler`).
     let flag_a = Flag::default();
     let flag_b = Flag::default();
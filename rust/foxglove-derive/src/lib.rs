@@ -0,0 +1,198 @@
+//! Derive macros for the [Foxglove SDK](https://docs.rs/foxglove).
+//!
+//! These macros are re-exported by the `foxglove` crate when the `derive` feature is enabled, and
+//! should be used through it, e.g. `#[derive(foxglove::Encode)]`.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Implements `foxglove::Encode` for a struct, encoding it as a protobuf message.
+///
+/// See the `foxglove::protobuf` module for the supported field types.
+#[proc_macro_derive(Encode)]
+pub fn derive_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_encode(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_encode(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Encode can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Encode can only be derived for structs",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let name_str = name.unraw().to_string();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut descriptor_fields = vec![];
+    let mut encode_fields = vec![];
+    let mut encoded_len_fields = vec![];
+    for (index, field) in fields.iter().enumerate() {
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let field_name = ident.unraw().to_string();
+        let tag = u32::try_from(index + 1).expect("too many fields");
+        let number = tag as i32;
+        descriptor_fields.push(quote! {
+            .field::<#ty>(#field_name, #number, files)
+        });
+        encode_fields.push(quote! {
+            ::foxglove::protobuf::ProtobufField::encode(&self.#ident, #tag, buf);
+        });
+        encoded_len_fields.push(quote! {
+            + ::foxglove::protobuf::ProtobufField::encoded_len(&self.#ident, #tag)
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::foxglove::protobuf::ProtobufMessage for #name #ty_generics #where_clause {
+            fn type_name() -> ::std::string::String {
+                ::foxglove::protobuf::MessageDescriptor::new(::std::module_path!(), #name_str)
+                    .type_name()
+            }
+
+            fn file_name() -> ::std::string::String {
+                ::foxglove::protobuf::MessageDescriptor::new(::std::module_path!(), #name_str)
+                    .file_name()
+            }
+
+            fn file_descriptors(
+                files: &mut ::std::vec::Vec<::foxglove::protobuf::FileDescriptorProto>,
+            ) {
+                let Some(descriptor) =
+                    ::foxglove::protobuf::MessageDescriptor::new(::std::module_path!(), #name_str)
+                        .begin(files)
+                else {
+                    return;
+                };
+                descriptor
+                    #(#descriptor_fields)*
+                    .build(files);
+            }
+
+            fn encode_raw(&self, buf: &mut impl ::foxglove::protobuf::BufMut) {
+                #(#encode_fields)*
+            }
+
+            fn encoded_len(&self) -> usize {
+                0 #(#encoded_len_fields)*
+            }
+        }
+
+        impl #impl_generics ::foxglove::Encode for #name #ty_generics #where_clause {
+            type Error = ::foxglove::protobuf::ProtobufError;
+
+            fn get_schema() -> ::std::option::Option<::foxglove::Schema> {
+                ::std::option::Option::Some(::foxglove::protobuf::schema::<Self>())
+            }
+
+            fn get_message_encoding() -> ::std::string::String {
+                ::std::string::String::from("protobuf")
+            }
+
+            fn encode(
+                &self,
+                buf: &mut impl ::foxglove::protobuf::BufMut,
+            ) -> ::std::result::Result<(), Self::Error> {
+                ::foxglove::protobuf::encode(self, buf)
+            }
+
+            fn encoded_len(&self) -> ::std::option::Option<usize> {
+                ::std::option::Option::Some(
+                    <Self as ::foxglove::protobuf::ProtobufMessage>::encoded_len(self),
+                )
+            }
+        }
+    })
+}
+
+/// Implements `foxglove::point_cloud::PackedPoint` for a struct, packing its fields in order.
+///
+/// See the `foxglove::point_cloud` module for the supported field types.
+#[proc_macro_derive(PackedPoint)]
+pub fn derive_packed_point(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_packed_point(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_packed_point(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "PackedPoint can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "PackedPoint can only be derived for structs",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut layout_fields = vec![];
+    let mut write_fields = vec![];
+    let mut read_fields = vec![];
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let field_name = ident.unraw().to_string();
+        layout_fields.push(quote! {
+            (#field_name, <#ty as ::foxglove::point_cloud::PackedScalar>::TYPE)
+        });
+        write_fields.push(quote! {
+            writer.write::<#ty>(self.#ident);
+        });
+        read_fields.push(quote! {
+            #ident: reader.read::<#ty>(),
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::foxglove::point_cloud::PackedPoint for #name #ty_generics #where_clause {
+            const FIELDS: &'static [(
+                &'static str,
+                ::foxglove::schemas::packed_element_field::NumericType,
+            )] = &[#(#layout_fields),*];
+
+            fn write_fields(&self, writer: &mut ::foxglove::point_cloud::FieldWriter<'_>) {
+                #(#write_fields)*
+            }
+
+            fn read_fields(reader: &mut ::foxglove::point_cloud::FieldReader<'_>) -> Self {
+                Self {
+                    #(#read_fields)*
+                }
+            }
+        }
+    })
+}
@@ -3,6 +3,14 @@ use std::path::Path;
 fn main() {
     let workspace_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("..");
     let proto_path = workspace_root.join("schemas").join("proto");
+    let fbs_path = workspace_root.join("schemas").join("flatbuffer");
+    let jsonschema_path = workspace_root.join("schemas").join("jsonschema");
+    let omgidl_path = workspace_root
+        .join("schemas")
+        .join("omgidl")
+        .join("foxglove");
+    let ros1_path = workspace_root.join("schemas").join("ros1");
+    let ros2_path = workspace_root.join("schemas").join("ros2");
     let out_path = workspace_root
         .join("rust")
         .join("foxglove")
@@ -10,4 +18,12 @@ fn main() {
         .join("schemas");
 
     foxglove_proto_gen::generate_protos(&proto_path, &out_path).expect("Failed to generate protos");
+    foxglove_proto_gen::generate_flatbuffers(&fbs_path, &out_path)
+        .expect("Failed to generate flatbuffers");
+    foxglove_proto_gen::generate_json(&jsonschema_path, &out_path)
+        .expect("Failed to generate json");
+    foxglove_proto_gen::generate_omgidl(&omgidl_path, &out_path)
+        .expect("Failed to generate omgidl");
+    foxglove_proto_gen::generate_ros1(&ros1_path, &out_path).expect("Failed to generate ros1");
+    foxglove_proto_gen::generate_ros2(&ros2_path, &out_path).expect("Failed to generate ros2");
 }
@@ -0,0 +1,154 @@
+//! Generates FlatBuffers tables and binary schemas for the well-known schemas with `flatc`.
+
+use std::ffi::OsStr;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{ensure, Context};
+use walkdir::WalkDir;
+
+/// The namespace of the foxglove schemas.
+const NAMESPACE: &str = "foxglove";
+
+/// Root tables which are not message schemas.
+const SKIPPED_TABLES: &[&str] = &["ByteVector"];
+
+/// Runs `flatc` on a set of schemas.
+fn flatc(args: &[&OsStr], schemas: &[PathBuf]) -> anyhow::Result<()> {
+    let status = Command::new("flatc")
+        .args(args)
+        .args(schemas)
+        .status()
+        .context("Failed to run flatc")?;
+    ensure!(status.success(), "flatc failed: {status}");
+    Ok(())
+}
+
+/// Returns the name of the root table declared by a schema, if any.
+fn root_type(source: &str) -> Option<&str> {
+    source.lines().find_map(|line| {
+        let name = line.trim().strip_prefix("root_type")?;
+        Some(name.trim().trim_end_matches(';'))
+    })
+}
+
+/// Marks code blocks in the doc comments of generated files as text, to avoid errors when running
+/// doc tests.
+fn fix_generated_comments(dir: &Path) -> anyhow::Result<()> {
+    for entry in WalkDir::new(dir) {
+        let entry = entry.context("Failed to read entry")?;
+        if entry.path().extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+        let source = fs::read_to_string(entry.path()).context("Failed to read generated file")?;
+        let mut output = String::with_capacity(source.len());
+        let mut in_code_block = false;
+        for line in source.lines() {
+            output.push_str(line);
+            if line.trim_start() == "/// ```" {
+                if !in_code_block {
+                    output.push_str("text");
+                }
+                in_code_block = !in_code_block;
+            }
+            output.push('\n');
+        }
+        fs::write(entry.path(), output).context("Failed to write generated file")?;
+    }
+    Ok(())
+}
+
+/// Generates FlatBuffers tables and binary schemas.
+///
+/// Tables are generated with `flatc --rust` in `<out_dir>/flatbuffer/generated`, and binary
+/// schemas with `flatc -b --schema` in `<out_dir>/flatbuffer/*.bfbs`. Message impls for the root
+/// table of each schema are written to `<out_dir>/flatbuffer.rs`.
+pub fn generate_flatbuffers(fbs_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    let mut schemas: Vec<PathBuf> = fs::read_dir(fbs_path)
+        .context("Failed to read fbs directory")?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .filter(|path| {
+            path.as_ref()
+                .is_ok_and(|p| p.extension().is_some_and(|e| e == "fbs"))
+        })
+        .collect::<Result<_, _>>()?;
+    schemas.sort();
+
+    let schemas_dir = out_dir.join("flatbuffer");
+    if schemas_dir.exists() {
+        fs::remove_dir_all(&schemas_dir).context("Failed to remove flatbuffer directory")?;
+    }
+    fs::create_dir_all(&schemas_dir).context("Failed to create flatbuffer directory")?;
+
+    let generated_dir = schemas_dir.join("generated");
+    flatc(
+        &[
+            "--rust".as_ref(),
+            "--rust-module-root-file".as_ref(),
+            "-o".as_ref(),
+            generated_dir.as_os_str(),
+        ],
+        &schemas,
+    )?;
+    fix_generated_comments(&generated_dir).context("Failed to fix docstrings")?;
+
+    let mut module = String::new();
+    writeln!(module, "// This file is @generated by foxglove-proto-gen")?;
+    writeln!(module, "use crate::flatbuffer::FlatbufferMessage;\n")?;
+    writeln!(
+        module,
+        "#[allow(clippy::all, dead_code, deprecated, missing_docs, mismatched_lifetime_syntaxes, rustdoc::all, unused_imports)]"
+    )?;
+    writeln!(module, "mod generated;\n")?;
+    writeln!(module, "pub use generated::{NAMESPACE}::*;")?;
+
+    let mut root_schemas = vec![];
+    for path in &schemas {
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let Some(name) = root_type(&source) else {
+            continue;
+        };
+        if SKIPPED_TABLES.contains(&name) {
+            continue;
+        }
+        root_schemas.push(path.clone());
+        writeln!(
+            module,
+            "
+impl FlatbufferMessage for {name}<'_> {{
+    const TYPE_NAME: &'static str = \"{NAMESPACE}.{name}\";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!(\"flatbuffer/{name}.bfbs\");
+}}"
+        )?;
+    }
+    flatc(
+        &[
+            "-b".as_ref(),
+            "--schema".as_ref(),
+            "-o".as_ref(),
+            schemas_dir.as_os_str(),
+        ],
+        &root_schemas,
+    )?;
+
+    fs::write(out_dir.join("flatbuffer.rs"), module).context("Failed to write flatbuffer.rs")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_type() {
+        let source = "include \"Time.fbs\";\n\nnamespace foxglove;\n\ntable Log {\n  message:string (id: 0);\n}\n\nroot_type Log;\n";
+        assert_eq!(root_type(source), Some("Log"));
+        assert_eq!(
+            root_type("namespace foxglove;\n\nstruct Time {\n  sec:uint32;\n}\n"),
+            None
+        );
+    }
+}
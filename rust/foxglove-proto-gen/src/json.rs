@@ -0,0 +1,222 @@
+//! Generates JSON serialization impls for the well-known schemas.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+/// The properties of a JSON schema, in the order in which they are declared.
+struct Properties(Vec<(String, Map<String, Value>)>);
+
+impl<'de> Deserialize<'de> for Properties {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PropertiesVisitor;
+
+        impl<'de> Visitor<'de> for PropertiesVisitor {
+            type Value = Properties;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a map of properties")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Properties, A::Error> {
+                let mut properties = vec![];
+                while let Some(entry) = map.next_entry()? {
+                    properties.push(entry);
+                }
+                Ok(Properties(properties))
+            }
+        }
+
+        deserializer.deserialize_map(PropertiesVisitor)
+    }
+}
+
+/// The parts of a JSON schema used for code generation.
+#[derive(Deserialize)]
+struct JsonSchema {
+    title: String,
+    properties: Properties,
+}
+
+/// The representation of a property in JSON, and thus how it is serialized.
+#[derive(Debug, PartialEq)]
+enum Kind {
+    /// A value which serializes as-is, such as a number, string, enum, or array.
+    Plain,
+    /// An optional nested message, which is omitted if missing.
+    Message,
+    /// A `{sec, nsec}` timestamp.
+    Time,
+    /// A `{sec, nsec}` duration.
+    Duration,
+    /// A base64-encoded byte array.
+    Bytes,
+}
+
+/// Returns the kind of a top-level property in a JSON schema.
+fn property_kind(property: &Map<String, Value>) -> anyhow::Result<Kind> {
+    let r#type = property.get("type").and_then(Value::as_str);
+    let title = property.get("title").and_then(Value::as_str);
+    let kind = match (r#type, title) {
+        (Some("object"), Some("time")) => Kind::Time,
+        (Some("object"), Some("duration")) => Kind::Duration,
+        (Some("object"), Some(_)) => Kind::Message,
+        (Some("string"), _) if property.contains_key("contentEncoding") => Kind::Bytes,
+        (Some("string" | "number" | "integer" | "boolean" | "array"), _) => Kind::Plain,
+        // Enums are a `oneOf` of integer constants.
+        (None, _) if property.contains_key("oneOf") => Kind::Plain,
+        _ => bail!("Unsupported property {property:?}"),
+    };
+    Ok(kind)
+}
+
+/// Returns the identifier of the corresponding field in the generated Rust struct.
+fn ident(name: &str) -> String {
+    match name.to_lowercase().as_str() {
+        "type" => "r#type".to_string(),
+        name => name.to_string(),
+    }
+}
+
+/// Returns the statement which serializes a property.
+fn serialize_property(name: &str, kind: &Kind) -> String {
+    let ident = ident(name);
+    match kind {
+        Kind::Plain => format!("s.serialize_field(\"{name}\", &self.{ident})?;"),
+        Kind::Message => format!("serialize_optional(&mut s, \"{name}\", self.{ident}.as_ref())?;"),
+        Kind::Time => {
+            format!("serialize_optional(&mut s, \"{name}\", self.{ident}.as_ref().map(JsonTime))?;")
+        }
+        Kind::Duration => format!(
+            "serialize_optional(&mut s, \"{name}\", self.{ident}.as_ref().map(JsonDuration))?;"
+        ),
+        Kind::Bytes => format!("s.serialize_field(\"{name}\", &Base64(&self.{ident}))?;"),
+    }
+}
+
+/// Generates JSON serialization impls.
+///
+/// JSON schemas are copied to `<out_dir>/jsonschema/*.json`, and serialization impls are written
+/// to `<out_dir>/json.rs`.
+pub fn generate_json(jsonschema_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    let mut paths = fs::read_dir(jsonschema_path)
+        .context("Failed to read jsonschema directory")?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to read jsonschema entry")?;
+    paths.sort();
+
+    let schemas_dir = out_dir.join("jsonschema");
+    if schemas_dir.exists() {
+        fs::remove_dir_all(&schemas_dir).context("Failed to remove jsonschema directory")?;
+    }
+    fs::create_dir_all(&schemas_dir).context("Failed to create jsonschema directory")?;
+
+    let mut module = String::new();
+    writeln!(module, "// This file is @generated by foxglove-proto-gen")?;
+    writeln!(
+        module,
+        "use crate::json::{{serialize_optional, Base64, JsonDuration, JsonMessage, JsonTime}};"
+    )?;
+    writeln!(module, "use crate::schemas::foxglove::*;")?;
+    writeln!(
+        module,
+        "use serde::ser::{{Serialize, SerializeStruct, Serializer}};"
+    )?;
+
+    for path in paths {
+        let Some(name) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(".json"))
+        else {
+            continue;
+        };
+        let source = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let JsonSchema { title, properties } = serde_json::from_str(&source)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        // Special case for GeoJSON casing
+        let rust_name = if name == "GeoJSON" { "GeoJson" } else { name };
+
+        fs::write(schemas_dir.join(format!("{name}.json")), &source)
+            .context("Failed to write schema")?;
+
+        writeln!(
+            module,
+            "
+impl JsonMessage for {rust_name} {{
+    const TYPE_NAME: &'static str = \"{title}\";
+    const JSON_SCHEMA: &'static str = include_str!(\"jsonschema/{name}.json\");
+}}
+
+impl Serialize for {rust_name} {{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        let mut s = serializer.serialize_struct(\"{title}\", {len})?;",
+            len = properties.0.len(),
+        )?;
+        for (property_name, property) in &properties.0 {
+            let kind = property_kind(property)
+                .with_context(|| format!("Failed to generate {name}.{property_name}"))?;
+            writeln!(
+                module,
+                "        {}",
+                serialize_property(property_name, &kind)
+            )?;
+        }
+        writeln!(module, "        s.end()\n    }}\n}}")?;
+    }
+
+    fs::write(out_dir.join("json.rs"), module).context("Failed to write json.rs")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_property() {
+        let schema = r#"{
+            "title": "foxglove.Test",
+            "properties": {
+                "timestamp": { "type": "object", "title": "time" },
+                "lifetime": { "type": "object", "title": "duration" },
+                "pose": { "type": "object", "title": "foxglove.Pose" },
+                "data": { "type": "string", "contentEncoding": "base64" },
+                "K": { "type": "array", "items": { "type": "number" } },
+                "type": { "title": "foxglove.LineType", "oneOf": [] }
+            }
+        }"#;
+        let schema: JsonSchema = serde_json::from_str(schema).unwrap();
+        assert_eq!(schema.title, "foxglove.Test");
+        let statements: Vec<_> = schema
+            .properties
+            .0
+            .iter()
+            .map(|(name, property)| {
+                let kind = property_kind(property).unwrap();
+                serialize_property(name, &kind)
+            })
+            .collect();
+        assert_eq!(
+            statements,
+            vec![
+                "serialize_optional(&mut s, \"timestamp\", self.timestamp.as_ref().map(JsonTime))?;",
+                "serialize_optional(&mut s, \"lifetime\", self.lifetime.as_ref().map(JsonDuration))?;",
+                "serialize_optional(&mut s, \"pose\", self.pose.as_ref())?;",
+                "s.serialize_field(\"data\", &Base64(&self.data))?;",
+                "s.serialize_field(\"K\", &self.k)?;",
+                "s.serialize_field(\"type\", &self.r#type)?;",
+            ]
+        );
+
+        let invalid = serde_json::json!({ "type": "null" });
+        assert!(property_kind(invalid.as_object().unwrap()).is_err());
+    }
+}
@@ -11,6 +11,16 @@ use prost_types::{FileDescriptorProto, FileDescriptorSet};
 use tempfile::NamedTempFile;
 use walkdir::WalkDir;
 
+mod flatbuffer;
+mod json;
+mod omgidl;
+mod ros;
+
+pub use flatbuffer::generate_flatbuffers;
+pub use json::generate_json;
+pub use omgidl::generate_omgidl;
+pub use ros::{generate_ros1, generate_ros2};
+
 /// Recursively builds a file descriptor set for a file descriptor and its dependencies.
 fn build_fds(
     fd: &FileDescriptorProto,
@@ -128,7 +138,11 @@ fn generate_impls(out_dir: &Path, fds: &FileDescriptorSet) -> anyhow::Result<()>
         module,
         "use crate::schemas::{{descriptors, foxglove::*}};"
     ));
-    result = result.and(writeln!(module, "use crate::{{Schema, Encode}};"));
+    result = result.and(writeln!(
+        module,
+        "use crate::protobuf::{{add_file_descriptors, FileDescriptorProto, ProtobufMessage}};"
+    ));
+    result = result.and(writeln!(module, "use crate::{{Schema, Encode, Decode}};"));
     result = result.and(writeln!(module, "use bytes::BufMut;"));
     result.context("Failed to write impls.rs")?;
 
@@ -142,6 +156,7 @@ fn generate_impls(out_dir: &Path, fds: &FileDescriptorSet) -> anyhow::Result<()>
         else {
             continue;
         };
+        let proto_name = name;
         // Special case for GeoJSON casing
         if name == "GeoJSON" {
             name = "GeoJson";
@@ -169,6 +184,36 @@ fn generate_impls(out_dir: &Path, fds: &FileDescriptorSet) -> anyhow::Result<()>
     }}
 
     fn encoded_len(&self) -> Option<usize> {{ Some(::prost::Message::encoded_len(self)) }}
+}}
+
+impl Decode for {name} {{
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {{
+        ::prost::Message::decode(buf)
+    }}
+}}
+
+impl ProtobufMessage for {name} {{
+    fn type_name() -> String {{
+        \"foxglove.{proto_name}\".to_string()
+    }}
+
+    fn file_name() -> String {{
+        \"foxglove/{proto_name}.proto\".to_string()
+    }}
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {{
+        add_file_descriptors(files, descriptors::{descriptor_name});
+    }}
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {{
+        ::prost::Message::encode_raw(self, buf)
+    }}
+
+    fn encoded_len(&self) -> usize {{
+        ::prost::Message::encoded_len(self)
+    }}
 }}"
         )
         .context("Failed to write trait impl in impls.rs")?;
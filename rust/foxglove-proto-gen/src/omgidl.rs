@@ -0,0 +1,348 @@
+//! Generates OMG IDL definitions and XCDR2 serialization impls for the well-known schemas.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+
+/// The IDL module containing the foxglove schemas.
+const MODULE: &str = "foxglove";
+
+/// The type of a struct member.
+#[derive(Debug, Clone, PartialEq)]
+enum MemberType {
+    /// A primitive type, enum, or struct, by name.
+    Named(String),
+    Sequence(String),
+    Array(String, usize),
+}
+
+/// A struct member.
+#[derive(Debug)]
+struct Member {
+    name: String,
+    r#type: MemberType,
+}
+
+impl Member {
+    /// Returns the identifier of the corresponding field in the generated Rust struct.
+    ///
+    /// Some members have upper-case names, such as `CameraCalibration.K`.
+    fn ident(&self) -> String {
+        match self.name.to_lowercase().as_str() {
+            "type" => "r#type".to_string(),
+            name => name.to_string(),
+        }
+    }
+}
+
+/// An IDL file, containing a single struct or enum definition.
+#[derive(Debug)]
+struct Definition {
+    /// The files included by this file, by definition name.
+    includes: Vec<String>,
+    /// The source of the file, without include directives.
+    source: String,
+    /// The struct members, or `None` if this is an enum.
+    members: Option<Vec<Member>>,
+}
+
+/// Parses an IDL file.
+fn parse_definition(source: &str) -> anyhow::Result<Definition> {
+    let mut includes = vec![];
+    let mut members = None;
+    let mut body = String::new();
+    let mut in_struct = false;
+    let mut after_include = false;
+    for line in source.lines() {
+        if let Some(include) = line.strip_prefix("#include ") {
+            let name = include
+                .trim()
+                .trim_matches('"')
+                .strip_prefix(&format!("{MODULE}/"))
+                .and_then(|name| name.strip_suffix(".idl"))
+                .with_context(|| format!("Invalid include: {line}"))?;
+            includes.push(name.to_string());
+            after_include = true;
+            continue;
+        }
+        // Drop the blank line separating the includes from the module.
+        if std::mem::take(&mut after_include) && line.is_empty() {
+            continue;
+        }
+        body.push_str(line);
+        body.push('\n');
+
+        let line = line.split("//").next().unwrap_or_default().trim();
+        if line.starts_with("struct ") {
+            in_struct = true;
+            members = Some(vec![]);
+        } else if line.starts_with("enum ") {
+            members = None;
+        } else if line == "};" {
+            in_struct = false;
+        } else if in_struct && !line.is_empty() && !line.starts_with('@') {
+            let member = parse_member(line).with_context(|| format!("Invalid member: {line}"))?;
+            members.get_or_insert_with(Vec::new).push(member);
+        }
+    }
+    Ok(Definition {
+        includes,
+        source: body.trim_start().to_string(),
+        members,
+    })
+}
+
+/// Parses a member declaration, such as `sequence<double> d;` or `double K[9];`.
+fn parse_member(line: &str) -> anyhow::Result<Member> {
+    let line = line.trim_end_matches(';');
+    let (r#type, name) = line.rsplit_once(' ').context("Missing member name")?;
+    let (name, r#type) = match name.split_once('[') {
+        Some((name, len)) => {
+            let len = len.trim_end_matches(']').parse()?;
+            (name, MemberType::Array(r#type.to_string(), len))
+        }
+        None => match r#type
+            .strip_prefix("sequence<")
+            .and_then(|t| t.strip_suffix('>'))
+        {
+            Some(element) => (name, MemberType::Sequence(element.to_string())),
+            None => (name, MemberType::Named(r#type.to_string())),
+        },
+    };
+    Ok(Member {
+        name: name.to_string(),
+        r#type,
+    })
+}
+
+/// Returns the statement which serializes a struct member.
+fn serialize_member(
+    member: &Member,
+    definitions: &BTreeMap<String, Definition>,
+) -> anyhow::Result<String> {
+    let ident = member.ident();
+    let is_struct = |name: &str| definitions.get(name).is_some_and(|d| d.members.is_some());
+    let is_enum = |name: &str| definitions.get(name).is_some_and(|d| d.members.is_none());
+    let statement = match &member.r#type {
+        MemberType::Named(t) => match t.as_str() {
+            "boolean" => format!("w.write_bool(self.{ident});"),
+            "uint32" => format!("w.write_u32(self.{ident});"),
+            "double" => format!("w.write_f64(self.{ident});"),
+            "string" => format!("w.write_string(&self.{ident})?;"),
+            "Time" => format!("w.write_time(self.{ident}.as_ref())?;"),
+            "Duration" => format!("w.write_duration(self.{ident}.as_ref())?;"),
+            t if is_enum(t) => format!("w.write_enum(self.{ident});"),
+            t if is_struct(t) => format!("w.write_struct(self.{ident}.as_ref())?;"),
+            t => bail!("Unsupported type {t}"),
+        },
+        MemberType::Sequence(t) => match t.as_str() {
+            "uint8" => format!("w.write_bytes(&self.{ident})?;"),
+            "uint32" | "double" => format!("w.write_sequence(&self.{ident})?;"),
+            t if is_struct(t) => format!("w.write_struct_sequence(&self.{ident})?;"),
+            t => bail!("Unsupported sequence type {t}"),
+        },
+        MemberType::Array(t, len) if t == "double" => {
+            format!("w.write_array(&self.{ident}, {len})?;")
+        }
+        MemberType::Array(t, _) => bail!("Unsupported array type {t}"),
+    };
+    Ok(statement)
+}
+
+/// Collects the transitive dependencies of a definition, such that each definition follows its
+/// own dependencies.
+fn collect_dependencies<'a>(
+    name: &'a str,
+    definitions: &'a BTreeMap<String, Definition>,
+    dependencies: &mut Vec<&'a str>,
+    seen: &mut BTreeSet<&'a str>,
+) -> anyhow::Result<()> {
+    let Some(definition) = definitions.get(name) else {
+        bail!("Unknown definition {name}");
+    };
+    for include in &definition.includes {
+        if seen.insert(include) {
+            collect_dependencies(include, definitions, dependencies, seen)?;
+            dependencies.push(include);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the definition concatenated with the definitions of its dependencies.
+///
+/// Dependencies are declared before they are used, and include directives are omitted, so that
+/// the schema can be parsed on its own.
+fn merged_definition(
+    name: &str,
+    definitions: &BTreeMap<String, Definition>,
+) -> anyhow::Result<String> {
+    let mut dependencies = vec![];
+    collect_dependencies(name, definitions, &mut dependencies, &mut BTreeSet::new())?;
+    let mut merged = String::new();
+    for dependency in dependencies.into_iter().chain([name]) {
+        if !merged.is_empty() {
+            merged.push('\n');
+        }
+        merged.push_str(&definitions[dependency].source);
+    }
+    Ok(merged)
+}
+
+/// Generates concatenated OMG IDL definitions and XCDR2 serialization impls.
+///
+/// Definitions are written to `<out_dir>/omgidl/*.idl`, and serialization impls are written to
+/// `<out_dir>/omgidl.rs`.
+pub fn generate_omgidl(idl_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    let mut definitions = BTreeMap::new();
+    for entry in fs::read_dir(idl_path).context("Failed to read idl directory")? {
+        let path = entry.context("Failed to read idl entry")?.path();
+        let Some(name) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(".idl"))
+        else {
+            continue;
+        };
+        let source = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let definition = parse_definition(&source)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        definitions.insert(name.to_string(), definition);
+    }
+
+    let definitions_dir = out_dir.join("omgidl");
+    if definitions_dir.exists() {
+        fs::remove_dir_all(&definitions_dir).context("Failed to remove omgidl directory")?;
+    }
+    fs::create_dir_all(&definitions_dir).context("Failed to create omgidl directory")?;
+
+    let mut module = String::new();
+    writeln!(module, "// This file is @generated by foxglove-proto-gen")?;
+    writeln!(
+        module,
+        "use crate::omgidl::{{OmgIdlMessage, Xcdr2Error, Xcdr2Serialize, Xcdr2Writer}};"
+    )?;
+    writeln!(module, "use crate::schemas::foxglove::*;")?;
+
+    for (name, definition) in &definitions {
+        // Time and Duration are represented by well-known protobuf types.
+        let Some(members) = definition
+            .members
+            .as_ref()
+            .filter(|_| name != "Time" && name != "Duration")
+        else {
+            continue;
+        };
+        // Special case for GeoJSON casing
+        let rust_name = if name == "GeoJSON" { "GeoJson" } else { name };
+
+        fs::write(
+            definitions_dir.join(format!("{name}.idl")),
+            merged_definition(name, &definitions)?,
+        )
+        .context("Failed to write definition")?;
+
+        writeln!(
+            module,
+            "
+impl OmgIdlMessage for {rust_name} {{
+    const TYPE_NAME: &'static str = \"{MODULE}::{name}\";
+    const DEFINITION: &'static str = include_str!(\"omgidl/{name}.idl\");
+}}
+
+impl Xcdr2Serialize for {rust_name} {{
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {{"
+        )?;
+        for member in members {
+            let statement = serialize_member(member, &definitions)
+                .with_context(|| format!("Failed to generate {name}"))?;
+            writeln!(module, "        {statement}")?;
+        }
+        writeln!(module, "        Ok(())\n    }}\n}}")?;
+    }
+
+    fs::write(out_dir.join("omgidl.rs"), module).context("Failed to write omgidl.rs")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POSE_IDL: &str = r#"// Generated by https://github.com/foxglove/foxglove-sdk
+
+#include "foxglove/Point3.idl"
+
+module foxglove {
+
+// A pose
+struct Pose {
+  // Position
+  Point3 position;
+
+  @default(1.0)
+  double scale;
+
+  sequence<Point3> points;
+
+  double K[9];
+};
+
+};
+"#;
+
+    const POINT3_IDL: &str = "module foxglove {\n\nstruct Point3 {\n  double x;\n};\n\n};\n";
+
+    #[test]
+    fn test_parse_definition() {
+        let definition = parse_definition(POSE_IDL).unwrap();
+        assert_eq!(definition.includes, vec!["Point3"]);
+        assert!(definition.source.starts_with("// Generated by"));
+        assert!(!definition.source.contains("#include"));
+        let members: Vec<_> = definition
+            .members
+            .unwrap()
+            .into_iter()
+            .map(|m| (m.ident(), m.r#type))
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                ("position".into(), MemberType::Named("Point3".into())),
+                ("scale".into(), MemberType::Named("double".into())),
+                ("points".into(), MemberType::Sequence("Point3".into())),
+                ("k".into(), MemberType::Array("double".into(), 9)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merged_definition() {
+        let definitions = BTreeMap::from([
+            ("Pose".to_string(), parse_definition(POSE_IDL).unwrap()),
+            ("Point3".to_string(), parse_definition(POINT3_IDL).unwrap()),
+        ]);
+        let merged = merged_definition("Pose", &definitions).unwrap();
+        assert!(merged.starts_with(POINT3_IDL));
+        assert!(merged.ends_with("double K[9];\n};\n\n};\n"));
+
+        let pose = definitions["Pose"].members.as_ref().unwrap();
+        let statements: Vec<_> = pose
+            .iter()
+            .map(|m| serialize_member(m, &definitions).unwrap())
+            .collect();
+        assert_eq!(
+            statements,
+            vec![
+                "w.write_struct(self.position.as_ref())?;",
+                "w.write_f64(self.scale);",
+                "w.write_struct_sequence(&self.points)?;",
+                "w.write_array(&self.k, 9)?;",
+            ]
+        );
+    }
+}
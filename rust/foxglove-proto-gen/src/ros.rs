@@ -0,0 +1,365 @@
+//! Generates ROS 1 and ROS 2 message definitions and serialization impls for the well-known
+//! schemas.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+
+/// Separator between message definitions in a concatenated ROS message definition.
+const DEFINITION_SEPARATOR: &str =
+    "================================================================================\n";
+
+/// ROS messages which are used in place of equivalent foxglove schemas.
+///
+/// Tuples of (ROS message name, Rust type name, field definitions).
+const ROS_EQUIVALENTS: &[(&str, &str, &str)] = &[
+    (
+        "geometry_msgs/Point",
+        "Point3",
+        "float64 x\nfloat64 y\nfloat64 z\n",
+    ),
+    (
+        "geometry_msgs/Pose",
+        "Pose",
+        "geometry_msgs/Point position\ngeometry_msgs/Quaternion orientation\n",
+    ),
+    (
+        "geometry_msgs/Quaternion",
+        "Quaternion",
+        "float64 x\nfloat64 y\nfloat64 z\nfloat64 w\n",
+    ),
+    (
+        "geometry_msgs/Vector3",
+        "Vector3",
+        "float64 x\nfloat64 y\nfloat64 z\n",
+    ),
+];
+
+/// A ROS version for which definitions and serialization impls are generated.
+#[derive(Debug, Clone, Copy)]
+enum RosVersion {
+    Ros1,
+    Ros2,
+}
+
+impl RosVersion {
+    /// The name of the generated module, and of the directory containing message definitions.
+    fn module(self) -> &'static str {
+        match self {
+            Self::Ros1 => "ros1",
+            Self::Ros2 => "ros2",
+        }
+    }
+
+    /// Returns the fully-qualified type name of a message.
+    fn type_name(self, name: &str) -> String {
+        match (self, name.split_once('/')) {
+            (Self::Ros2, Some((package, msg))) => format!("{package}/msg/{msg}"),
+            _ => name.to_string(),
+        }
+    }
+
+    /// The names of the message trait, serialization trait, writer, and error type.
+    fn runtime_types(self) -> [&'static str; 4] {
+        match self {
+            Self::Ros1 => ["Ros1Message", "Ros1Serialize", "Ros1Writer", "Ros1Error"],
+            Self::Ros2 => ["Ros2Message", "CdrSerialize", "CdrWriter", "CdrError"],
+        }
+    }
+
+    /// The names of the builtin time and duration types.
+    fn time_types(self) -> [&'static str; 2] {
+        match self {
+            Self::Ros1 => ["time", "duration"],
+            Self::Ros2 => ["builtin_interfaces/Time", "builtin_interfaces/Duration"],
+        }
+    }
+}
+
+/// The array specifier of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Array {
+    None,
+    Unbounded,
+    Fixed(usize),
+}
+
+/// A (non-constant) field in a ROS message definition.
+#[derive(Debug)]
+struct Field {
+    r#type: String,
+    array: Array,
+    name: String,
+}
+
+impl Field {
+    /// Returns true if the field type is another message.
+    fn is_complex(&self) -> bool {
+        self.r#type.contains('/') && !self.r#type.starts_with("builtin_interfaces/")
+    }
+
+    /// Returns the identifier of the corresponding field in the generated Rust struct.
+    ///
+    /// ROS 1 definitions use upper-case names for some fields, such as `CameraCalibration.K`.
+    fn ident(&self) -> String {
+        match self.name.to_lowercase().as_str() {
+            "type" => "r#type".to_string(),
+            name => name.to_string(),
+        }
+    }
+}
+
+/// A ROS message definition.
+#[derive(Debug)]
+struct MessageDefinition {
+    /// The name used to refer to the message in other definitions, e.g. `foxglove_msgs/Color`.
+    name: String,
+    /// The name of the corresponding Rust type.
+    rust_name: String,
+    /// The message definition source.
+    source: String,
+    fields: Vec<Field>,
+}
+
+/// Parses the fields from a message definition, ignoring comments and constants.
+fn parse_fields(source: &str) -> anyhow::Result<Vec<Field>> {
+    let mut fields = vec![];
+    for line in source.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() || line.contains('=') {
+            continue;
+        }
+        let Some((r#type, name)) = line.split_once(' ') else {
+            bail!("Invalid field definition: {line}");
+        };
+        let (r#type, array) = match r#type.split_once('[') {
+            None => (r#type, Array::None),
+            Some((r#type, "]")) => (r#type, Array::Unbounded),
+            Some((r#type, len)) => {
+                let len = len
+                    .strip_suffix(']')
+                    .and_then(|len| len.parse().ok())
+                    .with_context(|| format!("Invalid array length: {line}"))?;
+                (r#type, Array::Fixed(len))
+            }
+        };
+        fields.push(Field {
+            r#type: r#type.to_string(),
+            array,
+            name: name.trim().to_string(),
+        });
+    }
+    Ok(fields)
+}
+
+/// Loads the message definitions for the foxglove schemas, and their ROS equivalents.
+fn load_definitions(msg_path: &Path) -> anyhow::Result<BTreeMap<String, MessageDefinition>> {
+    let mut definitions = BTreeMap::new();
+    for entry in fs::read_dir(msg_path).context("Failed to read msg directory")? {
+        let path = entry.context("Failed to read msg entry")?.path();
+        let Some(name) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(".msg"))
+        else {
+            continue;
+        };
+        let source = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let fields =
+            parse_fields(&source).with_context(|| format!("Failed to parse {}", path.display()))?;
+        // Special case for GeoJSON casing
+        let rust_name = if name == "GeoJSON" { "GeoJson" } else { name };
+        let name = format!("foxglove_msgs/{name}");
+        definitions.insert(
+            name.clone(),
+            MessageDefinition {
+                name,
+                rust_name: rust_name.to_string(),
+                source,
+                fields,
+            },
+        );
+    }
+    for (name, rust_name, fields) in ROS_EQUIVALENTS {
+        let source = format!(
+            "# {name}\n\n# Generated by https://github.com/foxglove/foxglove-sdk\n\n{fields}"
+        );
+        definitions.insert(
+            name.to_string(),
+            MessageDefinition {
+                name: name.to_string(),
+                rust_name: rust_name.to_string(),
+                source,
+                fields: parse_fields(fields)?,
+            },
+        );
+    }
+    Ok(definitions)
+}
+
+/// Collects the transitive dependencies of a message definition, in depth-first order.
+fn collect_dependencies<'a>(
+    definition: &MessageDefinition,
+    definitions: &'a BTreeMap<String, MessageDefinition>,
+    dependencies: &mut Vec<&'a MessageDefinition>,
+) -> anyhow::Result<()> {
+    for field in definition.fields.iter().filter(|f| f.is_complex()) {
+        let Some(dependency) = definitions.get(&field.r#type) else {
+            bail!(
+                "Unknown message type {} in {}",
+                field.r#type,
+                definition.name
+            );
+        };
+        if !dependencies.iter().any(|d| d.name == dependency.name) {
+            dependencies.push(dependency);
+        }
+        collect_dependencies(dependency, definitions, dependencies)?;
+    }
+    Ok(())
+}
+
+/// Returns the message definition concatenated with the definitions of its dependencies.
+fn merged_definition(
+    definition: &MessageDefinition,
+    definitions: &BTreeMap<String, MessageDefinition>,
+) -> anyhow::Result<String> {
+    let mut dependencies = vec![];
+    collect_dependencies(definition, definitions, &mut dependencies)?;
+    let mut merged = definition.source.clone();
+    for dependency in dependencies {
+        merged.push_str(DEFINITION_SEPARATOR);
+        writeln!(merged, "MSG: {}", dependency.name)?;
+        merged.push_str(&dependency.source);
+    }
+    Ok(merged)
+}
+
+/// Returns the statement which serializes a field.
+fn serialize_field(field: &Field, version: RosVersion) -> anyhow::Result<String> {
+    let ident = field.ident();
+    let [time, duration] = version.time_types();
+    let statement = match (field.r#type.as_str(), field.array) {
+        (t, Array::None) if t == time => {
+            format!("w.write_time(self.{ident}.as_ref())?;")
+        }
+        (t, Array::None) if t == duration => {
+            format!("w.write_duration(self.{ident}.as_ref())?;")
+        }
+        ("bool", Array::None) => format!("w.write_bool(self.{ident});"),
+        ("uint8", Array::None) => format!("w.write_u8(self.{ident} as u8);"),
+        ("uint32", Array::None) => format!("w.write_u32(self.{ident});"),
+        ("float64", Array::None) => format!("w.write_f64(self.{ident});"),
+        ("string", Array::None) => format!("w.write_string(&self.{ident})?;"),
+        (_, Array::None) if field.is_complex() => {
+            format!("w.write_message(self.{ident}.as_ref())?;")
+        }
+        ("uint8", Array::Unbounded) => format!("w.write_bytes(&self.{ident})?;"),
+        ("uint32" | "float64", Array::Unbounded) => format!("w.write_sequence(&self.{ident})?;"),
+        (_, Array::Unbounded) if field.is_complex() => {
+            format!("w.write_sequence(&self.{ident})?;")
+        }
+        ("float64", Array::Fixed(len)) => format!("w.write_array(&self.{ident}, {len})?;"),
+        _ => bail!("Unsupported field type: {field:?}"),
+    };
+    Ok(statement)
+}
+
+/// Generates concatenated ROS 1 message definitions and serialization impls.
+pub fn generate_ros1(msg_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    generate(msg_path, out_dir, RosVersion::Ros1)
+}
+
+/// Generates concatenated ROS 2 message definitions and CDR serialization impls.
+pub fn generate_ros2(msg_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    generate(msg_path, out_dir, RosVersion::Ros2)
+}
+
+/// Writes message definitions to `<out_dir>/<module>/*.msg`, and serialization impls to
+/// `<out_dir>/<module>.rs`.
+fn generate(msg_path: &Path, out_dir: &Path, version: RosVersion) -> anyhow::Result<()> {
+    let definitions = load_definitions(msg_path)?;
+    let module_name = version.module();
+
+    let definitions_dir = out_dir.join(module_name);
+    if definitions_dir.exists() {
+        fs::remove_dir_all(&definitions_dir)
+            .with_context(|| format!("Failed to remove {module_name} directory"))?;
+    }
+    fs::create_dir_all(&definitions_dir)
+        .with_context(|| format!("Failed to create {module_name} directory"))?;
+
+    let [message_trait, serialize_trait, writer, error] = version.runtime_types();
+    let mut module = String::new();
+    writeln!(module, "// This file is @generated by foxglove-proto-gen")?;
+    let mut imports = version.runtime_types();
+    imports.sort_unstable();
+    writeln!(
+        module,
+        "use crate::{module_name}::{{{}}};",
+        imports.join(", ")
+    )?;
+    writeln!(module, "use crate::schemas::foxglove::*;")?;
+
+    for definition in definitions.values() {
+        let rust_name = &definition.rust_name;
+        fs::write(
+            definitions_dir.join(format!("{rust_name}.msg")),
+            merged_definition(definition, &definitions)?,
+        )
+        .context("Failed to write message definition")?;
+
+        let type_name = version.type_name(&definition.name);
+        writeln!(
+            module,
+            "
+impl {message_trait} for {rust_name} {{
+    const TYPE_NAME: &'static str = \"{type_name}\";
+    const DEFINITION: &'static str = include_str!(\"{module_name}/{rust_name}.msg\");
+}}
+
+impl {serialize_trait} for {rust_name} {{
+    fn serialize(&self, w: &mut {writer}) -> Result<(), {error}> {{"
+        )?;
+        for field in &definition.fields {
+            let statement = serialize_field(field, version)
+                .with_context(|| format!("Failed to generate {}", definition.name))?;
+            writeln!(module, "        {statement}")?;
+        }
+        writeln!(module, "        Ok(())\n    }}\n}}")?;
+    }
+
+    fs::write(out_dir.join(format!("{module_name}.rs")), module)
+        .with_context(|| format!("Failed to write {module_name}.rs"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fields() {
+        let fields = parse_fields(
+            "# comment\n\nuint8 UNKNOWN=0\nbuiltin_interfaces/Time timestamp\nfloat64[9] k\nuint8[] data\nfoxglove_msgs/Color[] colors\n",
+        )
+        .unwrap();
+        let fields: Vec<_> = fields
+            .iter()
+            .map(|f| (f.r#type.as_str(), f.array, f.name.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("builtin_interfaces/Time", Array::None, "timestamp"),
+                ("float64", Array::Fixed(9), "k"),
+                ("uint8", Array::Unbounded, "data"),
+                ("foxglove_msgs/Color", Array::Unbounded, "colors"),
+            ]
+        );
+    }
+}
@@ -1,9 +1,11 @@
+use crate::clock::ContextClock;
 use crate::log_sink_set::LogSinkSet;
-use crate::{nanoseconds_since_epoch, Metadata, PartialMetadata};
+use crate::schema_registry::RegisteredSchema;
+use crate::{Metadata, PartialMetadata};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
-use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering::Relaxed;
+use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::{collections::BTreeMap, sync::Arc};
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize, Serialize)]
@@ -77,7 +79,8 @@ impl Schema {
 /// [MCAP channel]: https://mcap.dev/guides/concepts#channel
 ///
 /// If a schema was provided, all messages must be encoded according to the schema.
-/// This is not checked. See [`TypedChannel`](crate::TypedChannel) for type-safe channels.
+/// This is not checked, unless a `ValidationSink` is installed with the `validate` feature.
+/// See [`TypedChannel`](crate::TypedChannel) for type-safe channels.
 /// Channels are immutable, returned as `Arc<Channel>` and can be shared between threads.
 ///
 /// Channels are created using [`ChannelBuilder`](crate::ChannelBuilder).
@@ -96,8 +99,13 @@ pub struct Channel {
     pub(crate) message_sequence: AtomicU32,
     pub(crate) topic: String,
     pub(crate) message_encoding: String,
-    pub(crate) schema: Option<Schema>,
+    /// The schema, shared with other channels in the context with the same schema.
+    pub(crate) schema: Option<Arc<RegisteredSchema>>,
     pub(crate) metadata: BTreeMap<String, String>,
+    /// The clock of the log context, used when the log time is not provided.
+    pub(crate) clock: Arc<ContextClock>,
+    /// Counts of the messages and bytes logged, reported as metrics.
+    pub(crate) counters: ChannelCounters,
 }
 
 impl Channel {
@@ -113,7 +121,7 @@ impl Channel {
 
     /// Returns the channel schema.
     pub fn schema(&self) -> Option<&Schema> {
-        self.schema.as_ref()
+        self.schema.as_deref().map(RegisteredSchema::schema)
     }
 
     /// Atomically increments and returns the next message sequence number.
@@ -121,6 +129,16 @@ impl Channel {
         self.message_sequence.fetch_add(1, Relaxed)
     }
 
+    /// Returns the number of messages logged on the channel.
+    pub fn messages_logged(&self) -> u64 {
+        self.counters.messages.load(Relaxed)
+    }
+
+    /// Returns the number of message bytes logged on the channel.
+    pub fn bytes_logged(&self) -> u64 {
+        self.counters.bytes.load(Relaxed)
+    }
+
     /// Logs a message.
     pub fn log(self: &Arc<Self>, msg: &[u8]) {
         self.log_with_meta(msg, PartialMetadata::default());
@@ -135,7 +153,7 @@ impl Channel {
 
         let mut metadata = Metadata {
             sequence: opts.sequence.unwrap_or_else(|| self.next_sequence()),
-            log_time: opts.log_time.unwrap_or_else(nanoseconds_since_epoch),
+            log_time: opts.log_time.unwrap_or_else(|| self.clock.now()),
             publish_time: opts.publish_time.unwrap_or_default(),
         };
         // If publish_time is not set, use log_time.
@@ -143,10 +161,19 @@ impl Channel {
             metadata.publish_time = metadata.log_time
         }
 
+        self.counters.messages.fetch_add(1, Relaxed);
+        self.counters.bytes.fetch_add(msg.len() as u64, Relaxed);
         self.sinks.for_each(|sink| sink.log(self, msg, &metadata));
     }
 }
 
+/// Counters for the messages logged on a channel.
+#[derive(Default)]
+pub(crate) struct ChannelCounters {
+    messages: AtomicU64,
+    bytes: AtomicU64,
+}
+
 #[cfg(test)]
 impl PartialEq for Channel {
     fn eq(&self, other: &Self) -> bool {
@@ -201,7 +228,7 @@ mod test {
             message_sequence: AtomicU32::new(1),
             topic: "topic".to_string(),
             message_encoding: "message_encoding".to_string(),
-            schema: Some(Schema::new(
+            schema: Some(RegisteredSchema::unregistered(Schema::new(
                 "name",
                 "encoding",
                 br#"{
@@ -211,8 +238,10 @@ mod test {
                         "count": {"type": "number"},
                     },
                 }"#,
-            )),
+            ))),
             metadata: collection! {"key".to_string() => "value".to_string()},
+            clock: Arc::default(),
+            counters: Default::default(),
         })
     }
 
@@ -233,7 +262,7 @@ mod test {
         assert!(u64::from(channel.id) > 0);
         assert_eq!(channel.topic, topic);
         assert_eq!(channel.message_encoding, message_encoding);
-        assert_eq!(channel.schema, Some(schema));
+        assert_eq!(channel.schema(), Some(&schema));
         assert_eq!(channel.metadata, metadata);
         assert_eq!(
             LogContext::global().get_channel_by_topic(topic),
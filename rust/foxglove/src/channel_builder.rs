@@ -1,6 +1,8 @@
 use crate::channel::ChannelId;
 use crate::encode::TypedChannel;
 use crate::log_sink_set::LogSinkSet;
+#[cfg(feature = "reflect")]
+use crate::reflect::DynamicChannel;
 use crate::{Channel, Encode, FoxgloveError, LogContext, Schema};
 use std::collections::BTreeMap;
 use std::sync::atomic::Ordering::Relaxed;
@@ -68,6 +70,7 @@ impl<'a> ChannelBuilder<'a> {
     /// Returns FoxgloveError::DuplicateChannel if a channel with the same topic already exists.
     pub fn build(self) -> Result<Arc<Channel>, FoxgloveError> {
         static CHANNEL_ID: AtomicU64 = AtomicU64::new(1);
+        let context = self.context.unwrap_or_else(|| LogContext::global());
         let channel = Arc::new(Channel {
             sinks: LogSinkSet::new(),
             id: ChannelId::new(CHANNEL_ID.fetch_add(1, Relaxed)),
@@ -76,12 +79,12 @@ impl<'a> ChannelBuilder<'a> {
             message_encoding: self
                 .message_encoding
                 .ok_or_else(|| FoxgloveError::MessageEncodingRequired)?,
-            schema: self.schema,
+            schema: self.schema.map(|schema| context.schemas.register(schema)),
             metadata: self.metadata,
+            clock: context.clock.clone(),
+            counters: Default::default(),
         });
-        self.context
-            .unwrap_or_else(|| LogContext::global())
-            .add_channel(channel.clone())?;
+        context.add_channel(channel.clone())?;
         Ok(channel)
     }
 
@@ -98,4 +101,22 @@ impl<'a> ChannelBuilder<'a> {
         let channel = self.build()?;
         Ok(TypedChannel::from_channel(channel))
     }
+
+    /// Build the channel and return it as a [`DynamicChannel`] as a Result.
+    /// The schema is derived from the message descriptor, and messages are encoded as protobuf.
+    /// Returns FoxgloveError::DuplicateChannel if a channel with the same topic already exists.
+    #[cfg(feature = "reflect")]
+    pub fn build_dynamic(
+        mut self,
+        descriptor: prost_reflect::MessageDescriptor,
+    ) -> Result<DynamicChannel, FoxgloveError> {
+        if self.message_encoding.is_none() {
+            self.message_encoding = Some("protobuf".to_string());
+        }
+        if self.schema.is_none() {
+            self.schema = Some(crate::reflect::schema(&descriptor));
+        }
+        let channel = self.build()?;
+        Ok(DynamicChannel::from_channel(channel, descriptor))
+    }
 }
@@ -0,0 +1,177 @@
+//! Clocks for message timestamps.
+//!
+//! When a message is logged without an explicit `log_time`, the [`LogContext`](crate::LogContext)
+//! assigns one from its clock. By default, this is the [`SystemClock`]. Simulators and log
+//! replayers can install a [`ManualClock`] instead, and advance it as simulated time progresses:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use foxglove::clock::ManualClock;
+//! use foxglove::LogContext;
+//!
+//! let clock = ManualClock::new(0);
+//! LogContext::global().set_clock(clock.clone());
+//!
+//! loop {
+//!     // ... step the simulation and log messages ...
+//!     clock.advance(Duration::from_millis(10));
+//! }
+//! ```
+//!
+//! Any type implementing [`Clock`] can be used as a clock.
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use arc_swap::ArcSwap;
+
+use crate::nanoseconds_since_epoch;
+
+/// A source of timestamps for logged messages.
+pub trait Clock: Send + Sync + 'static {
+    /// Returns the current time, in nanoseconds since the Unix epoch.
+    fn now(&self) -> u64;
+}
+
+/// A clock which reads the system time.
+///
+/// The system time may jump forwards or backwards, e.g. if it is adjusted by NTP.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        nanoseconds_since_epoch()
+    }
+}
+
+/// A clock which never goes backwards.
+///
+/// The clock measures the time elapsed since it was created with a monotonic clock, and adds it to
+/// an offset. By default, the offset is the system time when the clock was created.
+#[derive(Debug, Clone, Copy)]
+pub struct MonotonicClock {
+    start: Instant,
+    offset: u64,
+}
+
+impl MonotonicClock {
+    /// Creates a monotonic clock starting at the current system time.
+    pub fn new() -> Self {
+        Self::with_offset(nanoseconds_since_epoch())
+    }
+
+    /// Creates a monotonic clock starting at the given time, in nanoseconds since the Unix epoch.
+    pub fn with_offset(offset: u64) -> Self {
+        Self {
+            start: Instant::now(),
+            offset,
+        }
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MonotonicClock {
+    fn now(&self) -> u64 {
+        let elapsed = u64::try_from(self.start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        self.offset.saturating_add(elapsed)
+    }
+}
+
+/// A clock which is set explicitly, e.g. by a simulator.
+///
+/// Clones of the clock share the same time, so one clone can be installed in a
+/// [`LogContext`](crate::LogContext) while another is used to drive it.
+#[derive(Debug, Default, Clone)]
+pub struct ManualClock(Arc<AtomicU64>);
+
+impl ManualClock {
+    /// Creates a manual clock set to the given time, in nanoseconds since the Unix epoch.
+    pub fn new(nanos: u64) -> Self {
+        Self(Arc::new(AtomicU64::new(nanos)))
+    }
+
+    /// Sets the time, in nanoseconds since the Unix epoch.
+    pub fn set(&self, nanos: u64) {
+        self.0.store(nanos, Relaxed);
+    }
+
+    /// Advances the time by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        // The closure always returns `Some`, so the update cannot fail.
+        _ = self
+            .0
+            .fetch_update(Relaxed, Relaxed, |now| Some(now.saturating_add(nanos)));
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> u64 {
+        self.0.load(Relaxed)
+    }
+}
+
+/// The clock of a log context, shared with its channels.
+///
+/// The clock can be replaced while channels hold a reference to it.
+pub(crate) struct ContextClock(ArcSwap<Box<dyn Clock>>);
+
+impl ContextClock {
+    /// Replaces the clock.
+    pub fn set(&self, clock: impl Clock) {
+        self.0.store(Arc::new(Box::new(clock)));
+    }
+
+    /// Returns the current time of the clock.
+    pub fn now(&self) -> u64 {
+        self.0.load().now()
+    }
+}
+
+impl Default for ContextClock {
+    fn default() -> Self {
+        Self(ArcSwap::from_pointee(Box::new(SystemClock)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::new(10);
+        let other = clock.clone();
+        assert_eq!(clock.now(), 10);
+        other.set(20);
+        assert_eq!(clock.now(), 20);
+        other.advance(Duration::from_nanos(5));
+        assert_eq!(clock.now(), 25);
+        other.advance(Duration::MAX);
+        assert_eq!(clock.now(), u64::MAX);
+    }
+
+    #[test]
+    fn test_monotonic_clock() {
+        let clock = MonotonicClock::with_offset(1_000);
+        let first = clock.now();
+        assert!(first >= 1_000);
+        assert!(clock.now() >= first);
+    }
+
+    #[test]
+    fn test_context_clock() {
+        let clock = ContextClock::default();
+        assert!(clock.now() > 0);
+        clock.set(ManualClock::new(42));
+        assert_eq!(clock.now(), 42);
+    }
+}
@@ -0,0 +1,63 @@
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+
+/// A trait representing a message that can be decoded from its encoded representation.
+///
+/// This is the counterpart to [`Encode`](crate::Encode), and is used where the SDK receives
+/// messages, such as the requests for typed websocket services.
+pub trait Decode: Sized {
+    /// The error type returned by methods in this trait.
+    type Error: std::error::Error;
+
+    /// Decodes a message from the provided buffer.
+    fn decode(buf: &[u8]) -> Result<Self, Self::Error>;
+}
+
+/// Automatically implements [`Decode`] for any type that implements
+/// [`Deserialize`](serde::Deserialize) and
+/// [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html), matching the
+/// JSON encoding used by the blanket [`Encode`](crate::Encode) implementation.
+impl<T: DeserializeOwned + JsonSchema> Decode for T {
+    type Error = serde_json::Error;
+
+    fn decode(buf: &[u8]) -> Result<Self, Self::Error> {
+        serde_json::from_slice(buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schemas::Log;
+    use crate::Encode;
+    use serde::Serialize;
+
+    #[test]
+    fn test_json_round_trip() {
+        #[derive(Debug, PartialEq, Serialize, serde::Deserialize, JsonSchema)]
+        struct Foo {
+            msg: String,
+            count: u32,
+        }
+
+        let foo = Foo {
+            msg: "hello".to_string(),
+            count: 42,
+        };
+        let mut buf = Vec::new();
+        foo.encode(&mut buf).expect("failed to encode");
+        assert_eq!(Foo::decode(&buf).expect("failed to decode"), foo);
+        assert!(Foo::decode(b"not json").is_err());
+    }
+
+    #[test]
+    fn test_protobuf_round_trip() {
+        let log = Log {
+            message: "hello".to_string(),
+            ..Default::default()
+        };
+        let mut buf = Vec::new();
+        log.encode(&mut buf).expect("failed to encode");
+        assert_eq!(Log::decode(&buf).expect("failed to decode"), log);
+    }
+}
@@ -97,6 +97,10 @@ impl<T: Encode> TypedChannel<T> {
         }
     }
 
+    pub(crate) fn inner(&self) -> &Arc<Channel> {
+        &self.inner
+    }
+
     /// Encodes the message and logs it on the channel.
     pub fn log(&self, msg: &T) {
         self.log_with_meta(msg, PartialMetadata::default());
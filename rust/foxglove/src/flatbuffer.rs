@@ -0,0 +1,213 @@
+//! FlatBuffers message encoding for well-known schemas.
+//!
+//! By default, the [well-known schemas](crate::schemas) are encoded as protobuf. The [`schemas`]
+//! module provides FlatBuffers tables for the same schemas, generated by `flatc`. A message is
+//! built in place with a [`FlatBufferBuilder`], using the generated `Args` structs or builders,
+//! and logged as a [`Flatbuffer`] with a binary `flatbuffer` schema.
+//!
+//! ```no_run
+//! use foxglove::flatbuffer::schemas::{RawImage, RawImageArgs, Time};
+//! use foxglove::flatbuffer::{FlatBufferBuilder, Flatbuffer};
+//! use foxglove::TypedChannel;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = TypedChannel::<Flatbuffer<RawImage>>::new("/image")?;
+//!
+//! let mut fbb = FlatBufferBuilder::new();
+//! let encoding = fbb.create_string("mono8");
+//! let data = fbb.create_vector(&[0u8; 640 * 480]);
+//! let image = RawImage::create(
+//!     &mut fbb,
+//!     &RawImageArgs {
+//!         timestamp: Some(&Time::new(1, 0)),
+//!         width: 640,
+//!         height: 480,
+//!         encoding: Some(encoding),
+//!         step: 640,
+//!         data: Some(data),
+//!         ..Default::default()
+//!     },
+//! );
+//! channel.log(&Flatbuffer::finish(fbb, image));
+//! # Ok(()) }
+//! ```
+//!
+//! Consumers can access fields of a FlatBuffer in place, without deserializing the message, which
+//! makes this encoding well suited for large payloads such as images and point clouds.
+//!
+//! This module requires the `flatbuffer` feature.
+
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+use bytes::BufMut;
+pub use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use crate::{Encode, Schema};
+
+/// FlatBuffers tables for the well-known schemas, generated by `flatc`.
+pub mod schemas {
+    pub use crate::schemas::flatbuffer::*;
+}
+
+/// A table with a binary FlatBuffers schema, which can be the root of a [`Flatbuffer`].
+pub trait FlatbufferMessage {
+    /// The fully-qualified table name, e.g. `foxglove.Log`.
+    const TYPE_NAME: &'static str;
+    /// The binary schema (`.bfbs`), as produced by `flatc -b --schema`.
+    const BINARY_SCHEMA: &'static [u8];
+}
+
+/// A finished FlatBuffer, whose root is a table of type `T`.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct Flatbuffer<T> {
+    data: Vec<u8>,
+    head: usize,
+    _root: PhantomData<T>,
+}
+
+impl<T> Flatbuffer<T> {
+    /// Finishes a builder with the given root table, and takes ownership of its buffer.
+    pub fn finish(mut fbb: FlatBufferBuilder<'_>, root: WIPOffset<T>) -> Self {
+        fbb.finish_minimal(root);
+        let (data, head) = fbb.collapse();
+        Self {
+            data,
+            head,
+            _root: PhantomData,
+        }
+    }
+
+    /// Returns the encoded message.
+    pub fn data(&self) -> &[u8] {
+        &self.data[self.head..]
+    }
+}
+
+impl<T: FlatbufferMessage> Encode for Flatbuffer<T> {
+    type Error = Infallible;
+
+    fn get_schema() -> Option<Schema> {
+        Some(Schema::new(T::TYPE_NAME, "flatbuffer", T::BINARY_SCHEMA))
+    }
+
+    fn get_message_encoding() -> String {
+        "flatbuffer".to_string()
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) -> Result<(), Self::Error> {
+        buf.put_slice(self.data());
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> Option<usize> {
+        Some(self.data().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::schemas::*;
+    use super::*;
+
+    #[test]
+    fn test_encode_log() {
+        let mut fbb = FlatBufferBuilder::new();
+        let message = fbb.create_string("hello");
+        let log = Log::create(
+            &mut fbb,
+            &LogArgs {
+                timestamp: Some(&Time::new(5, 6)),
+                level: LogLevel::WARNING,
+                message: Some(message),
+                line: 42,
+                ..Default::default()
+            },
+        );
+        let msg = Flatbuffer::finish(fbb, log);
+
+        let mut buf = Vec::new();
+        msg.encode(&mut buf).unwrap();
+        assert_eq!(msg.encoded_len(), Some(buf.len()));
+        let log = root_as_log(&buf).unwrap();
+        let timestamp = log.timestamp().unwrap();
+        assert_eq!((timestamp.sec(), timestamp.nsec()), (5, 6));
+        assert_eq!(log.level(), LogLevel::WARNING);
+        assert_eq!(log.message(), Some("hello"));
+        assert_eq!(log.name(), None);
+        assert_eq!(log.line(), 42);
+    }
+
+    #[test]
+    fn test_encode_nested() {
+        let mut fbb = FlatBufferBuilder::new();
+        let id = fbb.create_string("entity");
+        let cubes = [
+            CubePrimitive::create(&mut fbb, &CubePrimitiveArgs::default()),
+            CubePrimitive::create(&mut fbb, &CubePrimitiveArgs::default()),
+        ];
+        let cubes = fbb.create_vector(&cubes);
+        let entity = SceneEntity::create(
+            &mut fbb,
+            &SceneEntityArgs {
+                id: Some(id),
+                cubes: Some(cubes),
+                ..Default::default()
+            },
+        );
+        let entities = fbb.create_vector(&[entity]);
+        let update = SceneUpdate::create(
+            &mut fbb,
+            &SceneUpdateArgs {
+                entities: Some(entities),
+                ..Default::default()
+            },
+        );
+        let msg = Flatbuffer::finish(fbb, update);
+
+        let update = root_as_scene_update(msg.data()).unwrap();
+        let entities = update.entities().unwrap();
+        assert_eq!(entities.len(), 1);
+        let entity = entities.get(0);
+        assert_eq!(entity.id(), Some("entity"));
+        assert!(entity.timestamp().is_none());
+        assert_eq!(entity.cubes().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_encode_defaults() {
+        // `w` has a default of 1.0 in the schema.
+        let mut fbb = FlatBufferBuilder::new();
+        let quaternion = Quaternion::create(&mut fbb, &QuaternionArgs::default());
+        let msg = Flatbuffer::finish(fbb, quaternion);
+        let quaternion = root_as_quaternion(msg.data()).unwrap();
+        assert_eq!(quaternion.x(), 0.0);
+        assert_eq!(quaternion.w(), 1.0);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let quaternion = Quaternion::create(
+            &mut fbb,
+            &QuaternionArgs {
+                w: 0.0,
+                ..Default::default()
+            },
+        );
+        let msg = Flatbuffer::finish(fbb, quaternion);
+        assert_eq!(root_as_quaternion(msg.data()).unwrap().w(), 0.0);
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Flatbuffer::<Log>::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove.Log");
+        assert_eq!(schema.encoding, "flatbuffer");
+        assert!(flatbuffers::buffer_has_identifier(
+            &schema.data,
+            "BFBS",
+            false
+        ));
+        assert_eq!(Flatbuffer::<Log>::get_message_encoding(), "flatbuffer");
+    }
+}
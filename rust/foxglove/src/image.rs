@@ -0,0 +1,332 @@
+//! Conversions between image schemas and the [`image`] crate.
+//!
+//! With the `image` feature, a [`RawImage`] can be created from any [`DynamicImage`] or
+//! [`ImageBuffer`], and converted back with [`RawImage::to_image`]. Raw images can be compressed
+//! to JPEG or PNG with [`RawImage::compress`], and compressed images decoded with
+//! [`CompressedImage::decode`].
+//!
+//! ```no_run
+//! use foxglove::image::CompressionFormat;
+//! use foxglove::schemas::RawImage;
+//!
+//! # fn func(frame: ::image::RgbImage) -> Result<(), Box<dyn std::error::Error>> {
+//! let raw = RawImage {
+//!     frame_id: "camera".to_string(),
+//!     ..RawImage::from(&frame)
+//! };
+//! let compressed = raw.compress(CompressionFormat::Jpeg { quality: 80 })?;
+//! # Ok(()) }
+//! ```
+//!
+//! The following [`RawImage`] encodings are supported: `mono8` (or `8UC1`), `mono16` (or
+//! `16UC1`), `rgb8`, `rgba8`, `bgr8` (or `8UC3`), and `bgra8`.
+
+use ::image::codecs::jpeg::JpegEncoder;
+use ::image::codecs::png::PngEncoder;
+use ::image::{DynamicImage, GrayImage, ImageBuffer, Luma, Rgb, RgbImage, Rgba, RgbaImage};
+use thiserror::Error;
+
+use crate::schemas::{CompressedImage, RawImage};
+
+/// An error encountered while converting an image.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum ImageError {
+    /// The raw image encoding is not supported.
+    #[error("Unsupported raw image encoding: {0}")]
+    UnsupportedEncoding(String),
+    /// The raw image data is too short for its dimensions and step.
+    #[error("Raw image data has {len} bytes, expected at least {expected}")]
+    InvalidData {
+        /// The expected minimum length, in bytes.
+        expected: usize,
+        /// The actual length, in bytes.
+        len: usize,
+    },
+    /// The image could not be encoded or decoded.
+    #[error(transparent)]
+    Image(#[from] ::image::ImageError),
+}
+
+/// The format of a [`CompressedImage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CompressionFormat {
+    /// Lossless PNG compression.
+    Png,
+    /// Lossy JPEG compression, with a quality between 1 and 100.
+    ///
+    /// JPEG does not support transparency or 16-bit channels, so images are reduced to 8-bit RGB
+    /// or grayscale before compression.
+    Jpeg {
+        /// The compression quality.
+        quality: u8,
+    },
+}
+
+impl CompressionFormat {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg { .. } => "jpeg",
+        }
+    }
+}
+
+/// Returns the number of bytes per pixel for a raw image encoding.
+fn bytes_per_pixel(encoding: &str) -> Option<usize> {
+    match encoding {
+        "mono8" | "8UC1" => Some(1),
+        "mono16" | "16UC1" => Some(2),
+        "rgb8" | "bgr8" | "8UC3" => Some(3),
+        "rgba8" | "bgra8" => Some(4),
+        _ => None,
+    }
+}
+
+/// Creates a raw image from tightly-packed pixel data.
+fn raw_image(
+    width: u32,
+    height: u32,
+    encoding: &str,
+    bytes_per_pixel: u32,
+    data: Vec<u8>,
+) -> RawImage {
+    RawImage {
+        width,
+        height,
+        encoding: encoding.to_string(),
+        step: width * bytes_per_pixel,
+        data,
+        ..Default::default()
+    }
+}
+
+impl From<&GrayImage> for RawImage {
+    fn from(image: &GrayImage) -> Self {
+        raw_image(
+            image.width(),
+            image.height(),
+            "mono8",
+            1,
+            image.as_raw().clone(),
+        )
+    }
+}
+
+impl From<&ImageBuffer<Luma<u16>, Vec<u16>>> for RawImage {
+    fn from(image: &ImageBuffer<Luma<u16>, Vec<u16>>) -> Self {
+        let data = image
+            .as_raw()
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        raw_image(image.width(), image.height(), "mono16", 2, data)
+    }
+}
+
+impl From<&RgbImage> for RawImage {
+    fn from(image: &RgbImage) -> Self {
+        raw_image(
+            image.width(),
+            image.height(),
+            "rgb8",
+            3,
+            image.as_raw().clone(),
+        )
+    }
+}
+
+impl From<&RgbaImage> for RawImage {
+    fn from(image: &RgbaImage) -> Self {
+        raw_image(
+            image.width(),
+            image.height(),
+            "rgba8",
+            4,
+            image.as_raw().clone(),
+        )
+    }
+}
+
+impl From<&DynamicImage> for RawImage {
+    /// Converts the image to a raw image.
+    ///
+    /// 8-bit grayscale, 16-bit grayscale, RGB, and RGBA images are converted without loss. Other
+    /// images are converted to 8-bit RGB, or to 8-bit RGBA if they have an alpha channel.
+    fn from(image: &DynamicImage) -> Self {
+        match image {
+            DynamicImage::ImageLuma8(image) => image.into(),
+            DynamicImage::ImageLuma16(image) => image.into(),
+            DynamicImage::ImageRgb8(image) => image.into(),
+            DynamicImage::ImageRgba8(image) => image.into(),
+            image if image.color().has_alpha() => (&image.to_rgba8()).into(),
+            image => (&image.to_rgb8()).into(),
+        }
+    }
+}
+
+impl RawImage {
+    /// Converts the raw image to a [`DynamicImage`].
+    ///
+    /// Any padding at the end of each row is removed. BGR images are converted to RGB.
+    pub fn to_image(&self) -> Result<DynamicImage, ImageError> {
+        let bytes_per_pixel = bytes_per_pixel(&self.encoding)
+            .ok_or_else(|| ImageError::UnsupportedEncoding(self.encoding.clone()))?;
+        let (width, height) = (self.width as usize, self.height as usize);
+        let row_len = width * bytes_per_pixel;
+        let step = (self.step as usize).max(row_len);
+        let expected = match height {
+            0 => 0,
+            _ => step * (height - 1) + row_len,
+        };
+        if self.data.len() < expected {
+            return Err(ImageError::InvalidData {
+                expected,
+                len: self.data.len(),
+            });
+        }
+
+        let mut data = Vec::with_capacity(row_len * height);
+        if row_len > 0 {
+            for row in self.data.chunks(step).take(height) {
+                data.extend_from_slice(&row[..row_len]);
+            }
+        }
+        if matches!(self.encoding.as_str(), "bgr8" | "8UC3" | "bgra8") {
+            for pixel in data.chunks_exact_mut(bytes_per_pixel) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        let (w, h) = (self.width, self.height);
+        let image = match bytes_per_pixel {
+            1 => ImageBuffer::<Luma<u8>, _>::from_raw(w, h, data).map(DynamicImage::from),
+            2 => {
+                let data = data
+                    .chunks_exact(2)
+                    .map(|b| u16::from_le_bytes([b[0], b[1]]))
+                    .collect();
+                ImageBuffer::<Luma<u16>, _>::from_raw(w, h, data).map(DynamicImage::from)
+            }
+            3 => ImageBuffer::<Rgb<u8>, _>::from_raw(w, h, data).map(DynamicImage::from),
+            _ => ImageBuffer::<Rgba<u8>, _>::from_raw(w, h, data).map(DynamicImage::from),
+        };
+        Ok(image.expect("buffer matches dimensions"))
+    }
+
+    /// Compresses the raw image, preserving its timestamp and frame of reference.
+    pub fn compress(&self, format: CompressionFormat) -> Result<CompressedImage, ImageError> {
+        let mut image = self.to_image()?;
+        let mut data = Vec::new();
+        match format {
+            CompressionFormat::Png => image.write_with_encoder(PngEncoder::new(&mut data))?,
+            CompressionFormat::Jpeg { quality } => {
+                image = match image {
+                    DynamicImage::ImageLuma8(_) | DynamicImage::ImageRgb8(_) => image,
+                    DynamicImage::ImageLuma16(_) => image.to_luma8().into(),
+                    _ => image.to_rgb8().into(),
+                };
+                image.write_with_encoder(JpegEncoder::new_with_quality(&mut data, quality))?;
+            }
+        }
+        Ok(CompressedImage {
+            timestamp: self.timestamp,
+            frame_id: self.frame_id.clone(),
+            data,
+            format: format.name().to_string(),
+        })
+    }
+}
+
+impl CompressedImage {
+    /// Decodes the compressed image.
+    ///
+    /// The image format is detected from the data.
+    pub fn decode(&self) -> Result<DynamicImage, ImageError> {
+        Ok(::image::load_from_memory(&self.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient() -> RgbImage {
+        RgbImage::from_fn(4, 3, |x, y| Rgb([x as u8 * 60, y as u8 * 80, 10]))
+    }
+
+    #[test]
+    fn test_raw_image_round_trip() {
+        let rgb = gradient();
+        let raw = RawImage::from(&rgb);
+        assert_eq!((raw.width, raw.height, raw.step), (4, 3, 12));
+        assert_eq!(raw.encoding, "rgb8");
+        assert_eq!(raw.to_image().unwrap(), DynamicImage::from(rgb.clone()));
+
+        let mono16 =
+            ImageBuffer::<Luma<u16>, _>::from_fn(2, 2, |x, y| Luma([(x * 1000 + y) as u16]));
+        let raw = RawImage::from(&DynamicImage::from(mono16.clone()));
+        assert_eq!(raw.encoding, "mono16");
+        assert_eq!(&raw.data[2..4], &1000u16.to_le_bytes());
+        assert_eq!(raw.to_image().unwrap(), DynamicImage::from(mono16));
+    }
+
+    #[test]
+    fn test_raw_image_step_and_bgr() {
+        // A 2x2 bgr8 image, with two bytes of padding per row.
+        let raw = RawImage {
+            width: 2,
+            height: 2,
+            encoding: "bgr8".to_string(),
+            step: 8,
+            data: vec![1, 2, 3, 4, 5, 6, 0, 0, 7, 8, 9, 10, 11, 12],
+            ..Default::default()
+        };
+        let image = raw.to_image().unwrap().into_rgb8();
+        assert_eq!(image.into_raw(), [3, 2, 1, 6, 5, 4, 9, 8, 7, 12, 11, 10]);
+
+        let short = RawImage {
+            data: vec![0; 13],
+            ..raw.clone()
+        };
+        assert!(matches!(
+            short.to_image(),
+            Err(ImageError::InvalidData {
+                expected: 14,
+                len: 13
+            })
+        ));
+
+        let unsupported = RawImage {
+            encoding: "yuyv".to_string(),
+            ..raw
+        };
+        assert!(matches!(
+            unsupported.to_image(),
+            Err(ImageError::UnsupportedEncoding(e)) if e == "yuyv"
+        ));
+    }
+
+    #[test]
+    fn test_compress() {
+        let rgba = RgbaImage::from_fn(8, 8, |x, y| Rgba([x as u8 * 30, y as u8 * 30, 0, 128]));
+        let raw = RawImage {
+            frame_id: "camera".to_string(),
+            ..RawImage::from(&rgba)
+        };
+
+        let png = raw.compress(CompressionFormat::Png).unwrap();
+        assert_eq!(png.format, "png");
+        assert_eq!(png.frame_id, "camera");
+        assert_eq!(png.decode().unwrap(), DynamicImage::from(rgba));
+
+        let jpeg = raw
+            .compress(CompressionFormat::Jpeg { quality: 90 })
+            .unwrap();
+        assert_eq!(jpeg.format, "jpeg");
+        let decoded = jpeg.decode().unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (8, 8));
+        assert!(!decoded.color().has_alpha());
+    }
+}
@@ -0,0 +1,232 @@
+//! JSON message encoding for well-known schemas.
+//!
+//! By default, the [well-known schemas](crate::schemas) are encoded as protobuf. Wrapping a
+//! message in [`Json`] encodes it as JSON instead, with the corresponding `jsonschema` schema, so
+//! that it can be read by tools which don't support protobuf.
+//!
+//! ```no_run
+//! use foxglove::json::Json;
+//! use foxglove::schemas::Log;
+//! use foxglove::TypedChannel;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = TypedChannel::<Json<Log>>::new("/log")?;
+//! channel.log(&Json(Log {
+//!     message: "Hello, JSON!".to_string(),
+//!     ..Default::default()
+//! }));
+//! # Ok(()) }
+//! ```
+//!
+//! Timestamps and durations are encoded as `{"sec": ..., "nsec": ...}` objects, byte arrays are
+//! base64-encoded strings, and enums are encoded as integers. Missing nested messages are omitted.
+
+use base64::prelude::*;
+use bytes::BufMut;
+use serde::ser::{Error as _, SerializeStruct};
+use serde::{Serialize, Serializer};
+
+use crate::schemas::{Duration, Timestamp};
+use crate::time::normalize;
+use crate::{Encode, Schema};
+
+/// A message with a JSON schema.
+pub trait JsonMessage: Serialize {
+    /// The fully-qualified type name, e.g. `foxglove.Log`.
+    const TYPE_NAME: &'static str;
+    /// The JSON schema.
+    const JSON_SCHEMA: &'static str;
+}
+
+/// Wraps a message so that it is encoded as JSON, with a `jsonschema` schema.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Json<T>(pub T);
+
+impl<T> From<T> for Json<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: JsonMessage> Encode for Json<T> {
+    type Error = serde_json::Error;
+
+    fn get_schema() -> Option<Schema> {
+        Some(Schema::new(
+            T::TYPE_NAME,
+            "jsonschema",
+            T::JSON_SCHEMA.as_bytes(),
+        ))
+    }
+
+    fn get_message_encoding() -> String {
+        "json".to_string()
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) -> Result<(), Self::Error> {
+        serde_json::to_writer(buf.writer(), &self.0)
+    }
+}
+
+/// Serializes a struct field, omitting it if the value is missing.
+pub(crate) fn serialize_optional<S: SerializeStruct, T: Serialize>(
+    s: &mut S,
+    key: &'static str,
+    value: Option<T>,
+) -> Result<(), S::Error> {
+    match value {
+        Some(value) => s.serialize_field(key, &value),
+        None => s.skip_field(key),
+    }
+}
+
+/// Serializes a `{sec, nsec}` object.
+fn serialize_sec_nsec<S: Serializer>(
+    serializer: S,
+    sec: impl Serialize,
+    nsec: u32,
+) -> Result<S::Ok, S::Error> {
+    let mut s = serializer.serialize_struct("time", 2)?;
+    s.serialize_field("sec", &sec)?;
+    s.serialize_field("nsec", &nsec)?;
+    s.end()
+}
+
+/// Serializes a timestamp as a `time` object, with unsigned seconds.
+pub(crate) struct JsonTime<'a>(pub &'a Timestamp);
+
+impl Serialize for JsonTime<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (sec, nsec) = normalize(self.0.seconds, self.0.nanos);
+        let sec = u32::try_from(sec).map_err(|_| {
+            S::Error::custom(format_args!("Time {}s is out of range", self.0.seconds))
+        })?;
+        serialize_sec_nsec(serializer, sec, nsec)
+    }
+}
+
+/// Serializes a duration as a `duration` object, with signed seconds.
+pub(crate) struct JsonDuration<'a>(pub &'a Duration);
+
+impl Serialize for JsonDuration<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (sec, nsec) = normalize(self.0.seconds, self.0.nanos);
+        let sec = i32::try_from(sec).map_err(|_| {
+            S::Error::custom(format_args!("Duration {}s is out of range", self.0.seconds))
+        })?;
+        serialize_sec_nsec(serializer, sec, nsec)
+    }
+}
+
+/// Serializes a byte array as a base64-encoded string.
+pub(crate) struct Base64<'a>(pub &'a [u8]);
+
+impl Serialize for Base64<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64_STANDARD.encode(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::schemas::log::Level;
+    use crate::schemas::{CameraCalibration, Log, Pose, RawImage, SceneEntity, Vector3};
+
+    fn encode<T: JsonMessage>(msg: T) -> serde_json::Value {
+        let mut buf = Vec::new();
+        Json(msg).encode(&mut buf).unwrap();
+        serde_json::from_slice(&buf).unwrap()
+    }
+
+    #[test]
+    fn test_encode_log() {
+        let value = encode(Log {
+            timestamp: Some(Timestamp {
+                seconds: 1,
+                nanos: 2,
+            }),
+            level: Level::Info as i32,
+            message: "hi".to_string(),
+            name: String::new(),
+            file: "a".to_string(),
+            line: 7,
+        });
+        assert_eq!(
+            value,
+            json!({
+                "timestamp": { "sec": 1, "nsec": 2 },
+                "level": 2,
+                "message": "hi",
+                "name": "",
+                "file": "a",
+                "line": 7,
+            })
+        );
+    }
+
+    #[test]
+    fn test_encode_nested_and_bytes() {
+        let value = encode(Pose {
+            position: Some(Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            }),
+            orientation: None,
+        });
+        assert_eq!(
+            value,
+            json!({ "position": { "x": 1.0, "y": 2.0, "z": 3.0 } })
+        );
+
+        let value = encode(RawImage {
+            data: vec![1, 2, 3],
+            ..Default::default()
+        });
+        assert_eq!(value["data"], "AQID");
+        assert!(value.get("timestamp").is_none());
+
+        let value = encode(CameraCalibration {
+            k: vec![1.0; 9],
+            ..Default::default()
+        });
+        assert_eq!(value["K"], json!(vec![1.0; 9]));
+    }
+
+    #[test]
+    fn test_time_and_duration() {
+        let value = encode(SceneEntity {
+            lifetime: Some(Duration {
+                seconds: -1,
+                nanos: -500_000_000,
+            }),
+            ..Default::default()
+        });
+        assert_eq!(value["lifetime"], json!({ "sec": -2, "nsec": 500_000_000 }));
+
+        let result = Json(Log {
+            timestamp: Some(Timestamp {
+                seconds: -1,
+                nanos: 0,
+            }),
+            ..Default::default()
+        })
+        .encode(&mut Vec::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Json::<Log>::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove.Log");
+        assert_eq!(schema.encoding, "jsonschema");
+        let schema: serde_json::Value = serde_json::from_slice(&schema.data).unwrap();
+        assert_eq!(schema["title"], "foxglove.Log");
+        assert_eq!(Json::<Log>::get_message_encoding(), "json");
+    }
+}
@@ -69,6 +69,11 @@
 //! # Ok(()) }
 //! ```
 //!
+//! With the `derive` feature, you can instead derive [`Encode`] to log custom types as protobuf,
+//! which is more compact than JSON. See the [`protobuf`] module for details. With the `reflect`
+//! feature, messages described by runtime `prost-reflect` descriptors can be logged on a
+//! `reflect::DynamicChannel`, without compiling them into Rust types.
+//!
 //! ### Static Channels
 //!
 //! A common pattern is to create the channels once as static variables, and then use them
@@ -160,20 +165,51 @@ use thiserror::Error;
 
 mod channel;
 mod channel_builder;
+pub mod clock;
 mod collection;
 mod cow_vec;
+mod decode;
 mod encode;
+#[cfg(feature = "flatbuffer")]
+pub mod flatbuffer;
+#[cfg(feature = "image")]
+pub mod image;
+pub mod json;
 mod log_context;
 mod log_sink;
 mod log_sink_set;
 mod mcap_writer;
 mod metadata;
+pub mod metrics;
+#[cfg(feature = "omgidl")]
+pub mod omgidl;
+pub mod point_cloud;
+pub mod protobuf;
+#[cfg(feature = "reflect")]
+pub mod reflect;
+#[cfg(feature = "ros1")]
+pub mod ros1;
+#[cfg(feature = "ros2")]
+pub mod ros2;
 mod runtime;
+mod schema_registry;
 pub mod schemas;
-mod time;
+mod subscription;
+pub mod time;
+#[cfg(feature = "tracing-subscriber")]
+pub mod tracing_layer;
+pub mod transforms;
+#[cfg(feature = "validate")]
+pub mod validate;
+#[cfg(feature = "h264")]
+pub mod video;
 pub mod websocket;
 mod websocket_server;
 
+// Allows `#[derive(foxglove::Encode)]` to be used within this crate.
+#[cfg(test)]
+extern crate self as foxglove;
+
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
@@ -181,7 +217,10 @@ mod testutil;
 
 pub use channel::{Channel, Schema};
 pub use channel_builder::ChannelBuilder;
+pub use decode::Decode;
 pub use encode::{Encode, TypedChannel};
+#[cfg(feature = "derive")]
+pub use foxglove_derive::Encode;
 #[doc(hidden)]
 pub use log_context::LogContext;
 pub use log_sink::LogSink;
@@ -189,6 +228,9 @@ pub use mcap_writer::{McapWriter, McapWriterHandle};
 pub use metadata::{Metadata, PartialMetadata};
 pub(crate) use runtime::get_runtime_handle;
 pub use runtime::shutdown_runtime;
+pub use subscription::{
+    MessageStream, SubscribedMessage, Subscription, TopicFilter, DEFAULT_STREAM_CAPACITY,
+};
 pub(crate) use time::nanoseconds_since_epoch;
 pub use websocket_server::{WebSocketServer, WebSocketServerBlockingHandle, WebSocketServerHandle};
 
@@ -226,6 +268,18 @@ pub enum FoxgloveError {
     /// Services are not supported on this server instance.
     #[error("Services are not supported on this server instance")]
     ServicesNotSupported,
+    /// A parameter with the same name is already declared.
+    #[error("Parameter {0} already exists in store")]
+    DuplicateParameter(String),
+    /// The parameter has not been declared.
+    #[error("Unknown parameter {0}")]
+    UnknownParameter(String),
+    /// The parameter cannot be set by clients.
+    #[error("Parameter {0} is read-only")]
+    ReadOnlyParameter(String),
+    /// The value is not valid for the parameter.
+    #[error("Invalid value for parameter {0}: {1}")]
+    InvalidParameterValue(String, String),
     /// An I/O error.
     #[error(transparent)]
     IoError(#[from] std::io::Error),
@@ -1,8 +1,14 @@
-use crate::log_sink_set::LogSinkSet;
+use crate::clock::{Clock, ContextClock};
+use crate::log_sink_set::{LogSinkSet, SinkEntry};
+use crate::metadata::Metadata;
+use crate::metrics::{self, Metric, Metrics};
+use crate::schema_registry::SchemaRegistry;
+use crate::subscription::{MessageStream, Subscription, TopicFilter};
 use crate::{Channel, FoxgloveError, LogSink};
 use parking_lot::RwLock;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::sync::atomic::Ordering::Relaxed;
 use std::sync::{Arc, OnceLock};
 
 /// A thread-safe wrapper around one or more Sinks, that writes to all of them.
@@ -10,6 +16,10 @@ pub struct LogContext {
     // Map of channels by topic.
     channels: RwLock<HashMap<String, Arc<Channel>>>,
     sinks: LogSinkSet,
+    // Clock for message timestamps, shared with channels.
+    pub(crate) clock: Arc<ContextClock>,
+    // Schemas of the channels built in this context, shared by channels with the same schema.
+    pub(crate) schemas: SchemaRegistry,
 }
 
 impl LogContext {
@@ -18,6 +28,8 @@ impl LogContext {
         Self {
             channels: RwLock::new(HashMap::new()),
             sinks: LogSinkSet::new(),
+            clock: Arc::default(),
+            schemas: SchemaRegistry::new(),
         }
     }
 
@@ -29,6 +41,19 @@ impl LogContext {
         DEFAULT_CONTEXT.get_or_init(LogContext::new)
     }
 
+    /// Sets the clock used to timestamp messages logged without an explicit `log_time`.
+    ///
+    /// The clock applies to all channels in the context, including existing ones. By default, the
+    /// context uses the [`SystemClock`](crate::clock::SystemClock).
+    pub fn set_clock(&self, clock: impl Clock) {
+        self.clock.set(clock);
+    }
+
+    /// Returns the current time of the context's clock, in nanoseconds since the Unix epoch.
+    pub fn now(&self) -> u64 {
+        self.clock.now()
+    }
+
     /// Returns the channel for the specified topic, if there is one.
     pub fn get_channel_by_topic(&self, topic: &str) -> Option<Arc<Channel>> {
         let channels = self.channels.read();
@@ -46,11 +71,10 @@ impl LogContext {
             };
             entry.insert(channel.clone());
         }
-        self.sinks.for_each(|sink| {
-            if channel.sinks.add_sink(sink.clone()) {
-                sink.add_channel(&channel);
+        self.sinks.for_each_entry(|entry| {
+            if channel.sinks.add_entry(entry.clone()) {
+                entry.sink.add_channel(&channel);
             }
-            Ok(())
         });
         Ok(())
     }
@@ -79,13 +103,14 @@ impl LogContext {
 
     /// Adds a sink to the log context.
     pub fn add_sink(&self, sink: Arc<dyn LogSink>) -> bool {
-        if !self.sinks.add_sink(sink.clone()) {
+        let entry = SinkEntry::new(sink.clone());
+        if !self.sinks.add_entry(entry.clone()) {
             return false;
         }
 
         // Add the sink to all existing channels.
         for channel in self.channels.read().values() {
-            if channel.sinks.add_sink(sink.clone()) {
+            if channel.sinks.add_entry(entry.clone()) {
                 sink.add_channel(channel);
             }
         }
@@ -119,6 +144,64 @@ impl LogContext {
         true
     }
 
+    /// Subscribes to messages logged on channels in this context, including channels created later.
+    ///
+    /// The callback is called for each message on a topic matching the filter, on the thread that
+    /// logged the message, so it should return quickly. The subscription ends when the returned
+    /// [`Subscription`] is dropped.
+    pub fn subscribe(
+        &self,
+        filter: TopicFilter,
+        callback: impl Fn(&Arc<Channel>, &[u8], &Metadata) + Send + Sync + 'static,
+    ) -> Subscription<'_> {
+        Subscription::new(self, filter, callback)
+    }
+
+    /// Returns a stream of the messages logged on channels in this context, including channels
+    /// created later.
+    ///
+    /// Only messages on topics matching the filter are received. See [`MessageStream`] for how
+    /// messages are buffered.
+    pub fn subscribe_stream(&self, filter: TopicFilter) -> MessageStream<'_> {
+        MessageStream::new(self, filter)
+    }
+
+    /// Returns a snapshot of the metrics of the channels and sinks in this context.
+    ///
+    /// See the [`metrics`](crate::metrics) module for the metrics which are reported.
+    pub fn metrics(&self) -> Metrics {
+        let mut snapshot = Metrics::default();
+        let mut channels: Vec<_> = self.channels.read().values().cloned().collect();
+        channels.sort_by(|a, b| a.topic.cmp(&b.topic));
+        for channel in &channels {
+            snapshot.push(
+                Metric::counter(metrics::CHANNEL_MESSAGES, channel.messages_logged())
+                    .with_label("topic", &channel.topic),
+            );
+            snapshot.push(
+                Metric::counter(metrics::CHANNEL_BYTES, channel.bytes_logged())
+                    .with_label("topic", &channel.topic),
+            );
+        }
+        let mut index = 0;
+        self.sinks.for_each_entry(|entry| {
+            // Label the sink's metrics, so that sinks of the same type can be told apart.
+            let start = snapshot.len();
+            snapshot.push(Metric::counter(
+                metrics::SINK_ERRORS,
+                entry.errors.load(Relaxed),
+            ));
+            entry.sink.metrics(&mut snapshot);
+            let (name, index_label) = (entry.sink.name(), index.to_string());
+            for metric in &mut snapshot.0[start..] {
+                metric.labels.insert(0, ("sink_index", index_label.clone()));
+                metric.labels.insert(0, ("sink", name.clone()));
+            }
+            index += 1;
+        });
+        snapshot
+    }
+
     /// Removes all channels and sinks from the log context.
     pub fn clear(&self) {
         let channels: HashMap<_, _> = std::mem::take(&mut self.channels.write());
@@ -148,13 +231,16 @@ impl Default for LogContext {
 #[cfg(test)]
 mod tests {
     use crate::channel::ChannelId;
+    use crate::clock::ManualClock;
     use crate::collection::collection;
     use crate::log_context::*;
     use crate::log_sink_set::ERROR_LOGGING_MESSAGE;
+    use crate::schema_registry::RegisteredSchema;
     use crate::testutil::{ErrorSink, MockSink, RecordingSink};
-    use crate::{nanoseconds_since_epoch, Channel, PartialMetadata, Schema};
+    use crate::{nanoseconds_since_epoch, Channel, ChannelBuilder, PartialMetadata, Schema};
     use std::sync::atomic::AtomicU32;
     use std::sync::Arc;
+    use std::time::Duration;
     use tracing_test::traced_test;
 
     fn new_test_channel(id: u64) -> Arc<Channel> {
@@ -164,7 +250,7 @@ mod tests {
             message_sequence: AtomicU32::new(1),
             topic: "topic".to_string(),
             message_encoding: "message_encoding".to_string(),
-            schema: Some(Schema::new(
+            schema: Some(RegisteredSchema::unregistered(Schema::new(
                 "name",
                 "encoding",
                 br#"{
@@ -174,8 +260,10 @@ mod tests {
                         "count": {"type": "number"},
                     },
                 }"#,
-            )),
+            ))),
             metadata: collection! {"key".to_string() => "value".to_string()},
+            clock: Arc::default(),
+            counters: Default::default(),
         })
     }
 
@@ -205,6 +293,34 @@ mod tests {
         assert!(ctx.remove_sink(&sink2));
     }
 
+    #[test]
+    fn test_channels_share_schemas() {
+        let ctx = LogContext::new();
+        let channel = |topic: &str| {
+            ChannelBuilder::new(topic)
+                .message_encoding("json")
+                .schema(Schema::new("schema", "jsonschema", b"{}".as_slice()))
+                .with_context(&ctx)
+                .build()
+                .unwrap()
+        };
+        let a = channel("/a");
+        let b = channel("/b");
+        assert!(Arc::ptr_eq(
+            a.schema.as_ref().unwrap(),
+            b.schema.as_ref().unwrap()
+        ));
+        assert_eq!(ctx.schemas.len(), 1);
+
+        // The schema is evicted when the last channel using it is removed and dropped.
+        assert!(ctx.remove_channel_for_topic("/a"));
+        drop(a);
+        assert_eq!(ctx.schemas.len(), 1);
+        assert!(ctx.remove_channel_for_topic("/b"));
+        drop(b);
+        assert_eq!(ctx.schemas.len(), 0);
+    }
+
     #[traced_test]
     #[test]
     fn test_log_calls_sinks() {
@@ -291,4 +407,42 @@ mod tests {
         channel.log(msg);
         assert!(!logs_contain(ERROR_LOGGING_MESSAGE));
     }
+
+    #[test]
+    fn test_log_uses_context_clock() {
+        let ctx = LogContext::new();
+        let sink = Arc::new(RecordingSink::new());
+        assert!(ctx.add_sink(sink.clone()));
+
+        let channel = ChannelBuilder::new("/test")
+            .message_encoding("json")
+            .with_context(&ctx)
+            .build()
+            .unwrap();
+
+        // The clock applies to existing channels.
+        let clock = ManualClock::new(42);
+        ctx.set_clock(clock.clone());
+        assert_eq!(ctx.now(), 42);
+        channel.log(b"first");
+
+        clock.advance(Duration::from_nanos(8));
+        channel.log(b"second");
+
+        // An explicit log time takes precedence.
+        channel.log_with_meta(
+            b"third",
+            PartialMetadata {
+                log_time: Some(1),
+                ..Default::default()
+            },
+        );
+
+        let recorded = sink.recorded.lock();
+        let times: Vec<_> = recorded
+            .iter()
+            .map(|r| (r.metadata.log_time, r.metadata.publish_time))
+            .collect();
+        assert_eq!(times, [(42, 42), (50, 50), (1, 1)]);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::channel::Channel;
 use crate::metadata::Metadata;
+use crate::metrics::Metrics;
 use crate::FoxgloveError;
 use std::sync::Arc;
 
@@ -25,4 +26,16 @@ pub trait LogSink: Send + Sync {
     /// remove_channel is called when a channel is unassociated with this Sink.
     /// Sinks can clean up any channel-related state they have or take other actions.
     fn remove_channel(&self, _channel: &Channel) {}
+
+    /// name returns the name of the sink, used to label its metrics.
+    /// Defaults to the name of the type, without its module path or generic parameters.
+    fn name(&self) -> String {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+        name.rsplit("::").next().unwrap_or(name).to_string()
+    }
+
+    /// metrics is called to collect sink-specific metrics, such as bytes written or queue depths.
+    /// Sinks push their metrics onto the snapshot. See [`LogContext::metrics`](crate::LogContext::metrics).
+    fn metrics(&self, _metrics: &mut Metrics) {}
 }
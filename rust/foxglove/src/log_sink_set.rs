@@ -1,5 +1,6 @@
 use crate::{FoxgloveError, LogSink};
 use parking_lot::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
 use std::sync::Arc;
 
 pub(crate) const ERROR_LOGGING_MESSAGE: &str = "error logging message";
@@ -9,7 +10,26 @@ pub(crate) const ERROR_LOGGING_MESSAGE: &str = "error logging message";
 //    next: AtomicPtr<Self>,
 // }
 
-pub(crate) struct LogSinkSet(RwLock<Vec<Arc<dyn LogSink>>>);
+/// A sink in a set, with a count of the errors it returned.
+///
+/// The error count is shared between the sets of a context and its channels, so that errors
+/// logging to any channel are attributed to the sink.
+#[derive(Clone)]
+pub(crate) struct SinkEntry {
+    pub sink: Arc<dyn LogSink>,
+    pub errors: Arc<AtomicU64>,
+}
+
+impl SinkEntry {
+    pub fn new(sink: Arc<dyn LogSink>) -> Self {
+        Self {
+            sink,
+            errors: Arc::default(),
+        }
+    }
+}
+
+pub(crate) struct LogSinkSet(RwLock<Vec<SinkEntry>>);
 
 impl LogSinkSet {
     pub const fn new() -> Self {
@@ -23,14 +43,15 @@ impl LogSinkSet {
         false
     }
 
-    /// Add a sink to the set. Returns false if the sink was already in the set.
-    pub fn add_sink(&self, sink: Arc<dyn LogSink>) -> bool {
+    /// Add a sink to the set, sharing its error count. Returns false if the sink was already in
+    /// the set.
+    pub fn add_entry(&self, entry: SinkEntry) -> bool {
         let mut sinks = self.0.write();
         // Check if the sink is already in the set.
-        if sinks.iter().any(|s| Arc::ptr_eq(s, &sink)) {
+        if sinks.iter().any(|e| Arc::ptr_eq(&e.sink, &entry.sink)) {
             return false;
         }
-        sinks.push(sink);
+        sinks.push(entry);
         true
     }
 
@@ -38,24 +59,33 @@ impl LogSinkSet {
     pub fn remove_sink(&self, sink: &Arc<dyn LogSink>) -> bool {
         let mut sinks = self.0.write();
         let len_before = sinks.len();
-        sinks.retain(|s| !Arc::ptr_eq(s, sink));
+        sinks.retain(|e| !Arc::ptr_eq(&e.sink, sink));
         sinks.len() < len_before
     }
 
     /// Iterate over all the sinks in the set, calling the given function on each,
-    /// logging any errors via tracing::warn!().
+    /// counting any errors and logging them via tracing::warn!().
     pub fn for_each<F>(&self, mut f: F)
     where
         F: FnMut(&Arc<dyn LogSink>) -> Result<(), FoxgloveError>,
     {
         let sinks = self.0.read();
-        for sink in sinks.iter() {
-            if let Err(err) = f(sink) {
+        for entry in sinks.iter() {
+            if let Err(err) = f(&entry.sink) {
+                entry.errors.fetch_add(1, Relaxed);
                 tracing::warn!("{ERROR_LOGGING_MESSAGE}: {:?}", err);
             }
         }
     }
 
+    /// Iterate over all the entries in the set.
+    pub fn for_each_entry<F>(&self, f: F)
+    where
+        F: FnMut(&SinkEntry),
+    {
+        self.0.read().iter().for_each(f);
+    }
+
     pub fn clear(&self) {
         self.0.write().clear();
     }
@@ -3,25 +3,75 @@ use crate::channel::Channel;
 use crate::channel::ChannelId;
 use crate::log_sink::LogSink;
 use crate::metadata::Metadata;
+use crate::metrics::{self, Metric, Metrics};
+use crate::schema_registry::SchemaId;
 use crate::FoxgloveError;
 use mcap::WriteOptions;
 use parking_lot::Mutex;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
-use std::io::{Seek, Write};
+use std::io::{Seek, SeekFrom, Write};
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
 use std::sync::Arc;
 
+/// A writer which counts the bytes written to the inner writer.
+///
+/// Bytes which overwrite earlier output, after seeking backwards, are not counted again, so that
+/// the count matches the size of the output.
+struct CountingWriter<W> {
+    inner: W,
+    start: u64,
+    position: u64,
+    written: Arc<AtomicU64>,
+}
+
+impl<W: Seek> CountingWriter<W> {
+    fn new(mut inner: W, written: Arc<AtomicU64>) -> std::io::Result<Self> {
+        let start = inner.stream_position()?;
+        Ok(Self {
+            inner,
+            start,
+            position: start,
+            written,
+        })
+    }
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.position += n as u64;
+        self.written
+            .fetch_max(self.position.saturating_sub(self.start), Relaxed);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Seek> Seek for CountingWriter<W> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.position = self.inner.seek(pos)?;
+        Ok(self.position)
+    }
+}
+
 struct WriterState<W: Write + Seek> {
-    writer: mcap::Writer<W>,
+    writer: mcap::Writer<CountingWriter<W>>,
     // ChannelId -> mcap file channel id
     channel_map: HashMap<ChannelId, u16>,
+    // Registered schema -> mcap file schema id
+    schema_map: HashMap<SchemaId, u16>,
 }
 
 impl<W: Write + Seek> WriterState<W> {
-    fn new(writer: mcap::Writer<W>) -> Self {
+    fn new(writer: mcap::Writer<CountingWriter<W>>) -> Self {
         Self {
             writer,
             channel_map: HashMap::new(),
+            schema_map: HashMap::new(),
         }
     }
 
@@ -35,10 +85,18 @@ impl<W: Write + Seek> WriterState<W> {
         let mcap_channel_id = match self.channel_map.entry(channel_id) {
             Entry::Occupied(entry) => *entry.get(),
             Entry::Vacant(entry) => {
-                let schema_id = if let Some(schema) = channel.schema() {
-                    self.writer
-                        .add_schema(&schema.name, &schema.encoding, &schema.data)
-                        .map_err(FoxgloveError::from)?
+                let schema_id = if let Some(registered) = &channel.schema {
+                    match self.schema_map.entry(registered.id()) {
+                        Entry::Occupied(entry) => *entry.get(),
+                        Entry::Vacant(entry) => {
+                            let schema = registered.schema();
+                            let schema_id = self
+                                .writer
+                                .add_schema(&schema.name, &schema.encoding, &schema.data)
+                                .map_err(FoxgloveError::from)?;
+                            *entry.insert(schema_id)
+                        }
+                    }
                 } else {
                     0 // 0 indicates a channel without a schema
                 };
@@ -72,25 +130,38 @@ impl<W: Write + Seek> WriterState<W> {
     }
 }
 
-pub struct McapSink<W: Write + Seek>(Mutex<Option<WriterState<W>>>);
+pub struct McapSink<W: Write + Seek> {
+    state: Mutex<Option<WriterState<W>>>,
+    bytes_written: Arc<AtomicU64>,
+}
 
 impl<W: Write + Seek> McapSink<W> {
     /// Creates a new MCAP writer log sink.
     pub fn new(writer: W, options: WriteOptions) -> Result<Arc<McapSink<W>>, FoxgloveError> {
+        let bytes_written = Arc::new(AtomicU64::new(0));
+        let writer = CountingWriter::new(writer, bytes_written.clone())?;
         let mcap_writer = options.create(writer).map_err(FoxgloveError::from)?;
-        let writer = Arc::new(Self(Mutex::new(Some(WriterState::new(mcap_writer)))));
+        let writer = Arc::new(Self {
+            state: Mutex::new(Some(WriterState::new(mcap_writer))),
+            bytes_written,
+        });
         Ok(writer)
     }
 
+    /// Returns the number of bytes written to the inner writer.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written.load(Relaxed)
+    }
+
     /// Finalizes the MCAP recording and flushes it to the file.
     ///
     /// Returns the inner writer that was passed to [`McapWriter::new`].
     pub fn finish(&self) -> Result<Option<W>, FoxgloveError> {
-        let Some(mut writer) = self.0.lock().take() else {
+        let Some(mut writer) = self.state.lock().take() else {
             return Ok(None);
         };
         writer.writer.finish()?;
-        Ok(Some(writer.writer.into_inner()))
+        Ok(Some(writer.writer.into_inner().inner))
     }
 }
 
@@ -102,17 +173,29 @@ impl<W: Write + Seek + Send> LogSink for McapSink<W> {
         metadata: &Metadata,
     ) -> Result<(), FoxgloveError> {
         _ = metadata;
-        let mut guard = self.0.lock();
+        let mut guard = self.state.lock();
         let writer = guard.as_mut().ok_or(FoxgloveError::SinkClosed)?;
         writer.log(channel, msg, metadata)
     }
+
+    fn name(&self) -> String {
+        "McapWriter".to_string()
+    }
+
+    fn metrics(&self, metrics: &mut Metrics) {
+        metrics.push(Metric::counter(
+            metrics::MCAP_BYTES_WRITTEN,
+            self.bytes_written(),
+        ));
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::log_sink_set::LogSinkSet;
-    use crate::{collection, Metadata, Schema};
+    use crate::schema_registry::RegisteredSchema;
+    use crate::{collection, ChannelBuilder, LogContext, Metadata, Schema};
     use mcap::McapError;
     use std::path::Path;
     use std::sync::atomic::AtomicU32;
@@ -125,7 +208,7 @@ mod tests {
             message_sequence: AtomicU32::new(1),
             topic,
             message_encoding: "message_encoding".to_string(),
-            schema: Some(Schema::new(
+            schema: Some(RegisteredSchema::unregistered(Schema::new(
                 name,
                 "encoding",
                 br#"{
@@ -135,8 +218,10 @@ mod tests {
                         "count": {"type": "number"},
                     },
                 }"#,
-            )),
+            ))),
             metadata: collection! {"key".to_string() => "value".to_string()},
+            clock: Arc::default(),
+            counters: Default::default(),
         })
     }
 
@@ -254,4 +339,48 @@ mod tests {
         })
         .expect("failed to read MCAP messages");
     }
+
+    #[test]
+    fn test_deduplicate_schemas() {
+        let ctx = LogContext::new();
+        let channel = |topic: &str, schema_name: &str| {
+            ChannelBuilder::new(topic)
+                .message_encoding("json")
+                .schema(Schema::new(schema_name, "jsonschema", b"{}".as_slice()))
+                .with_context(&ctx)
+                .build()
+                .expect("failed to create channel")
+        };
+        let ch1 = channel("foo", "shared_schema");
+        let ch2 = channel("bar", "shared_schema");
+        let ch3 = channel("baz", "other_schema");
+
+        let temp_file = NamedTempFile::new().expect("create tempfile");
+        let writer =
+            McapSink::new(&temp_file, WriteOptions::default()).expect("failed to create writer");
+        for ch in [&ch1, &ch2, &ch3] {
+            writer
+                .log(ch, b"msg", &Metadata::default())
+                .expect("failed to log");
+        }
+        writer.finish().expect("failed to finish recording");
+
+        let contents = std::fs::read(temp_file.path()).expect("failed to read file");
+        assert_eq!(writer.bytes_written(), contents.len() as u64);
+        let summary = mcap::Summary::read(&contents)
+            .expect("failed to read summary")
+            .expect("missing summary");
+        assert_eq!(summary.channels.len(), 3);
+        assert_eq!(summary.schemas.len(), 2);
+        let schema_id = |topic: &str| {
+            summary
+                .channels
+                .values()
+                .find(|c| c.topic == topic)
+                .and_then(|c| c.schema.as_ref())
+                .map(|s| s.id)
+        };
+        assert_eq!(schema_id("foo"), schema_id("bar"));
+        assert_ne!(schema_id("foo"), schema_id("baz"));
+    }
 }
@@ -1,3 +1,6 @@
+use crate::schemas::Timestamp;
+use crate::time::TimestampExt;
+
 /// PartialMetadata is `Metadata` with all optional fields.
 ///
 /// These timestamps are somewhat arbitrary, but should be used consistently within your app.
@@ -38,3 +41,17 @@ pub struct Metadata {
     /// If omitted, log time is used.
     pub publish_time: u64,
 }
+
+impl PartialMetadata {
+    /// Returns metadata with the publish time set from a message timestamp.
+    ///
+    /// Use this when logging a message with a timestamp field, so that the publish time recorded
+    /// by sinks matches the timestamp. If the timestamp is before the Unix epoch, the publish time
+    /// is left unset.
+    pub fn published_at(timestamp: &Timestamp) -> Self {
+        Self {
+            publish_time: timestamp.to_nanos(),
+            ..Default::default()
+        }
+    }
+}
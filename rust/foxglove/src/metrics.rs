@@ -0,0 +1,650 @@
+//! Metrics for channels, sinks, and the WebSocket server.
+//!
+//! The SDK counts the messages and bytes logged on each channel, the errors returned by each sink,
+//! and sink-specific metrics such as the bytes written by an [`McapWriter`](crate::McapWriter)
+//! and the queue depth of each WebSocket client. [`snapshot`] returns the current values:
+//!
+//! | Name | Kind | Labels |
+//! |------|------|--------|
+//! | `foxglove_channel_messages_total` | counter | `topic` |
+//! | `foxglove_channel_bytes_total` | counter | `topic` |
+//! | `foxglove_sink_errors_total` | counter | `sink`, `sink_index` |
+//! | `foxglove_mcap_bytes_written_total` | counter | `sink`, `sink_index` |
+//! | `foxglove_ws_clients` | gauge | `sink`, `sink_index` |
+//! | `foxglove_ws_client_queue_depth` | gauge | `sink`, `sink_index`, `client_id`, `client_addr` |
+//! | `foxglove_ws_client_dropped_messages_total` | counter | `sink`, `sink_index`, `client_id`, `client_addr` |
+//!
+//! Custom sinks can report their own metrics by implementing [`LogSink::metrics`](crate::LogSink::metrics).
+//!
+//! Metrics can be published periodically on a channel with a [`MetricsPublisher`], so that they
+//! are recorded alongside the data and can be plotted in Foxglove, or scraped by Prometheus from
+//! a server started with [`serve_prometheus`]:
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! use foxglove::metrics::{self, MetricsPublisher};
+//!
+//! # fn func() -> Result<(), Box<dyn std::error::Error>> {
+//! let publisher = Arc::new(MetricsPublisher::new()?);
+//! let _task = publisher.publish_periodically(Duration::from_secs(1));
+//!
+//! let server = metrics::serve_prometheus("127.0.0.1:9464")?;
+//! println!("Serving metrics on http://{}/metrics", server.local_addr());
+//! # Ok(()) }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::ops::Deref;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+
+use crate::{get_runtime_handle, FoxgloveError, LogContext, TypedChannel};
+
+/// The number of messages logged on a channel.
+pub const CHANNEL_MESSAGES: &str = "foxglove_channel_messages_total";
+/// The number of message bytes logged on a channel.
+pub const CHANNEL_BYTES: &str = "foxglove_channel_bytes_total";
+/// The number of errors returned by a sink when logging a message.
+pub const SINK_ERRORS: &str = "foxglove_sink_errors_total";
+/// The number of bytes written by an MCAP writer.
+pub const MCAP_BYTES_WRITTEN: &str = "foxglove_mcap_bytes_written_total";
+/// The number of clients connected to a WebSocket server.
+pub const WS_CLIENTS: &str = "foxglove_ws_clients";
+/// The number of messages queued to be sent to a WebSocket client.
+pub const WS_CLIENT_QUEUE_DEPTH: &str = "foxglove_ws_client_queue_depth";
+/// The number of messages dropped because a WebSocket client fell behind.
+pub const WS_CLIENT_DROPPED_MESSAGES: &str = "foxglove_ws_client_dropped_messages_total";
+
+/// The default topic for a [`MetricsPublisher`].
+pub const DEFAULT_TOPIC: &str = "/foxglove/metrics";
+
+/// The kind of a metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    /// A count which only increases.
+    Counter,
+    /// A value which can go up and down.
+    Gauge,
+}
+
+/// A metric value, with the labels that identify it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Metric {
+    /// The name of the metric.
+    pub name: &'static str,
+    /// The kind of the metric.
+    pub kind: MetricKind,
+    /// Labels, in the order they are formatted.
+    pub labels: Vec<(&'static str, String)>,
+    /// The value of the metric.
+    pub value: u64,
+}
+
+impl Metric {
+    /// Creates a counter metric.
+    pub fn counter(name: &'static str, value: u64) -> Self {
+        Self::new(name, MetricKind::Counter, value)
+    }
+
+    /// Creates a gauge metric.
+    pub fn gauge(name: &'static str, value: u64) -> Self {
+        Self::new(name, MetricKind::Gauge, value)
+    }
+
+    fn new(name: &'static str, kind: MetricKind, value: u64) -> Self {
+        Self {
+            name,
+            kind,
+            labels: Vec::new(),
+            value,
+        }
+    }
+
+    /// Adds a label to the metric.
+    pub fn with_label(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.labels.push((name, value.into()));
+        self
+    }
+
+    /// Returns the value of the label with the given name, if there is one.
+    pub fn label(&self, name: &str) -> Option<&str> {
+        self.labels
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A snapshot of metrics.
+///
+/// Returned by [`snapshot`]. Sinks add their metrics in [`LogSink::metrics`](crate::LogSink::metrics).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metrics(pub(crate) Vec<Metric>);
+
+impl Metrics {
+    /// Adds a metric to the snapshot.
+    pub fn push(&mut self, metric: Metric) {
+        self.0.push(metric);
+    }
+
+    /// Returns the number of metrics in the snapshot.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the snapshot is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the metrics.
+    pub fn iter(&self) -> std::slice::Iter<'_, Metric> {
+        self.0.iter()
+    }
+
+    /// Returns the value of the first metric with the given name and labels.
+    ///
+    /// Labels of the metric which are not given are ignored.
+    pub fn get(&self, name: &str, labels: &[(&str, &str)]) -> Option<u64> {
+        self.iter()
+            .find(|m| {
+                m.name == name
+                    && labels
+                        .iter()
+                        .all(|(label, value)| m.label(label) == Some(*value))
+            })
+            .map(|m| m.value)
+    }
+
+    /// Formats the metrics in the Prometheus text exposition format.
+    pub fn to_prometheus(&self) -> String {
+        let mut names: Vec<&str> = Vec::new();
+        for metric in self.iter() {
+            if !names.contains(&metric.name) {
+                names.push(metric.name);
+            }
+        }
+
+        // Metrics with the same name must be grouped together, after their type.
+        let mut out = String::new();
+        for name in names {
+            let mut metrics = self.iter().filter(|m| m.name == name).peekable();
+            let kind = match metrics.peek().map(|m| m.kind) {
+                Some(MetricKind::Gauge) => "gauge",
+                _ => "counter",
+            };
+            if let Some(help) = help(name) {
+                _ = writeln!(out, "# HELP {name} {help}");
+            }
+            _ = writeln!(out, "# TYPE {name} {kind}");
+            for metric in metrics {
+                out.push_str(name);
+                if !metric.labels.is_empty() {
+                    out.push('{');
+                    for (i, (label, value)) in metric.labels.iter().enumerate() {
+                        if i > 0 {
+                            out.push(',');
+                        }
+                        _ = write!(out, "{label}=\"{}\"", escape_label_value(value));
+                    }
+                    out.push('}');
+                }
+                _ = writeln!(out, " {}", metric.value);
+            }
+        }
+        out
+    }
+}
+
+impl<'a> IntoIterator for &'a Metrics {
+    type Item = &'a Metric;
+    type IntoIter = std::slice::Iter<'a, Metric>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+fn help(name: &str) -> Option<&'static str> {
+    Some(match name {
+        CHANNEL_MESSAGES => "Messages logged on a channel.",
+        CHANNEL_BYTES => "Message bytes logged on a channel.",
+        SINK_ERRORS => "Errors returned by a sink when logging a message.",
+        MCAP_BYTES_WRITTEN => "Bytes written by an MCAP writer.",
+        WS_CLIENTS => "Clients connected to a WebSocket server.",
+        WS_CLIENT_QUEUE_DEPTH => "Messages queued to be sent to a WebSocket client.",
+        WS_CLIENT_DROPPED_MESSAGES => "Messages dropped because a WebSocket client fell behind.",
+        _ => return None,
+    })
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Returns a snapshot of the metrics of the channels and sinks in the default context.
+pub fn snapshot() -> Metrics {
+    LogContext::global().metrics()
+}
+
+/// A metrics snapshot, encoded as JSON.
+#[derive(Serialize, JsonSchema)]
+struct MetricsMessage {
+    metrics: Vec<MetricSample>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct MetricSample {
+    name: String,
+    labels: BTreeMap<String, String>,
+    value: u64,
+}
+
+impl From<&Metrics> for MetricsMessage {
+    fn from(metrics: &Metrics) -> Self {
+        let metrics = metrics.iter().map(|m| MetricSample {
+            name: m.name.to_string(),
+            labels: m
+                .labels
+                .iter()
+                .map(|(label, value)| (label.to_string(), value.clone()))
+                .collect(),
+            value: m.value,
+        });
+        Self {
+            metrics: metrics.collect(),
+        }
+    }
+}
+
+/// Publishes metrics snapshots as JSON messages on a channel.
+///
+/// Each message has a `metrics` array of `{"name", "labels", "value"}` objects. By default, the
+/// publisher publishes the metrics of the default context on a channel in the default context. Use
+/// [`new_in`](Self::new_in) to publish the metrics of another context, which can be borrowed or
+/// shared, e.g. as an `Arc<LogContext>`.
+pub struct MetricsPublisher<C = &'static LogContext> {
+    context: C,
+    channel: TypedChannel<MetricsMessage>,
+}
+
+impl MetricsPublisher {
+    /// Creates a publisher which publishes to the [`DEFAULT_TOPIC`].
+    ///
+    /// Returns an error if a channel already exists for the topic.
+    pub fn new() -> Result<Self, FoxgloveError> {
+        Self::with_topic(DEFAULT_TOPIC)
+    }
+
+    /// Creates a publisher which publishes to the given topic.
+    ///
+    /// Returns an error if a channel already exists for the topic.
+    pub fn with_topic(topic: impl Into<String>) -> Result<Self, FoxgloveError> {
+        Self::new_in(LogContext::global(), topic)
+    }
+}
+
+impl<C: Deref<Target = LogContext>> MetricsPublisher<C> {
+    /// Creates a publisher which publishes the metrics of the given context, on a channel with the
+    /// given topic in that context.
+    ///
+    /// Returns an error if a channel already exists for the topic.
+    pub fn new_in(context: C, topic: impl Into<String>) -> Result<Self, FoxgloveError> {
+        let channel = crate::ChannelBuilder::new(topic)
+            .with_context(&context)
+            .build_typed()?;
+        Ok(Self { context, channel })
+    }
+
+    /// Publishes a snapshot of the current metrics.
+    pub fn publish(&self) {
+        self.channel
+            .log(&MetricsMessage::from(&self.context.metrics()));
+    }
+
+    /// Spawns a task which calls [`publish`](Self::publish) at the given interval.
+    ///
+    /// The task stops when the publisher is dropped, or when the returned handle is aborted.
+    pub fn publish_periodically(self: &Arc<Self>, interval: Duration) -> JoinHandle<()>
+    where
+        C: Send + Sync + 'static,
+    {
+        let publisher: Weak<Self> = Arc::downgrade(self);
+        get_runtime_handle().spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let Some(publisher) = publisher.upgrade() else {
+                    break;
+                };
+                publisher.publish();
+            }
+        })
+    }
+}
+
+impl<C> std::fmt::Debug for MetricsPublisher<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetricsPublisher")
+            .field("topic", &self.channel.topic())
+            .finish()
+    }
+}
+
+/// The maximum size of an HTTP request head read by the Prometheus server.
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+
+/// The delay before accepting connections again after a failure, e.g. if the process has run
+/// out of file descriptors.
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Limits for connections to a Prometheus server.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PrometheusOptions {
+    /// The time a connection has to send its request and read the response.
+    pub request_timeout: Duration,
+    /// The maximum number of connections handled at once.
+    ///
+    /// Further connections wait to be accepted until a connection closes or times out.
+    pub max_connections: usize,
+}
+
+impl Default for PrometheusOptions {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(10),
+            max_connections: 16,
+        }
+    }
+}
+
+/// A handle to a server started with [`serve_prometheus`].
+///
+/// The server keeps running if the handle is dropped.
+#[derive(Debug)]
+pub struct PrometheusServerHandle {
+    local_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl PrometheusServerHandle {
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Stops the server.
+    pub fn stop(self) {
+        self.task.abort();
+    }
+}
+
+/// Starts an HTTP server which serves the metrics of the default context at `/metrics`, in the
+/// Prometheus text exposition format.
+///
+/// Bind to port 0 to pick an unused port, and use [`PrometheusServerHandle::local_addr`] to find
+/// it. Each connection must send its request within a few seconds, and only a limited number of
+/// connections are handled at once.
+pub fn serve_prometheus(addr: impl ToSocketAddrs) -> std::io::Result<PrometheusServerHandle> {
+    serve_prometheus_in(LogContext::global(), addr)
+}
+
+/// Starts an HTTP server which serves the metrics of the given context at `/metrics`.
+///
+/// The context can be the default context, or a context shared with the server, e.g. as an
+/// `Arc<LogContext>`. See [`serve_prometheus`].
+pub fn serve_prometheus_in<C>(
+    context: C,
+    addr: impl ToSocketAddrs,
+) -> std::io::Result<PrometheusServerHandle>
+where
+    C: Deref<Target = LogContext> + Send + Sync + 'static,
+{
+    serve_prometheus_with(context, addr, PrometheusOptions::default())
+}
+
+/// Starts a Prometheus server with the given connection limits. See [`serve_prometheus_in`].
+pub(crate) fn serve_prometheus_with<C>(
+    context: C,
+    addr: impl ToSocketAddrs,
+    options: PrometheusOptions,
+) -> std::io::Result<PrometheusServerHandle>
+where
+    C: Deref<Target = LogContext> + Send + Sync + 'static,
+{
+    let listener = std::net::TcpListener::bind(addr)?;
+    listener.set_nonblocking(true)?;
+    let local_addr = listener.local_addr()?;
+    let runtime = get_runtime_handle();
+    let listener = {
+        let _guard = runtime.enter();
+        TcpListener::from_std(listener)?
+    };
+    let context = Arc::new(context);
+    let connections = Arc::new(Semaphore::new(options.max_connections));
+    let task = runtime.spawn(async move {
+        loop {
+            let Ok(permit) = connections.clone().acquire_owned().await else {
+                break;
+            };
+            let (stream, addr) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(err) => {
+                    tracing::warn!("Failed to accept metrics connection: {err}");
+                    tokio::time::sleep(ACCEPT_ERROR_BACKOFF).await;
+                    continue;
+                }
+            };
+            let context = context.clone();
+            tokio::spawn(async move {
+                let request = handle_request(&context, stream);
+                match tokio::time::timeout(options.request_timeout, request).await {
+                    Ok(Ok(())) => (),
+                    Ok(Err(err)) => tracing::debug!("Error serving metrics to {addr}: {err}"),
+                    Err(_) => tracing::debug!("Timed out serving metrics to {addr}"),
+                }
+                drop(permit);
+            });
+        }
+    });
+    Ok(PrometheusServerHandle { local_addr, task })
+}
+
+async fn handle_request(context: &LogContext, mut stream: TcpStream) -> std::io::Result<()> {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < MAX_REQUEST_SIZE {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        request.extend_from_slice(&buf[..n]);
+    }
+
+    let response = if request.starts_with(b"GET /metrics ") {
+        let body = context.metrics().to_prometheus();
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    } else {
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+    };
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+    use super::*;
+    use crate::testutil::{ErrorSink, RecordingSink};
+    use crate::{Channel, ChannelBuilder, LogSink};
+
+    fn channel(ctx: &LogContext, topic: &str) -> Arc<Channel> {
+        ChannelBuilder::new(topic)
+            .message_encoding("json")
+            .with_context(ctx)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_context_metrics() {
+        let ctx = LogContext::new();
+        let a = channel(&ctx, "/a");
+        let b = channel(&ctx, "/b");
+        assert!(ctx.add_sink(Arc::new(RecordingSink::new())));
+        assert!(ctx.add_sink(Arc::new(ErrorSink)));
+        a.log(b"123");
+        a.log(b"45");
+        b.log(b"6");
+
+        let metrics = ctx.metrics();
+        assert_eq!(metrics.get(CHANNEL_MESSAGES, &[("topic", "/a")]), Some(2));
+        assert_eq!(metrics.get(CHANNEL_BYTES, &[("topic", "/a")]), Some(5));
+        assert_eq!(metrics.get(CHANNEL_BYTES, &[("topic", "/b")]), Some(1));
+        assert_eq!(a.messages_logged(), 2);
+        assert_eq!(
+            metrics.get(SINK_ERRORS, &[("sink", "RecordingSink")]),
+            Some(0)
+        );
+        assert_eq!(
+            metrics.get(SINK_ERRORS, &[("sink", "ErrorSink"), ("sink_index", "1")]),
+            Some(3)
+        );
+    }
+
+    struct CustomSink(AtomicU64);
+
+    impl LogSink for CustomSink {
+        fn log(
+            &self,
+            _channel: &Arc<Channel>,
+            _msg: &[u8],
+            _metadata: &crate::Metadata,
+        ) -> Result<(), FoxgloveError> {
+            self.0.fetch_add(1, Relaxed);
+            Ok(())
+        }
+
+        fn metrics(&self, metrics: &mut Metrics) {
+            metrics.push(Metric::gauge("custom", self.0.load(Relaxed)).with_label("a", "b"));
+        }
+    }
+
+    #[test]
+    fn test_sink_metrics_are_labeled() {
+        let ctx = LogContext::new();
+        assert!(ctx.add_sink(Arc::new(CustomSink(AtomicU64::new(0)))));
+        channel(&ctx, "/a").log(b"");
+
+        let metrics = ctx.metrics();
+        let custom = metrics.iter().find(|m| m.name == "custom").unwrap();
+        assert_eq!(custom.kind, MetricKind::Gauge);
+        assert_eq!(custom.value, 1);
+        assert_eq!(
+            custom.labels,
+            [
+                ("sink", "CustomSink".to_string()),
+                ("sink_index", "0".to_string()),
+                ("a", "b".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_prometheus() {
+        let mut metrics = Metrics::default();
+        metrics.push(Metric::counter(CHANNEL_MESSAGES, 2).with_label("topic", "/a"));
+        metrics.push(Metric::gauge("custom", 7));
+        metrics.push(Metric::counter(CHANNEL_MESSAGES, 3).with_label("topic", "/\"b\"\n"));
+        assert_eq!(
+            metrics.to_prometheus(),
+            "# HELP foxglove_channel_messages_total Messages logged on a channel.\n\
+             # TYPE foxglove_channel_messages_total counter\n\
+             foxglove_channel_messages_total{topic=\"/a\"} 2\n\
+             foxglove_channel_messages_total{topic=\"/\\\"b\\\"\\n\"} 3\n\
+             # TYPE custom gauge\n\
+             custom 7\n"
+        );
+    }
+
+    #[test]
+    fn test_metrics_publisher() {
+        let ctx = LogContext::new();
+        let sink = Arc::new(RecordingSink::new());
+        assert!(ctx.add_sink(sink.clone()));
+        channel(&ctx, "/a").log(b"123");
+        let publisher = MetricsPublisher::new_in(&ctx, DEFAULT_TOPIC).unwrap();
+        publisher.publish();
+
+        let recorded = sink.recorded.lock();
+        let last = recorded.last().unwrap();
+        assert_eq!(last.channel.topic(), DEFAULT_TOPIC);
+        let msg: serde_json::Value = serde_json::from_slice(&last.msg).unwrap();
+        assert!(msg["metrics"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!({
+                "name": CHANNEL_BYTES,
+                "labels": {"topic": "/a"},
+                "value": 3,
+            })));
+    }
+
+    #[tokio::test]
+    async fn test_serve_prometheus() {
+        let ctx = Arc::new(LogContext::new());
+        channel(&ctx, "/a").log(b"123");
+        let options = PrometheusOptions {
+            request_timeout: Duration::from_millis(200),
+            max_connections: 2,
+        };
+        let server = serve_prometheus_with(ctx, "127.0.0.1:0", options).unwrap();
+        let addr = server.local_addr();
+
+        let get = |path: &'static str| async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            let request = format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n");
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+        let response = get("/metrics").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with("foxglove_channel_bytes_total{topic=\"/a\"} 3\n"));
+        assert!(get("/").await.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+        // Idle connections hold the connection slots until they time out.
+        let mut idle = vec![];
+        for _ in 0..options.max_connections {
+            idle.push(TcpStream::connect(addr).await.unwrap());
+        }
+        let start = tokio::time::Instant::now();
+        assert!(get("/metrics").await.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(start.elapsed() >= options.request_timeout / 2);
+        for mut stream in idle {
+            let mut buf = vec![];
+            assert_eq!(stream.read_to_end(&mut buf).await.unwrap(), 0);
+        }
+        server.stop();
+    }
+}
@@ -0,0 +1,514 @@
+//! OMG IDL message encoding for well-known schemas.
+//!
+//! By default, the [well-known schemas](crate::schemas) are encoded as protobuf. Wrapping a
+//! message in [`OmgIdl`] encodes it as XCDR2 instead, with an `omgidl` schema, so that it can be
+//! exchanged with DDS-based systems as a `foxglove` IDL type.
+//!
+//! ```no_run
+//! use foxglove::omgidl::OmgIdl;
+//! use foxglove::schemas::Log;
+//! use foxglove::TypedChannel;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = TypedChannel::<OmgIdl<Log>>::new("/log")?;
+//! channel.log(&OmgIdl(Log {
+//!     message: "Hello, DDS!".to_string(),
+//!     ..Default::default()
+//! }));
+//! # Ok(()) }
+//! ```
+//!
+//! The well-known types do not declare an extensibility kind, so they are appendable structs.
+//! Messages are encoded as delimited XCDR2, in which every struct, including the message itself,
+//! is preceded by a delimiter header (DHEADER) holding its size in bytes.
+//!
+//! This module requires the `omgidl` feature.
+
+use bytes::BufMut;
+use thiserror::Error;
+
+use crate::schemas::{Duration, Timestamp};
+use crate::time::normalize;
+use crate::{Encode, Schema};
+
+/// The encapsulation header for little-endian delimited XCDR2, used for appendable types.
+///
+/// The last byte holds the encapsulation options, which are filled in when the message is
+/// complete.
+const DELIMITED_CDR2_LE_HEADER: [u8; 4] = [0x00, 0x09, 0x00, 0x00];
+
+/// The maximum alignment of a primitive value in XCDR2.
+const MAX_ALIGNMENT: usize = 4;
+
+/// An error encountered while serializing a message as XCDR2.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum Xcdr2Error {
+    /// A string or sequence is too long to be encoded.
+    #[error("Sequence length {0} exceeds the maximum length")]
+    SequenceTooLong(usize),
+    /// A fixed-size array has the wrong number of elements.
+    #[error("Expected an array of {expected} elements, got {actual}")]
+    ArrayLengthMismatch {
+        /// The length of the array in the type definition.
+        expected: usize,
+        /// The length of the array in the message.
+        actual: usize,
+    },
+    /// A time or duration cannot be represented as a `foxglove::Time` or `foxglove::Duration`.
+    #[error("Time {0}s is out of range")]
+    TimeOutOfRange(i64),
+}
+
+/// A writer for little-endian, delimited XCDR2-encoded data.
+#[derive(Debug)]
+pub struct Xcdr2Writer {
+    buf: Vec<u8>,
+}
+
+impl Default for Xcdr2Writer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Xcdr2Writer {
+    /// Creates a new writer, beginning with the encapsulation header.
+    pub fn new() -> Self {
+        Self {
+            buf: DELIMITED_CDR2_LE_HEADER.to_vec(),
+        }
+    }
+
+    /// Returns the encoded data.
+    ///
+    /// The message is padded to a multiple of 4 bytes, and the amount of padding is recorded in
+    /// the encapsulation options.
+    pub fn into_inner(mut self) -> Vec<u8> {
+        let len = self.buf.len();
+        self.align(MAX_ALIGNMENT);
+        self.buf[3] = (self.buf.len() - len) as u8;
+        self.buf
+    }
+
+    /// Pads the buffer so that the next value is aligned to `size` bytes, up to the maximum
+    /// alignment of 4 bytes.
+    ///
+    /// Alignment is relative to the end of the encapsulation header.
+    fn align(&mut self, size: usize) {
+        let size = size.min(MAX_ALIGNMENT);
+        let offset = self.buf.len() - DELIMITED_CDR2_LE_HEADER.len();
+        let padding = (size - offset % size) % size;
+        self.buf.resize(self.buf.len() + padding, 0);
+    }
+
+    /// Writes an appendable struct with `f`, preceded by a delimiter header (DHEADER) holding its
+    /// size in bytes.
+    fn write_delimited(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<(), Xcdr2Error>,
+    ) -> Result<(), Xcdr2Error> {
+        self.write_u32(0);
+        let start = self.buf.len();
+        f(self)?;
+        let size = self.buf.len() - start;
+        let size = u32::try_from(size).map_err(|_| Xcdr2Error::SequenceTooLong(size))?;
+        self.buf[start - 4..start].copy_from_slice(&size.to_le_bytes());
+        Ok(())
+    }
+
+    /// Writes a sequence length.
+    fn write_len(&mut self, len: usize) -> Result<(), Xcdr2Error> {
+        let len = u32::try_from(len).map_err(|_| Xcdr2Error::SequenceTooLong(len))?;
+        self.write_u32(len);
+        Ok(())
+    }
+
+    /// Writes a `boolean`.
+    pub fn write_bool(&mut self, value: bool) {
+        self.buf.push(u8::from(value));
+    }
+
+    /// Writes a `uint8`.
+    pub fn write_u8(&mut self, value: u8) {
+        self.buf.push(value);
+    }
+
+    /// Writes a `uint32`.
+    pub fn write_u32(&mut self, value: u32) {
+        self.align(4);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes an `int32`.
+    pub fn write_i32(&mut self, value: i32) {
+        self.align(4);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes a `double`.
+    pub fn write_f64(&mut self, value: f64) {
+        self.align(8);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes an enum, which has a 32-bit representation.
+    pub fn write_enum(&mut self, value: i32) {
+        self.write_i32(value);
+    }
+
+    /// Writes a `string`.
+    pub fn write_string(&mut self, value: &str) -> Result<(), Xcdr2Error> {
+        // The length includes the null terminator.
+        self.write_len(value.len() + 1)?;
+        self.buf.extend_from_slice(value.as_bytes());
+        self.buf.push(0);
+        Ok(())
+    }
+
+    /// Writes a `sequence<uint8>`.
+    pub fn write_bytes(&mut self, value: &[u8]) -> Result<(), Xcdr2Error> {
+        self.write_len(value.len())?;
+        self.buf.extend_from_slice(value);
+        Ok(())
+    }
+
+    /// Writes a `foxglove::Time`.
+    ///
+    /// A missing timestamp is written as zero.
+    pub fn write_time(&mut self, value: Option<&Timestamp>) -> Result<(), Xcdr2Error> {
+        let (seconds, nanos) = value.map_or((0, 0), |t| (t.seconds, t.nanos));
+        let (sec, nsec) = normalize(seconds, nanos);
+        let sec = u32::try_from(sec).map_err(|_| Xcdr2Error::TimeOutOfRange(seconds))?;
+        self.write_delimited(|w| {
+            w.write_u32(sec);
+            w.write_u32(nsec);
+            Ok(())
+        })
+    }
+
+    /// Writes a `foxglove::Duration`.
+    ///
+    /// A missing duration is written as zero.
+    pub fn write_duration(&mut self, value: Option<&Duration>) -> Result<(), Xcdr2Error> {
+        let (seconds, nanos) = value.map_or((0, 0), |d| (d.seconds, d.nanos));
+        let (sec, nsec) = normalize(seconds, nanos);
+        let sec = i32::try_from(sec).map_err(|_| Xcdr2Error::TimeOutOfRange(seconds))?;
+        self.write_delimited(|w| {
+            w.write_i32(sec);
+            w.write_u32(nsec);
+            Ok(())
+        })
+    }
+
+    /// Writes a nested struct, preceded by its DHEADER.
+    ///
+    /// A missing struct is written with default values.
+    pub fn write_struct<T: Xcdr2Serialize + Default>(
+        &mut self,
+        value: Option<&T>,
+    ) -> Result<(), Xcdr2Error> {
+        self.write_delimited(|w| match value {
+            Some(value) => value.serialize(w),
+            None => T::default().serialize(w),
+        })
+    }
+
+    /// Writes a sequence of primitive values.
+    pub fn write_sequence<T: Xcdr2Serialize>(&mut self, values: &[T]) -> Result<(), Xcdr2Error> {
+        self.write_len(values.len())?;
+        values.iter().try_for_each(|v| v.serialize(self))
+    }
+
+    /// Writes a sequence of structs.
+    ///
+    /// Unlike sequences of primitive values, the sequence is preceded by a DHEADER, as is each
+    /// struct in the sequence.
+    pub fn write_struct_sequence<T: Xcdr2Serialize>(
+        &mut self,
+        values: &[T],
+    ) -> Result<(), Xcdr2Error> {
+        self.write_delimited(|w| {
+            w.write_len(values.len())?;
+            values
+                .iter()
+                .try_for_each(|v| w.write_delimited(|w| v.serialize(w)))
+        })
+    }
+
+    /// Writes a fixed-size array of `double`.
+    pub fn write_array(&mut self, values: &[f64], len: usize) -> Result<(), Xcdr2Error> {
+        if values.len() != len {
+            return Err(Xcdr2Error::ArrayLengthMismatch {
+                expected: len,
+                actual: values.len(),
+            });
+        }
+        values.iter().for_each(|v| self.write_f64(*v));
+        Ok(())
+    }
+}
+
+/// A type that can be serialized as XCDR2.
+pub trait Xcdr2Serialize {
+    /// Serializes the value to the writer.
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error>;
+}
+
+impl Xcdr2Serialize for u32 {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_u32(*self);
+        Ok(())
+    }
+}
+
+impl Xcdr2Serialize for f64 {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_f64(*self);
+        Ok(())
+    }
+}
+
+/// A message with an OMG IDL type definition.
+pub trait OmgIdlMessage: Xcdr2Serialize {
+    /// The fully-qualified type name, e.g. `foxglove::Log`.
+    const TYPE_NAME: &'static str;
+    /// The type definition, preceded by the definitions of its dependencies.
+    const DEFINITION: &'static str;
+}
+
+/// Wraps a message so that it is encoded as XCDR2, with an `omgidl` schema.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OmgIdl<T>(pub T);
+
+impl<T> From<T> for OmgIdl<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: OmgIdlMessage> Encode for OmgIdl<T> {
+    type Error = Xcdr2Error;
+
+    fn get_schema() -> Option<Schema> {
+        Some(Schema::new(
+            T::TYPE_NAME,
+            "omgidl",
+            T::DEFINITION.as_bytes(),
+        ))
+    }
+
+    fn get_message_encoding() -> String {
+        "cdr".to_string()
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) -> Result<(), Self::Error> {
+        let mut w = Xcdr2Writer::new();
+        w.write_delimited(|w| self.0.serialize(w))?;
+        buf.put_slice(&w.into_inner());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::log::Level;
+    use crate::schemas::scene_entity_deletion::Type as DeletionType;
+    use crate::schemas::{
+        CameraCalibration, Color, KeyValuePair, Log, Pose, SceneEntity, SceneEntityDeletion,
+        SceneUpdate, Vector3,
+    };
+
+    fn encode<T: OmgIdlMessage>(msg: T) -> Vec<u8> {
+        let mut buf = Vec::new();
+        OmgIdl(msg).encode(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_encode_log() {
+        let data = encode(Log {
+            timestamp: Some(Timestamp {
+                seconds: 1,
+                nanos: 2,
+            }),
+            level: Level::Info as i32,
+            message: "hi".to_string(),
+            name: String::new(),
+            file: "a".to_string(),
+            line: 7,
+        });
+        #[rustfmt::skip]
+        let expected = [
+            0x00, 0x09, 0x00, 0x00, // header
+            44, 0, 0, 0, // DHEADER
+            8, 0, 0, 0, // timestamp DHEADER
+            1, 0, 0, 0, // timestamp.sec
+            2, 0, 0, 0, // timestamp.nsec
+            2, 0, 0, 0, // level
+            3, 0, 0, 0, b'h', b'i', 0, // message
+            0, // padding
+            1, 0, 0, 0, 0, // name
+            0, 0, 0, // padding
+            2, 0, 0, 0, b'a', 0, // file
+            0, 0, // padding
+            7, 0, 0, 0, // line
+        ];
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn test_encode_alignment_and_defaults() {
+        let data = encode(Pose {
+            position: Some(Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            }),
+            orientation: None,
+        });
+        let mut expected = DELIMITED_CDR2_LE_HEADER.to_vec();
+        expected.extend_from_slice(&64u32.to_le_bytes());
+        expected.extend_from_slice(&24u32.to_le_bytes());
+        for v in [1.0f64, 2.0, 3.0] {
+            expected.extend_from_slice(&v.to_le_bytes());
+        }
+        expected.extend_from_slice(&32u32.to_le_bytes());
+        for v in [0.0f64, 0.0, 0.0, 0.0] {
+            expected.extend_from_slice(&v.to_le_bytes());
+        }
+        assert_eq!(data, expected);
+
+        let mut w = Xcdr2Writer::new();
+        w.write_bool(true);
+        Color::default().serialize(&mut w).unwrap();
+        // The double fields are aligned to 4 bytes, and the message is padded to 4 bytes.
+        let data = w.into_inner();
+        assert_eq!(data.len(), 4 + 4 + 32);
+        assert_eq!(data[3], 0);
+
+        let mut w = Xcdr2Writer::new();
+        w.write_bool(true);
+        let data = w.into_inner();
+        assert_eq!(data, [0x00, 0x09, 0x00, 0x03, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_encode_sequences() {
+        let data = encode(SceneUpdate {
+            deletions: vec![SceneEntityDeletion {
+                timestamp: Some(Timestamp {
+                    seconds: 1,
+                    nanos: 2,
+                }),
+                r#type: DeletionType::All as i32,
+                id: "a".to_string(),
+            }],
+            entities: vec![SceneEntity {
+                id: "e".to_string(),
+                frame_locked: true,
+                metadata: vec![KeyValuePair {
+                    key: "k".to_string(),
+                    value: "v".to_string(),
+                }],
+                ..Default::default()
+            }],
+        });
+        #[rustfmt::skip]
+        let mut expected = vec![
+            0x00, 0x09, 0x00, 0x00, // header
+            184, 0, 0, 0, // DHEADER
+            30, 0, 0, 0, // deletions DHEADER
+            1, 0, 0, 0, // deletions length
+            22, 0, 0, 0, // deletions[0] DHEADER
+            8, 0, 0, 0, // deletions[0].timestamp DHEADER
+            1, 0, 0, 0, // deletions[0].timestamp.sec
+            2, 0, 0, 0, // deletions[0].timestamp.nsec
+            1, 0, 0, 0, // deletions[0].type
+            2, 0, 0, 0, b'a', 0, // deletions[0].id
+            0, 0, // padding
+            144, 0, 0, 0, // entities DHEADER
+            1, 0, 0, 0, // entities length
+            136, 0, 0, 0, // entities[0] DHEADER
+            8, 0, 0, 0, // entities[0].timestamp DHEADER
+            0, 0, 0, 0, // entities[0].timestamp.sec
+            0, 0, 0, 0, // entities[0].timestamp.nsec
+            1, 0, 0, 0, 0, // entities[0].frame_id
+            0, 0, 0, // padding
+            2, 0, 0, 0, b'e', 0, // entities[0].id
+            0, 0, // padding
+            8, 0, 0, 0, // entities[0].lifetime DHEADER
+            0, 0, 0, 0, // entities[0].lifetime.sec
+            0, 0, 0, 0, // entities[0].lifetime.nsec
+            1, // entities[0].frame_locked
+            0, 0, 0, // padding
+            22, 0, 0, 0, // entities[0].metadata DHEADER
+            1, 0, 0, 0, // entities[0].metadata length
+            14, 0, 0, 0, // entities[0].metadata[0] DHEADER
+            2, 0, 0, 0, b'k', 0, // entities[0].metadata[0].key
+            0, 0, // padding
+            2, 0, 0, 0, b'v', 0, // entities[0].metadata[0].value
+            0, 0, // padding
+        ];
+        // Empty sequences of arrows, cubes, spheres, cylinders, lines, triangles, texts, and
+        // models, each with a DHEADER and a length.
+        for _ in 0..8 {
+            expected.extend_from_slice(&[4, 0, 0, 0, 0, 0, 0, 0]);
+        }
+        assert_eq!(data, expected);
+
+        let calibration = CameraCalibration {
+            k: vec![],
+            ..Default::default()
+        };
+        let mut w = Xcdr2Writer::new();
+        assert!(matches!(
+            calibration.serialize(&mut w),
+            Err(Xcdr2Error::ArrayLengthMismatch {
+                expected: 9,
+                actual: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn test_time_and_duration() {
+        let mut w = Xcdr2Writer::new();
+        w.write_duration(Some(&Duration {
+            seconds: -1,
+            nanos: -500_000_000,
+        }))
+        .unwrap();
+        let data = w.into_inner();
+        assert_eq!(&data[4..8], &8u32.to_le_bytes());
+        assert_eq!(&data[8..12], &(-2i32).to_le_bytes());
+        assert_eq!(&data[12..16], &500_000_000u32.to_le_bytes());
+
+        let mut w = Xcdr2Writer::new();
+        assert!(matches!(
+            w.write_time(Some(&Timestamp {
+                seconds: -1,
+                nanos: 0
+            })),
+            Err(Xcdr2Error::TimeOutOfRange(-1))
+        ));
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = OmgIdl::<Log>::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove::Log");
+        assert_eq!(schema.encoding, "omgidl");
+        let definition = std::str::from_utf8(&schema.data).unwrap();
+        assert!(!definition.contains("#include"));
+        // Dependencies are declared before they are used.
+        let log_level = definition.find("enum LogLevel").unwrap();
+        let time = definition.find("struct Time").unwrap();
+        let log = definition.find("struct Log").unwrap();
+        assert!(log_level < log && time < log);
+        assert_eq!(OmgIdl::<Log>::get_message_encoding(), "cdr");
+    }
+}
@@ -0,0 +1,565 @@
+//! Typed point cloud layouts.
+//!
+//! A [`PointCloud`] stores points as packed bytes, described by a list of [`PackedElementField`]s.
+//! This module provides a typed API on top of that representation: a point struct implements
+//! [`PackedPoint`] to declare its fields, and can then be packed into a cloud with
+//! [`PointCloud::builder`], or read back out of a cloud with [`PointCloud::points`].
+//!
+//! With the `derive` feature, `#[derive(PackedPoint)]` implements [`PackedPoint`] for a struct
+//! with named fields of the types that implement [`PackedScalar`]. Fields are packed in
+//! declaration order, without padding.
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use foxglove::point_cloud::PackedPoint;
+//! use foxglove::schemas::PointCloud;
+//!
+//! #[derive(PackedPoint, Debug, PartialEq)]
+//! struct Point {
+//!     x: f32,
+//!     y: f32,
+//!     z: f32,
+//!     intensity: u8,
+//! }
+//!
+//! let points = [
+//!     Point { x: 1.0, y: 2.0, z: 3.0, intensity: 100 },
+//!     Point { x: 4.0, y: 5.0, z: 6.0, intensity: 200 },
+//! ];
+//! let cloud = PointCloud::builder(&points).frame("lidar").build();
+//! assert_eq!(cloud.point_stride, 13);
+//!
+//! let read: Vec<Point> = cloud.points().unwrap().collect();
+//! assert_eq!(read, points);
+//! # }
+//! ```
+//!
+//! Reading a cloud matches fields by name, so the point type only needs to declare the fields it
+//! is interested in, in any order. Each field must be present in the cloud with the same type.
+
+use thiserror::Error;
+
+use crate::schemas::packed_element_field::NumericType;
+use crate::schemas::{PackedElementField, PointCloud, Pose, Timestamp};
+
+#[cfg(feature = "derive")]
+pub use foxglove_derive::PackedPoint;
+
+/// An error encountered while reading points from a [`PointCloud`].
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PointCloudError {
+    /// The data length is not a multiple of the point stride.
+    #[error("Point cloud data length {len} is not a multiple of the point stride {stride}")]
+    InvalidStride {
+        /// The point stride, in bytes.
+        stride: u32,
+        /// The length of the point data, in bytes.
+        len: usize,
+    },
+    /// The point type has a field which is not present in the cloud.
+    #[error("Point cloud has no field named {0}")]
+    MissingField(String),
+    /// A field in the cloud has a different type than the point type expects.
+    #[error("Point cloud field {name} has type {actual}, expected {expected:?}")]
+    FieldType {
+        /// The name of the field.
+        name: String,
+        /// The type expected by the point type.
+        expected: NumericType,
+        /// The raw type of the field in the cloud.
+        actual: i32,
+    },
+    /// A field in the cloud extends past the end of the point stride.
+    #[error("Point cloud field {name} at offset {offset} does not fit in the point stride")]
+    FieldOutOfBounds {
+        /// The name of the field.
+        name: String,
+        /// The offset of the field, in bytes.
+        offset: u32,
+    },
+}
+
+/// A scalar type which can be stored in a point cloud field.
+///
+/// Values are stored in little-endian byte order.
+pub trait PackedScalar: Copy + sealed::Sealed {
+    /// The type of the field.
+    const TYPE: NumericType;
+    /// The size of the value, in bytes.
+    const SIZE: usize;
+
+    #[doc(hidden)]
+    fn write_le(self, buf: &mut Vec<u8>);
+
+    #[doc(hidden)]
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_packed_scalar {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl PackedScalar for $ty {
+                const TYPE: NumericType = NumericType::$variant;
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn write_le(self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    let bytes = bytes[..Self::SIZE].try_into().expect("sized slice");
+                    <$ty>::from_le_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_scalar! {
+    u8 => Uint8,
+    i8 => Int8,
+    u16 => Uint16,
+    i16 => Int16,
+    u32 => Uint32,
+    i32 => Int32,
+    f32 => Float32,
+    f64 => Float64,
+}
+
+/// Returns the size of a field of the given type, in bytes.
+fn type_size(ty: NumericType) -> usize {
+    match ty {
+        NumericType::Unknown => 0,
+        NumericType::Uint8 | NumericType::Int8 => 1,
+        NumericType::Uint16 | NumericType::Int16 => 2,
+        NumericType::Uint32 | NumericType::Int32 | NumericType::Float32 => 4,
+        NumericType::Float64 => 8,
+    }
+}
+
+/// A point type with a fixed set of named fields.
+///
+/// This is usually implemented with `#[derive(PackedPoint)]`. A manual implementation declares
+/// its fields in [`FIELDS`](Self::FIELDS), and must write and read them in the same order and with
+/// the same types:
+///
+/// ```
+/// use foxglove::point_cloud::{FieldReader, FieldWriter, PackedPoint};
+/// use foxglove::schemas::packed_element_field::NumericType;
+///
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// impl PackedPoint for Point {
+///     const FIELDS: &'static [(&'static str, NumericType)] =
+///         &[("x", NumericType::Float64), ("y", NumericType::Float64)];
+///
+///     fn write_fields(&self, writer: &mut FieldWriter<'_>) {
+///         writer.write(self.x);
+///         writer.write(self.y);
+///     }
+///
+///     fn read_fields(reader: &mut FieldReader<'_>) -> Self {
+///         Self {
+///             x: reader.read(),
+///             y: reader.read(),
+///         }
+///     }
+/// }
+/// ```
+pub trait PackedPoint: Sized {
+    /// The name and type of each field, in order.
+    const FIELDS: &'static [(&'static str, NumericType)];
+
+    /// Writes each field of the point, in order.
+    fn write_fields(&self, writer: &mut FieldWriter<'_>);
+
+    /// Reads each field of the point, in order.
+    fn read_fields(reader: &mut FieldReader<'_>) -> Self;
+
+    /// Returns the packed layout of the point's fields, and the point stride.
+    fn layout() -> (Vec<PackedElementField>, u32) {
+        let mut offset = 0;
+        let fields = Self::FIELDS
+            .iter()
+            .map(|&(name, ty)| {
+                let field = PackedElementField {
+                    name: name.to_string(),
+                    offset,
+                    r#type: ty.into(),
+                };
+                offset += type_size(ty) as u32;
+                field
+            })
+            .collect();
+        (fields, offset)
+    }
+}
+
+/// Writes the fields of a point into a [`PointCloud`].
+pub struct FieldWriter<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl FieldWriter<'_> {
+    /// Writes the next field.
+    pub fn write<T: PackedScalar>(&mut self, value: T) {
+        value.write_le(self.buf);
+    }
+}
+
+/// Reads the fields of a point from a [`PointCloud`].
+pub struct FieldReader<'a> {
+    point: &'a [u8],
+    offsets: &'a [usize],
+    next: usize,
+}
+
+impl FieldReader<'_> {
+    /// Reads the next field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the point type reads more fields than it declares. Reading a field with a different
+    /// type than declared returns an unspecified value, and may panic.
+    pub fn read<T: PackedScalar>(&mut self) -> T {
+        let offset = self.offsets[self.next];
+        self.next += 1;
+        T::read_le(&self.point[offset..offset + T::SIZE])
+    }
+}
+
+/// An iterator over the points in a [`PointCloud`].
+///
+/// Created by [`PointCloud::points`].
+pub struct Points<'a, P> {
+    chunks: std::slice::ChunksExact<'a, u8>,
+    offsets: Vec<usize>,
+    _point: std::marker::PhantomData<fn() -> P>,
+}
+
+impl<P: PackedPoint> Iterator for Points<'_, P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        let point = self.chunks.next()?;
+        let mut reader = FieldReader {
+            point,
+            offsets: &self.offsets,
+            next: 0,
+        };
+        Some(P::read_fields(&mut reader))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<P: PackedPoint> ExactSizeIterator for Points<'_, P> {}
+
+/// A builder for a [`PointCloud`] of typed points.
+///
+/// Created by [`PointCloud::builder`].
+#[must_use]
+pub struct PointCloudBuilder<'a, P> {
+    points: &'a [P],
+    timestamp: Option<Timestamp>,
+    frame_id: String,
+    pose: Pose,
+}
+
+impl<P: PackedPoint> PointCloudBuilder<'_, P> {
+    /// Sets the timestamp of the point cloud.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the frame of reference.
+    pub fn frame(mut self, frame_id: impl Into<String>) -> Self {
+        self.frame_id = frame_id.into();
+        self
+    }
+
+    /// Sets the origin of the point cloud relative to the frame of reference.
+    ///
+    /// By default, this is the identity pose.
+    pub fn pose(mut self, pose: Pose) -> Self {
+        self.pose = pose;
+        self
+    }
+
+    /// Packs the points and builds the point cloud.
+    pub fn build(self) -> PointCloud {
+        let (fields, point_stride) = P::layout();
+        let mut data = Vec::with_capacity(self.points.len() * point_stride as usize);
+        for point in self.points {
+            P::write_fields(point, &mut FieldWriter { buf: &mut data });
+        }
+        PointCloud {
+            timestamp: self.timestamp,
+            frame_id: self.frame_id,
+            pose: Some(self.pose),
+            point_stride,
+            fields,
+            data,
+        }
+    }
+}
+
+impl PointCloud {
+    /// Returns a builder for a point cloud containing the given points.
+    pub fn builder<P: PackedPoint>(points: &[P]) -> PointCloudBuilder<'_, P> {
+        PointCloudBuilder {
+            points,
+            timestamp: None,
+            frame_id: String::new(),
+            pose: Pose::identity(),
+        }
+    }
+
+    /// Returns an iterator which reads the points in the cloud as `P`.
+    ///
+    /// Fields are matched by name. Returns an error if the point stride does not evenly divide the
+    /// data, or if a field of `P` is missing, has a different type, or does not fit in the stride.
+    pub fn points<P: PackedPoint>(&self) -> Result<Points<'_, P>, PointCloudError> {
+        let stride = self.point_stride as usize;
+        if stride == 0 || !self.data.len().is_multiple_of(stride) {
+            return Err(PointCloudError::InvalidStride {
+                stride: self.point_stride,
+                len: self.data.len(),
+            });
+        }
+
+        let offsets = P::FIELDS
+            .iter()
+            .map(|&(name, expected)| {
+                let field = self
+                    .fields
+                    .iter()
+                    .find(|f| f.name == name)
+                    .ok_or_else(|| PointCloudError::MissingField(name.to_string()))?;
+                if field.r#type != i32::from(expected) {
+                    return Err(PointCloudError::FieldType {
+                        name: name.to_string(),
+                        expected,
+                        actual: field.r#type,
+                    });
+                }
+                let offset = field.offset as usize;
+                if offset + type_size(expected) > stride {
+                    return Err(PointCloudError::FieldOutOfBounds {
+                        name: name.to_string(),
+                        offset: field.offset,
+                    });
+                }
+                Ok(offset)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Points {
+            chunks: self.data.chunks_exact(stride),
+            offsets,
+            _point: std::marker::PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Rgba {
+        x: f32,
+        y: f32,
+        z: f32,
+        red: u8,
+        green: u8,
+        blue: u8,
+        alpha: u8,
+    }
+
+    impl PackedPoint for Rgba {
+        const FIELDS: &'static [(&'static str, NumericType)] = &[
+            ("x", NumericType::Float32),
+            ("y", NumericType::Float32),
+            ("z", NumericType::Float32),
+            ("red", NumericType::Uint8),
+            ("green", NumericType::Uint8),
+            ("blue", NumericType::Uint8),
+            ("alpha", NumericType::Uint8),
+        ];
+
+        fn write_fields(&self, writer: &mut FieldWriter<'_>) {
+            writer.write(self.x);
+            writer.write(self.y);
+            writer.write(self.z);
+            writer.write(self.red);
+            writer.write(self.green);
+            writer.write(self.blue);
+            writer.write(self.alpha);
+        }
+
+        fn read_fields(reader: &mut FieldReader<'_>) -> Self {
+            Self {
+                x: reader.read(),
+                y: reader.read(),
+                z: reader.read(),
+                red: reader.read(),
+                green: reader.read(),
+                blue: reader.read(),
+                alpha: reader.read(),
+            }
+        }
+    }
+
+    // A subset of the fields, in a different order.
+    #[derive(Debug, PartialEq)]
+    struct Zx {
+        z: f32,
+        x: f32,
+    }
+
+    impl PackedPoint for Zx {
+        const FIELDS: &'static [(&'static str, NumericType)] =
+            &[("z", NumericType::Float32), ("x", NumericType::Float32)];
+
+        fn write_fields(&self, writer: &mut FieldWriter<'_>) {
+            writer.write(self.z);
+            writer.write(self.x);
+        }
+
+        fn read_fields(reader: &mut FieldReader<'_>) -> Self {
+            Self {
+                z: reader.read(),
+                x: reader.read(),
+            }
+        }
+    }
+
+    fn point(i: u8) -> Rgba {
+        let f = f32::from(i);
+        Rgba {
+            x: f,
+            y: -f,
+            z: f * 2.0,
+            red: i,
+            green: 255 - i,
+            blue: 0,
+            alpha: 255,
+        }
+    }
+
+    #[test]
+    fn test_build_and_read() {
+        let points: Vec<_> = (0..4).map(point).collect();
+        let cloud = PointCloud::builder(&points)
+            .frame("lidar")
+            .timestamp(Timestamp {
+                seconds: 1,
+                nanos: 2,
+            })
+            .build();
+        assert_eq!(cloud.frame_id, "lidar");
+        assert_eq!(cloud.timestamp.map(|t| (t.seconds, t.nanos)), Some((1, 2)));
+        assert_eq!(cloud.pose, Some(Pose::identity()));
+        assert_eq!(cloud.point_stride, 16);
+        assert_eq!(cloud.data.len(), 64);
+        let layout: Vec<_> = cloud
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.offset, f.r#type()))
+            .collect();
+        assert_eq!(
+            layout,
+            [
+                ("x", 0, NumericType::Float32),
+                ("y", 4, NumericType::Float32),
+                ("z", 8, NumericType::Float32),
+                ("red", 12, NumericType::Uint8),
+                ("green", 13, NumericType::Uint8),
+                ("blue", 14, NumericType::Uint8),
+                ("alpha", 15, NumericType::Uint8),
+            ]
+        );
+        assert_eq!(&cloud.data[16..20], &1.0f32.to_le_bytes());
+
+        let read: Vec<Rgba> = cloud.points().unwrap().collect();
+        assert_eq!(read, points);
+
+        let read: Vec<Zx> = cloud.points().unwrap().collect();
+        assert_eq!(read[3], Zx { z: 6.0, x: 3.0 });
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let cloud = PointCloud::builder(&[point(1)]).build();
+
+        let mut bad = cloud.clone();
+        bad.point_stride = 3;
+        assert_eq!(
+            bad.points::<Rgba>().err(),
+            Some(PointCloudError::InvalidStride { stride: 3, len: 16 })
+        );
+
+        let mut bad = cloud.clone();
+        bad.fields.retain(|f| f.name != "green");
+        assert_eq!(
+            bad.points::<Rgba>().err(),
+            Some(PointCloudError::MissingField("green".to_string()))
+        );
+
+        let mut bad = cloud.clone();
+        bad.fields[2].set_type(NumericType::Float64);
+        assert_eq!(
+            bad.points::<Rgba>().err(),
+            Some(PointCloudError::FieldType {
+                name: "z".to_string(),
+                expected: NumericType::Float32,
+                actual: NumericType::Float64.into(),
+            })
+        );
+
+        let mut bad = cloud.clone();
+        bad.fields[6].offset = 16;
+        assert_eq!(
+            bad.points::<Rgba>().err(),
+            Some(PointCloudError::FieldOutOfBounds {
+                name: "alpha".to_string(),
+                offset: 16,
+            })
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive() {
+        #[derive(foxglove::point_cloud::PackedPoint, Debug, PartialEq)]
+        struct Derived {
+            x: f64,
+            r#type: i16,
+        }
+
+        assert_eq!(
+            Derived::FIELDS,
+            &[("x", NumericType::Float64), ("type", NumericType::Int16)]
+        );
+        let points = [Derived { x: 1.5, r#type: -3 }];
+        let cloud = PointCloud::builder(&points).build();
+        assert_eq!(cloud.point_stride, 10);
+        let read: Vec<Derived> = cloud.points().unwrap().collect();
+        assert_eq!(read, points);
+    }
+}
@@ -0,0 +1,778 @@
+//! Protobuf encoding for custom types.
+//!
+//! With the `derive` feature, `#[derive(foxglove::Encode)]` implements [`Encode`](crate::Encode) for a struct
+//! with named fields, using a protobuf schema generated from the struct definition. This is as
+//! convenient as deriving [`Serialize`](serde::Serialize) and `JsonSchema`, but produces compact
+//! binary messages.
+//!
+//! ```no_run
+//! # #[cfg(feature = "derive")]
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! use foxglove::schemas::Pose;
+//!
+//! #[derive(foxglove::Encode)]
+//! struct Waypoint {
+//!     name: String,
+//!     pose: Option<Pose>,
+//!     speeds: Vec<f64>,
+//! }
+//!
+//! let channel = foxglove::TypedChannel::new("/waypoint")?;
+//! channel.log(&Waypoint {
+//!     name: "home".to_string(),
+//!     pose: Some(Pose::default()),
+//!     speeds: vec![1.0, 2.0],
+//! });
+//! # Ok(()) }
+//! ```
+//!
+//! Fields are numbered in declaration order, starting at 1. Field types must implement
+//! [`ProtobufField`], which is implemented for:
+//!
+//! - `bool`, `i32`, `i64`, `u32`, `u64`, `f32`, `f64`, `String`, and `Vec<u8>` (as `bytes`)
+//! - Messages, including the [well-known schemas](crate::schemas) and other derived structs
+//! - `Option<T>` of messages, which is omitted if `None`
+//! - `Vec<T>` of any of the above, except `Vec<u8>`
+//!
+//! The message is named after the struct, in a package named after the module in which the
+//! struct is defined. For example, a struct `Waypoint` in `my_crate::nav` is encoded as
+//! `my_crate.nav.Waypoint`.
+//!
+//! A struct may contain itself, e.g. `children: Vec<Node>` in a struct `Node`. Each message is
+//! defined in its own file, and protobuf does not allow files to import each other, so mutually
+//! recursive structs are not supported: building the schema for one of them panics.
+
+use prost::encoding::{encode_key, encode_varint, encoded_len_varint, key_len, WireType};
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, FieldDescriptorProto, FileDescriptorSet};
+use thiserror::Error;
+
+use crate::Schema;
+
+#[doc(hidden)]
+pub use bytes::BufMut;
+pub use prost_types::FileDescriptorProto;
+
+/// An error encountered while encoding a protobuf message.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum ProtobufError {
+    /// The buffer has insufficient capacity for the encoded message.
+    #[error("Insufficient buffer capacity: {required} bytes required, {remaining} remaining")]
+    InsufficientCapacity {
+        /// The length of the encoded message.
+        required: usize,
+        /// The remaining capacity of the buffer.
+        remaining: usize,
+    },
+}
+
+/// A type that can be encoded as a protobuf message.
+///
+/// This is implemented for the [well-known schemas](crate::schemas), and by
+/// `#[derive(foxglove::Encode)]`.
+pub trait ProtobufMessage {
+    /// Returns the fully-qualified message name, e.g. `foxglove.Pose`.
+    fn type_name() -> String;
+
+    /// Returns the name of the file which defines the message, e.g. `foxglove/Pose.proto`.
+    fn file_name() -> String;
+
+    /// Adds the file which defines the message, preceded by its dependencies, to `files`.
+    ///
+    /// Files which are already present are not added again.
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>);
+
+    /// Encodes the message fields, without a length delimiter.
+    fn encode_raw(&self, buf: &mut impl BufMut);
+
+    /// Returns the encoded length of the message fields.
+    fn encoded_len(&self) -> usize;
+}
+
+/// A type that can be used as a field of a derived protobuf message.
+///
+/// See the [module documentation](self) for the supported types.
+pub trait ProtobufField {
+    /// Returns the protobuf field type.
+    fn field_type() -> Type;
+
+    /// Returns whether the field is repeated.
+    fn repeated() -> bool {
+        false
+    }
+
+    /// Returns the fully-qualified message name, for message fields.
+    fn type_name() -> Option<String> {
+        None
+    }
+
+    /// Returns the name of the file which defines the message, for message fields.
+    fn file_name() -> Option<String> {
+        None
+    }
+
+    /// Adds the files required by the field type to `files`.
+    fn file_descriptors(_files: &mut Vec<FileDescriptorProto>) {}
+
+    /// Encodes the field with the given tag.
+    ///
+    /// Scalar fields with default values are omitted, following proto3 semantics.
+    fn encode(&self, tag: u32, buf: &mut impl BufMut);
+
+    /// Returns the encoded length of the field with the given tag.
+    fn encoded_len(&self, tag: u32) -> usize;
+
+    /// Encodes a repeated field with the given tag.
+    fn encode_repeated(values: &[Self], tag: u32, buf: &mut impl BufMut)
+    where
+        Self: Sized;
+
+    /// Returns the encoded length of a repeated field with the given tag.
+    fn encoded_len_repeated(values: &[Self], tag: u32) -> usize
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_scalar {
+    ($($ty:ty => $module:ident, $field_type:ident;)*) => {
+        $(
+            impl ProtobufField for $ty {
+                fn field_type() -> Type {
+                    Type::$field_type
+                }
+
+                fn encode(&self, tag: u32, buf: &mut impl BufMut) {
+                    if *self != Self::default() {
+                        prost::encoding::$module::encode(tag, self, buf);
+                    }
+                }
+
+                fn encoded_len(&self, tag: u32) -> usize {
+                    if *self != Self::default() {
+                        prost::encoding::$module::encoded_len(tag, self)
+                    } else {
+                        0
+                    }
+                }
+
+                fn encode_repeated(values: &[Self], tag: u32, buf: &mut impl BufMut) {
+                    prost::encoding::$module::encode_packed(tag, values, buf);
+                }
+
+                fn encoded_len_repeated(values: &[Self], tag: u32) -> usize {
+                    prost::encoding::$module::encoded_len_packed(tag, values)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar! {
+    bool => bool, Bool;
+    i32 => int32, Int32;
+    i64 => int64, Int64;
+    u32 => uint32, Uint32;
+    u64 => uint64, Uint64;
+    f32 => float, Float;
+    f64 => double, Double;
+}
+
+macro_rules! impl_length_delimited {
+    ($($ty:ty => $module:ident, $field_type:ident;)*) => {
+        $(
+            impl ProtobufField for $ty {
+                fn field_type() -> Type {
+                    Type::$field_type
+                }
+
+                fn encode(&self, tag: u32, buf: &mut impl BufMut) {
+                    if !self.is_empty() {
+                        prost::encoding::$module::encode(tag, self, buf);
+                    }
+                }
+
+                fn encoded_len(&self, tag: u32) -> usize {
+                    if !self.is_empty() {
+                        prost::encoding::$module::encoded_len(tag, self)
+                    } else {
+                        0
+                    }
+                }
+
+                fn encode_repeated(values: &[Self], tag: u32, buf: &mut impl BufMut) {
+                    prost::encoding::$module::encode_repeated(tag, values, buf);
+                }
+
+                fn encoded_len_repeated(values: &[Self], tag: u32) -> usize {
+                    prost::encoding::$module::encoded_len_repeated(tag, values)
+                }
+            }
+        )*
+    };
+}
+
+impl_length_delimited! {
+    String => string, String;
+    Vec<u8> => bytes, Bytes;
+}
+
+/// Encodes a message as a length-delimited field.
+fn encode_message<T: ProtobufMessage>(value: &T, tag: u32, buf: &mut impl BufMut) {
+    encode_key(tag, WireType::LengthDelimited, buf);
+    encode_varint(ProtobufMessage::encoded_len(value) as u64, buf);
+    value.encode_raw(buf);
+}
+
+/// Returns the encoded length of a message as a length-delimited field.
+fn encoded_len_message<T: ProtobufMessage>(value: &T, tag: u32) -> usize {
+    let len = ProtobufMessage::encoded_len(value);
+    key_len(tag) + encoded_len_varint(len as u64) + len
+}
+
+impl<T: ProtobufMessage> ProtobufField for T {
+    fn field_type() -> Type {
+        Type::Message
+    }
+
+    fn type_name() -> Option<String> {
+        Some(format!(".{}", T::type_name()))
+    }
+
+    fn file_name() -> Option<String> {
+        Some(T::file_name())
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        T::file_descriptors(files);
+    }
+
+    fn encode(&self, tag: u32, buf: &mut impl BufMut) {
+        encode_message(self, tag, buf);
+    }
+
+    fn encoded_len(&self, tag: u32) -> usize {
+        encoded_len_message(self, tag)
+    }
+
+    fn encode_repeated(values: &[Self], tag: u32, buf: &mut impl BufMut) {
+        values.iter().for_each(|v| encode_message(v, tag, buf));
+    }
+
+    fn encoded_len_repeated(values: &[Self], tag: u32) -> usize {
+        values.iter().map(|v| encoded_len_message(v, tag)).sum()
+    }
+}
+
+impl<T: ProtobufMessage> ProtobufField for Option<T> {
+    fn field_type() -> Type {
+        Type::Message
+    }
+
+    fn type_name() -> Option<String> {
+        <T as ProtobufField>::type_name()
+    }
+
+    fn file_name() -> Option<String> {
+        <T as ProtobufField>::file_name()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        T::file_descriptors(files);
+    }
+
+    fn encode(&self, tag: u32, buf: &mut impl BufMut) {
+        if let Some(value) = self {
+            encode_message(value, tag, buf);
+        }
+    }
+
+    fn encoded_len(&self, tag: u32) -> usize {
+        self.as_ref().map_or(0, |v| encoded_len_message(v, tag))
+    }
+
+    fn encode_repeated(values: &[Self], tag: u32, buf: &mut impl BufMut) {
+        values.iter().for_each(|v| v.encode(tag, buf));
+    }
+
+    fn encoded_len_repeated(values: &[Self], tag: u32) -> usize {
+        values.iter().map(|v| v.encoded_len(tag)).sum()
+    }
+}
+
+impl<T: ProtobufField> ProtobufField for Vec<T> {
+    fn field_type() -> Type {
+        T::field_type()
+    }
+
+    fn repeated() -> bool {
+        true
+    }
+
+    fn type_name() -> Option<String> {
+        T::type_name()
+    }
+
+    fn file_name() -> Option<String> {
+        T::file_name()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        T::file_descriptors(files);
+    }
+
+    fn encode(&self, tag: u32, buf: &mut impl BufMut) {
+        if !self.is_empty() {
+            T::encode_repeated(self, tag, buf);
+        }
+    }
+
+    fn encoded_len(&self, tag: u32) -> usize {
+        if !self.is_empty() {
+            T::encoded_len_repeated(self, tag)
+        } else {
+            0
+        }
+    }
+
+    fn encode_repeated(values: &[Self], tag: u32, buf: &mut impl BufMut) {
+        values.iter().for_each(|v| v.encode(tag, buf));
+    }
+
+    fn encoded_len_repeated(values: &[Self], tag: u32) -> usize {
+        values.iter().map(|v| v.encoded_len(tag)).sum()
+    }
+}
+
+/// A builder for the file descriptor of a derived message.
+///
+/// Used by `#[derive(foxglove::Encode)]`.
+#[doc(hidden)]
+pub struct MessageDescriptor {
+    package: String,
+    name: String,
+    fields: Vec<FieldDescriptorProto>,
+    dependencies: Vec<String>,
+}
+
+impl MessageDescriptor {
+    /// Creates a descriptor for a message defined in the given Rust module.
+    pub fn new(module_path: &str, name: &str) -> Self {
+        Self {
+            package: module_path.replace("::", "."),
+            name: name.to_string(),
+            fields: vec![],
+            dependencies: vec![],
+        }
+    }
+
+    /// Returns the fully-qualified message name.
+    pub fn type_name(&self) -> String {
+        format!("{}.{}", self.package, self.name)
+    }
+
+    /// Returns the name of the file which defines the message.
+    pub fn file_name(&self) -> String {
+        format!("{}/{}.proto", self.package.replace('.', "/"), self.name)
+    }
+
+    /// Starts adding the file which defines the message to `files`.
+    ///
+    /// A placeholder for the file is added until [`MessageDescriptor::build`] is called, so that
+    /// fields which refer to the message itself do not recurse. Returns `None` if the file is
+    /// already present.
+    pub fn begin(self, files: &mut Vec<FileDescriptorProto>) -> Option<Self> {
+        let file_name = self.file_name();
+        if files.iter().any(|f| f.name() == file_name) {
+            return None;
+        }
+        files.push(FileDescriptorProto {
+            name: Some(file_name),
+            ..Default::default()
+        });
+        Some(self)
+    }
+
+    /// Adds a field, and the files required by its type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field type refers back to this message through another message, since the
+    /// files defining the two messages would import each other.
+    pub fn field<T: ProtobufField>(
+        mut self,
+        name: &str,
+        number: i32,
+        files: &mut Vec<FileDescriptorProto>,
+    ) -> Self {
+        if let Some(file_name) = T::file_name() {
+            if file_name != self.file_name() {
+                if files
+                    .iter()
+                    .any(|f| f.name() == file_name && is_placeholder(f))
+                {
+                    panic!(
+                        "{} and {} are mutually recursive, which is not supported",
+                        self.type_name(),
+                        T::type_name().unwrap_or_default().trim_start_matches('.')
+                    );
+                }
+                T::file_descriptors(files);
+                if !self.dependencies.contains(&file_name) {
+                    self.dependencies.push(file_name);
+                }
+            }
+        }
+        let label = if T::repeated() {
+            Label::Repeated
+        } else {
+            Label::Optional
+        };
+        let mut field = FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            type_name: T::type_name(),
+            json_name: Some(json_name(name)),
+            ..Default::default()
+        };
+        field.set_label(label);
+        field.set_type(T::field_type());
+        self.fields.push(field);
+        self
+    }
+
+    /// Replaces the placeholder added by [`MessageDescriptor::begin`] with the file which defines
+    /// the message.
+    ///
+    /// The file is moved after the files added for its fields, so that dependencies precede the
+    /// files which import them.
+    pub fn build(self, files: &mut Vec<FileDescriptorProto>) {
+        let file_name = self.file_name();
+        files.retain(|f| f.name() != file_name);
+        files.push(FileDescriptorProto {
+            name: Some(file_name),
+            package: Some(self.package),
+            dependency: self.dependencies,
+            message_type: vec![DescriptorProto {
+                name: Some(self.name),
+                field: self.fields,
+                ..Default::default()
+            }],
+            syntax: Some("proto3".to_string()),
+            ..Default::default()
+        });
+    }
+}
+
+/// Returns whether a file is a placeholder for a message whose fields are still being added.
+fn is_placeholder(file: &FileDescriptorProto) -> bool {
+    file.message_type.is_empty()
+}
+
+/// Returns the JSON name of a field, which is its name in lower camel case.
+fn json_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Adds the files from an encoded file descriptor set to `files`, skipping those which are
+/// already present.
+pub(crate) fn add_file_descriptors(files: &mut Vec<FileDescriptorProto>, descriptor: &[u8]) {
+    let fds = FileDescriptorSet::decode(descriptor).expect("Invalid file descriptor set");
+    for file in fds.file {
+        if !files.iter().any(|f| f.name == file.name) {
+            files.push(file);
+        }
+    }
+}
+
+/// Returns the `protobuf` schema for a message.
+///
+/// Used by `#[derive(foxglove::Encode)]`.
+#[doc(hidden)]
+pub fn schema<T: ProtobufMessage>() -> Schema {
+    let mut files = vec![];
+    T::file_descriptors(&mut files);
+    let fds = FileDescriptorSet { file: files };
+    Schema::new(T::type_name(), "protobuf", fds.encode_to_vec())
+}
+
+/// Encodes a message to the buffer.
+///
+/// Used by `#[derive(foxglove::Encode)]`.
+#[doc(hidden)]
+pub fn encode<T: ProtobufMessage>(value: &T, buf: &mut impl BufMut) -> Result<(), ProtobufError> {
+    let required = ProtobufMessage::encoded_len(value);
+    let remaining = buf.remaining_mut();
+    if required > remaining {
+        return Err(ProtobufError::InsufficientCapacity {
+            required,
+            remaining,
+        });
+    }
+    value.encode_raw(buf);
+    Ok(())
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::schemas::{Pose, Vector3};
+    use crate::Encode;
+
+    #[derive(crate::Encode)]
+    struct Inner {
+        id: u32,
+        tags: Vec<String>,
+    }
+
+    #[derive(crate::Encode)]
+    struct Outer {
+        name: String,
+        pose: Option<Pose>,
+        inner: Vec<Inner>,
+        values: Vec<f64>,
+        data: Vec<u8>,
+        flag: bool,
+        offset: i64,
+    }
+
+    #[derive(crate::Encode)]
+    struct Node {
+        name: String,
+        children: Vec<Node>,
+    }
+
+    #[derive(crate::Encode)]
+    struct Left {
+        right: Vec<Right>,
+    }
+
+    #[derive(crate::Encode)]
+    struct Right {
+        left: Vec<Left>,
+    }
+
+    /// The prost equivalent of `Inner`, for decoding.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct InnerProto {
+        #[prost(uint32, tag = "1")]
+        id: u32,
+        #[prost(string, repeated, tag = "2")]
+        tags: Vec<String>,
+    }
+
+    /// The prost equivalent of `Outer`, for decoding.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct OuterProto {
+        #[prost(string, tag = "1")]
+        name: String,
+        #[prost(message, optional, tag = "2")]
+        pose: Option<Pose>,
+        #[prost(message, repeated, tag = "3")]
+        inner: Vec<InnerProto>,
+        #[prost(double, repeated, tag = "4")]
+        values: Vec<f64>,
+        #[prost(bytes = "vec", tag = "5")]
+        data: Vec<u8>,
+        #[prost(bool, tag = "6")]
+        flag: bool,
+        #[prost(int64, tag = "7")]
+        offset: i64,
+    }
+
+    /// The prost equivalent of `Node`, for decoding.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct NodeProto {
+        #[prost(string, tag = "1")]
+        name: String,
+        #[prost(message, repeated, tag = "2")]
+        children: Vec<NodeProto>,
+    }
+
+    fn encode<T: Encode>(msg: &T) -> Vec<u8> {
+        let mut buf = Vec::new();
+        msg.encode(&mut buf).unwrap();
+        assert_eq!(Some(buf.len()), msg.encoded_len());
+        buf
+    }
+
+    #[test]
+    fn test_derive_schema() {
+        let schema = Outer::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove.protobuf.tests.Outer");
+        assert_eq!(schema.encoding, "protobuf");
+        assert_eq!(Outer::get_message_encoding(), "protobuf");
+        let fds = FileDescriptorSet::decode(schema.data.as_ref()).unwrap();
+        let names: Vec<_> = fds.file.iter().map(|f| f.name()).collect();
+        // Dependencies precede the files which import them.
+        assert_eq!(
+            names,
+            [
+                "foxglove/Quaternion.proto",
+                "foxglove/Vector3.proto",
+                "foxglove/Pose.proto",
+                "foxglove/protobuf/tests/Inner.proto",
+                "foxglove/protobuf/tests/Outer.proto",
+            ]
+        );
+        let outer = fds.file.last().unwrap();
+        assert_eq!(outer.package(), "foxglove.protobuf.tests");
+        assert_eq!(
+            outer.dependency,
+            ["foxglove/Pose.proto", "foxglove/protobuf/tests/Inner.proto"]
+        );
+        let fields: Vec<_> = outer.message_type[0]
+            .field
+            .iter()
+            .map(|f| (f.name(), f.number(), f.r#type(), f.label(), f.type_name()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("name", 1, Type::String, Label::Optional, ""),
+                ("pose", 2, Type::Message, Label::Optional, ".foxglove.Pose"),
+                (
+                    "inner",
+                    3,
+                    Type::Message,
+                    Label::Repeated,
+                    ".foxglove.protobuf.tests.Inner"
+                ),
+                ("values", 4, Type::Double, Label::Repeated, ""),
+                ("data", 5, Type::Bytes, Label::Optional, ""),
+                ("flag", 6, Type::Bool, Label::Optional, ""),
+                ("offset", 7, Type::Int64, Label::Optional, ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_derive_encode() {
+        let pose = Pose {
+            position: Some(Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            }),
+            orientation: None,
+        };
+        let data = encode(&Outer {
+            name: "outer".to_string(),
+            pose: Some(pose),
+            inner: vec![Inner {
+                id: 5,
+                tags: vec!["a".to_string(), "b".to_string()],
+            }],
+            values: vec![1.5, 2.5],
+            data: vec![1, 2, 3],
+            flag: true,
+            offset: -4,
+        });
+        let msg = OuterProto::decode(data.as_slice()).unwrap();
+        assert_eq!(
+            msg,
+            OuterProto {
+                name: "outer".to_string(),
+                pose: Some(pose),
+                inner: vec![InnerProto {
+                    id: 5,
+                    tags: vec!["a".to_string(), "b".to_string()],
+                }],
+                values: vec![1.5, 2.5],
+                data: vec![1, 2, 3],
+                flag: true,
+                offset: -4,
+            }
+        );
+        // Repeated scalars are packed.
+        assert_eq!(data, msg.encode_to_vec());
+    }
+
+    #[test]
+    fn test_derive_defaults() {
+        let msg = Outer {
+            name: String::new(),
+            pose: None,
+            inner: vec![],
+            values: vec![],
+            data: vec![],
+            flag: false,
+            offset: 0,
+        };
+        // Default values are omitted.
+        assert!(encode(&msg).is_empty());
+
+        let mut buf = [0u8; 2];
+        let inner = Inner {
+            id: 1000,
+            tags: vec![],
+        };
+        let result = Encode::encode(&inner, &mut buf.as_mut_slice());
+        assert!(matches!(
+            result,
+            Err(ProtobufError::InsufficientCapacity {
+                required: 3,
+                remaining: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_derive_recursive() {
+        let schema = Node::get_schema().unwrap();
+        let fds = FileDescriptorSet::decode(schema.data.as_ref()).unwrap();
+        assert_eq!(fds.file.len(), 1);
+        let node = &fds.file[0];
+        assert_eq!(node.name(), "foxglove/protobuf/tests/Node.proto");
+        // A message does not import its own file.
+        assert!(node.dependency.is_empty());
+        let children = &node.message_type[0].field[1];
+        assert_eq!(children.type_name(), ".foxglove.protobuf.tests.Node");
+        assert_eq!(children.label(), Label::Repeated);
+
+        let data = encode(&Node {
+            name: "root".to_string(),
+            children: vec![Node {
+                name: "leaf".to_string(),
+                children: vec![],
+            }],
+        });
+        assert_eq!(
+            NodeProto::decode(data.as_slice()).unwrap(),
+            NodeProto {
+                name: "root".to_string(),
+                children: vec![NodeProto {
+                    name: "leaf".to_string(),
+                    children: vec![],
+                }],
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "foxglove.protobuf.tests.Left and foxglove.protobuf.tests.Right are mutually recursive"
+    )]
+    fn test_derive_mutually_recursive() {
+        Right::get_schema();
+    }
+
+    #[test]
+    fn test_json_name() {
+        assert_eq!(json_name("frame_id"), "frameId");
+        assert_eq!(json_name("x"), "x");
+    }
+}
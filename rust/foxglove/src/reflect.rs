@@ -0,0 +1,231 @@
+//! Logging of dynamic protobuf messages.
+//!
+//! Messages described by a [`prost_reflect`] descriptor can be logged without compiling them into
+//! Rust types. A [`DynamicChannel`] derives its `protobuf` schema from a message descriptor,
+//! including the files that the message depends on, and encodes messages which match that
+//! descriptor.
+//!
+//! ```no_run
+//! use foxglove::reflect::DynamicChannel;
+//! use prost_reflect::{DescriptorPool, DynamicMessage, Value};
+//!
+//! # fn func(file_descriptor_set: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+//! let pool = DescriptorPool::decode(file_descriptor_set)?;
+//! let descriptor = pool
+//!     .get_message_by_name("my_package.Status")
+//!     .ok_or("unknown message")?;
+//!
+//! let channel = DynamicChannel::new("/status", descriptor.clone())?;
+//! let mut msg = DynamicMessage::new(descriptor);
+//! msg.set_field_by_name("message", Value::String("ok".to_string()));
+//! channel.log(&msg)?;
+//! # Ok(()) }
+//! ```
+//!
+//! This module requires the `reflect` feature.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use prost::Message;
+use prost_reflect::{FileDescriptor, MessageDescriptor, ReflectMessage};
+use prost_types::{FileDescriptorProto, FileDescriptorSet};
+use thiserror::Error;
+
+use crate::{Channel, ChannelBuilder, FoxgloveError, PartialMetadata, Schema};
+
+/// An error encountered while logging a dynamic message.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum ReflectError {
+    /// The message type does not match the type of the channel.
+    #[error("Expected a message of type {expected}, got {actual}")]
+    DescriptorMismatch {
+        /// The message type of the channel.
+        expected: String,
+        /// The message type of the logged message.
+        actual: String,
+    },
+}
+
+/// Returns the `protobuf` schema for a message descriptor.
+///
+/// The schema is a `FileDescriptorSet` containing the file which defines the message, and its
+/// transitive dependencies. Each file is preceded by its dependencies.
+pub fn schema(descriptor: &MessageDescriptor) -> Schema {
+    let mut fds = FileDescriptorSet::default();
+    let mut seen = BTreeSet::new();
+    let file = descriptor.parent_file();
+    seen.insert(file.name().to_string());
+    add_file(&file, &mut fds, &mut seen);
+    Schema::new(descriptor.full_name(), "protobuf", fds.encode_to_vec())
+}
+
+/// Adds a file to the set, after its dependencies.
+fn add_file(file: &FileDescriptor, fds: &mut FileDescriptorSet, seen: &mut BTreeSet<String>) {
+    let mut dependencies: Vec<_> = file.dependencies().collect();
+    dependencies.sort_unstable_by(|a, b| a.name().cmp(b.name()));
+    for dependency in dependencies {
+        if seen.insert(dependency.name().to_string()) {
+            add_file(&dependency, fds, seen);
+        }
+    }
+    fds.file.push(FileDescriptorProto {
+        source_code_info: None,
+        ..file.file_descriptor_proto().clone()
+    });
+}
+
+/// A channel for dynamic protobuf messages of a single type.
+///
+/// See the [module documentation](self) for an example.
+pub struct DynamicChannel {
+    inner: Arc<Channel>,
+    descriptor: MessageDescriptor,
+}
+
+impl DynamicChannel {
+    /// Constructs a new channel for messages of the given type, with default settings.
+    ///
+    /// If you want to override the channel configuration, use
+    /// [`ChannelBuilder::build_dynamic`].
+    pub fn new(
+        topic: impl Into<String>,
+        descriptor: MessageDescriptor,
+    ) -> Result<Self, FoxgloveError> {
+        ChannelBuilder::new(topic).build_dynamic(descriptor)
+    }
+
+    pub(crate) fn from_channel(channel: Arc<Channel>, descriptor: MessageDescriptor) -> Self {
+        Self {
+            inner: channel,
+            descriptor,
+        }
+    }
+
+    /// Returns the topic name of the channel.
+    pub fn topic(&self) -> &str {
+        &self.inner.topic
+    }
+
+    /// Returns the descriptor of the channel's message type.
+    pub fn descriptor(&self) -> &MessageDescriptor {
+        &self.descriptor
+    }
+
+    /// Encodes the message and logs it on the channel.
+    ///
+    /// Returns an error if the message type does not match the channel.
+    pub fn log(&self, msg: &impl ReflectMessage) -> Result<(), ReflectError> {
+        self.log_with_meta(msg, PartialMetadata::default())
+    }
+
+    /// Encodes the message and logs it on the channel with additional metadata.
+    ///
+    /// Returns an error if the message type does not match the channel.
+    pub fn log_with_meta(
+        &self,
+        msg: &impl ReflectMessage,
+        metadata: PartialMetadata,
+    ) -> Result<(), ReflectError> {
+        let descriptor = msg.descriptor();
+        if descriptor != self.descriptor {
+            return Err(ReflectError::DescriptorMismatch {
+                expected: self.descriptor.full_name().to_string(),
+                actual: descriptor.full_name().to_string(),
+            });
+        }
+        self.inner.log_with_meta(&msg.encode_to_vec(), metadata);
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for DynamicChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamicChannel")
+            .field("topic", &self.topic())
+            .field("descriptor", &self.descriptor.full_name())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use prost_reflect::{DescriptorPool, DynamicMessage, Value};
+
+    use super::*;
+    use crate::schemas::{descriptors, Pose, Vector3};
+    use crate::testutil::RecordingSink;
+    use crate::LogContext;
+
+    fn pool() -> DescriptorPool {
+        let mut pool = DescriptorPool::decode(descriptors::POSE).unwrap();
+        pool.decode_file_descriptor_set(descriptors::LOG).unwrap();
+        pool
+    }
+
+    #[test]
+    fn test_schema() {
+        let descriptor = pool().get_message_by_name("foxglove.Pose").unwrap();
+        let schema = schema(&descriptor);
+        assert_eq!(schema.name, "foxglove.Pose");
+        assert_eq!(schema.encoding, "protobuf");
+        // The schema matches the one for the compiled type.
+        assert_eq!(schema.data.as_ref(), descriptors::POSE);
+
+        let fds = FileDescriptorSet::decode(schema.data.as_ref()).unwrap();
+        let names: Vec<_> = fds.file.iter().map(|f| f.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "foxglove/Quaternion.proto",
+                "foxglove/Vector3.proto",
+                "foxglove/Pose.proto"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_log_dynamic_message() {
+        let ctx = LogContext::new();
+        let sink = Arc::new(RecordingSink::new());
+        assert!(ctx.add_sink(sink.clone()));
+
+        let pool = pool();
+        let descriptor = pool.get_message_by_name("foxglove.Pose").unwrap();
+        let channel = ChannelBuilder::new("/pose")
+            .with_context(&ctx)
+            .build_dynamic(descriptor.clone())
+            .unwrap();
+        assert_eq!(channel.topic(), "/pose");
+        assert_eq!(channel.inner.message_encoding, "protobuf");
+        assert_eq!(channel.inner.schema(), Some(&schema(&descriptor)));
+
+        let mut position =
+            DynamicMessage::new(pool.get_message_by_name("foxglove.Vector3").unwrap());
+        position.set_field_by_name("x", Value::F64(1.0));
+        let mut msg = DynamicMessage::new(descriptor);
+        msg.set_field_by_name("position", Value::Message(position));
+        channel.log(&msg).unwrap();
+
+        let log = DynamicMessage::new(pool.get_message_by_name("foxglove.Log").unwrap());
+        assert!(matches!(
+            channel.log(&log),
+            Err(ReflectError::DescriptorMismatch { expected, actual })
+                if expected == "foxglove.Pose" && actual == "foxglove.Log"
+        ));
+
+        let recorded = sink.recorded.lock();
+        assert_eq!(recorded.len(), 1);
+        let expected = Pose {
+            position: Some(Vector3 {
+                x: 1.0,
+                ..Default::default()
+            }),
+            orientation: None,
+        };
+        assert_eq!(recorded[0].msg, prost::Message::encode_to_vec(&expected));
+    }
+}
@@ -0,0 +1,352 @@
+//! ROS 1 message encoding for well-known schemas.
+//!
+//! By default, the [well-known schemas](crate::schemas) are encoded as protobuf. Wrapping a
+//! message in [`Ros1`] encodes it with the ROS 1 serialization format instead, with a `ros1msg`
+//! schema, so that it can be consumed by ROS 1 tooling as a `foxglove_msgs` message.
+//!
+//! ```no_run
+//! use foxglove::ros1::Ros1;
+//! use foxglove::schemas::Log;
+//! use foxglove::TypedChannel;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = TypedChannel::<Ros1<Log>>::new("/log")?;
+//! channel.log(&Ros1(Log {
+//!     message: "Hello, ROS!".to_string(),
+//!     ..Default::default()
+//! }));
+//! # Ok(()) }
+//! ```
+//!
+//! This module requires the `ros1` feature.
+
+use bytes::BufMut;
+use thiserror::Error;
+
+use crate::schemas::{Duration, Timestamp};
+use crate::time::normalize;
+use crate::{Encode, Schema};
+
+/// An error encountered while serializing a ROS 1 message.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum Ros1Error {
+    /// A string or array is too long to be encoded.
+    #[error("Array length {0} exceeds the maximum length")]
+    ArrayTooLong(usize),
+    /// A fixed-size array has the wrong number of elements.
+    #[error("Expected an array of {expected} elements, got {actual}")]
+    ArrayLengthMismatch {
+        /// The length of the array in the message definition.
+        expected: usize,
+        /// The length of the array in the message.
+        actual: usize,
+    },
+    /// A time or duration cannot be represented as a ROS 1 builtin type.
+    #[error("Time {0}s is out of range")]
+    TimeOutOfRange(i64),
+}
+
+/// A writer for ROS 1 serialized data.
+///
+/// Values are little-endian and unaligned.
+#[derive(Debug, Default)]
+pub struct Ros1Writer {
+    buf: Vec<u8>,
+}
+
+impl Ros1Writer {
+    /// Creates a new writer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the encoded data.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Writes an array length.
+    fn write_len(&mut self, len: usize) -> Result<(), Ros1Error> {
+        let len = u32::try_from(len).map_err(|_| Ros1Error::ArrayTooLong(len))?;
+        self.write_u32(len);
+        Ok(())
+    }
+
+    /// Writes a `bool`.
+    pub fn write_bool(&mut self, value: bool) {
+        self.buf.push(u8::from(value));
+    }
+
+    /// Writes a `uint8`.
+    pub fn write_u8(&mut self, value: u8) {
+        self.buf.push(value);
+    }
+
+    /// Writes a `uint32`.
+    pub fn write_u32(&mut self, value: u32) {
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes an `int32`.
+    pub fn write_i32(&mut self, value: i32) {
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes a `float64`.
+    pub fn write_f64(&mut self, value: f64) {
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes a `string`.
+    pub fn write_string(&mut self, value: &str) -> Result<(), Ros1Error> {
+        self.write_bytes(value.as_bytes())
+    }
+
+    /// Writes a `uint8[]`.
+    pub fn write_bytes(&mut self, value: &[u8]) -> Result<(), Ros1Error> {
+        self.write_len(value.len())?;
+        self.buf.extend_from_slice(value);
+        Ok(())
+    }
+
+    /// Writes a `time`, as unsigned seconds and nanoseconds.
+    ///
+    /// A missing timestamp is written as zero.
+    pub fn write_time(&mut self, value: Option<&Timestamp>) -> Result<(), Ros1Error> {
+        let (seconds, nanos) = value.map_or((0, 0), |t| (t.seconds, t.nanos));
+        let (sec, nsec) = normalize(seconds, nanos);
+        let sec = u32::try_from(sec).map_err(|_| Ros1Error::TimeOutOfRange(seconds))?;
+        self.write_u32(sec);
+        self.write_u32(nsec);
+        Ok(())
+    }
+
+    /// Writes a `duration`, as signed seconds and nanoseconds.
+    ///
+    /// A missing duration is written as zero.
+    pub fn write_duration(&mut self, value: Option<&Duration>) -> Result<(), Ros1Error> {
+        let (seconds, nanos) = value.map_or((0, 0), |d| (d.seconds, d.nanos));
+        let (sec, nsec) = normalize(seconds, nanos);
+        let sec = i32::try_from(sec).map_err(|_| Ros1Error::TimeOutOfRange(seconds))?;
+        self.write_i32(sec);
+        // Always in the range [0, 1e9).
+        self.write_i32(nsec as i32);
+        Ok(())
+    }
+
+    /// Writes a nested message.
+    ///
+    /// A missing message is written with default values.
+    pub fn write_message<T: Ros1Serialize + Default>(
+        &mut self,
+        value: Option<&T>,
+    ) -> Result<(), Ros1Error> {
+        match value {
+            Some(value) => value.serialize(self),
+            None => T::default().serialize(self),
+        }
+    }
+
+    /// Writes a variable-length array.
+    pub fn write_sequence<T: Ros1Serialize>(&mut self, values: &[T]) -> Result<(), Ros1Error> {
+        self.write_len(values.len())?;
+        values.iter().try_for_each(|v| v.serialize(self))
+    }
+
+    /// Writes a fixed-size array of `float64`, which has no length prefix.
+    pub fn write_array(&mut self, values: &[f64], len: usize) -> Result<(), Ros1Error> {
+        if values.len() != len {
+            return Err(Ros1Error::ArrayLengthMismatch {
+                expected: len,
+                actual: values.len(),
+            });
+        }
+        values.iter().for_each(|v| self.write_f64(*v));
+        Ok(())
+    }
+}
+
+/// A type that can be serialized in the ROS 1 format.
+pub trait Ros1Serialize {
+    /// Serializes the value to the writer.
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error>;
+}
+
+impl Ros1Serialize for u32 {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_u32(*self);
+        Ok(())
+    }
+}
+
+impl Ros1Serialize for f64 {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_f64(*self);
+        Ok(())
+    }
+}
+
+/// A message with a ROS 1 message definition.
+pub trait Ros1Message: Ros1Serialize {
+    /// The fully-qualified type name, e.g. `foxglove_msgs/Log`.
+    const TYPE_NAME: &'static str;
+    /// The message definition, concatenated with the definitions of its dependencies.
+    const DEFINITION: &'static str;
+}
+
+/// Wraps a message so that it is encoded in the ROS 1 format, with a `ros1msg` schema.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ros1<T>(pub T);
+
+impl<T> From<T> for Ros1<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Ros1Message> Encode for Ros1<T> {
+    type Error = Ros1Error;
+
+    fn get_schema() -> Option<Schema> {
+        Some(Schema::new(
+            T::TYPE_NAME,
+            "ros1msg",
+            T::DEFINITION.as_bytes(),
+        ))
+    }
+
+    fn get_message_encoding() -> String {
+        "ros1".to_string()
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) -> Result<(), Self::Error> {
+        let mut w = Ros1Writer::new();
+        self.0.serialize(&mut w)?;
+        buf.put_slice(&w.into_inner());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::log::Level;
+    use crate::schemas::{CameraCalibration, Log, Pose, Vector3};
+
+    fn encode<T: Ros1Message>(msg: T) -> Vec<u8> {
+        let mut buf = Vec::new();
+        Ros1(msg).encode(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_encode_log() {
+        let data = encode(Log {
+            timestamp: Some(Timestamp {
+                seconds: 1,
+                nanos: 2,
+            }),
+            level: Level::Info as i32,
+            message: "hi".to_string(),
+            name: String::new(),
+            file: "a".to_string(),
+            line: 7,
+        });
+        #[rustfmt::skip]
+        let expected = [
+            1, 0, 0, 0, // timestamp.sec
+            2, 0, 0, 0, // timestamp.nsec
+            2, // level
+            2, 0, 0, 0, b'h', b'i', // message
+            0, 0, 0, 0, // name
+            1, 0, 0, 0, b'a', // file
+            7, 0, 0, 0, // line
+        ];
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn test_encode_nested_and_arrays() {
+        let data = encode(Pose {
+            position: Some(Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            }),
+            orientation: None,
+        });
+        let expected: Vec<u8> = [1.0f64, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        assert_eq!(data, expected);
+
+        // Fixed-size arrays have no length prefix, and must have the declared length.
+        let calibration = CameraCalibration {
+            d: vec![0.5],
+            k: vec![0.0; 9],
+            r: vec![0.0; 9],
+            p: vec![0.0; 12],
+            ..Default::default()
+        };
+        let data = encode(calibration.clone());
+        // time + frame_id + width + height + distortion_model + D + K + R + P
+        assert_eq!(data.len(), 8 + 4 + 4 + 4 + 4 + (4 + 8) + 30 * 8);
+        let mut w = Ros1Writer::new();
+        assert!(matches!(
+            Ros1Serialize::serialize(
+                &CameraCalibration {
+                    k: vec![],
+                    ..calibration
+                },
+                &mut w
+            ),
+            Err(Ros1Error::ArrayLengthMismatch {
+                expected: 9,
+                actual: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn test_time_and_duration() {
+        let mut w = Ros1Writer::new();
+        w.write_duration(Some(&Duration {
+            seconds: -1,
+            nanos: -500_000_000,
+        }))
+        .unwrap();
+        let data = w.into_inner();
+        assert_eq!(&data[0..4], &(-2i32).to_le_bytes());
+        assert_eq!(&data[4..8], &500_000_000i32.to_le_bytes());
+
+        let mut w = Ros1Writer::new();
+        assert!(matches!(
+            w.write_time(Some(&Timestamp {
+                seconds: -1,
+                nanos: 0
+            })),
+            Err(Ros1Error::TimeOutOfRange(-1))
+        ));
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Ros1::<Log>::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove_msgs/Log");
+        assert_eq!(schema.encoding, "ros1msg");
+        assert!(schema
+            .data
+            .starts_with(b"# foxglove_msgs/Log\n# A log message"));
+        assert_eq!(Ros1::<Log>::get_message_encoding(), "ros1");
+
+        let schema = Ros1::<Pose>::get_schema().unwrap();
+        assert_eq!(schema.name, "geometry_msgs/Pose");
+        assert!(std::str::from_utf8(&schema.data)
+            .unwrap()
+            .contains("MSG: geometry_msgs/Point\n"));
+    }
+}
@@ -0,0 +1,369 @@
+//! ROS 2 message encoding for well-known schemas.
+//!
+//! By default, the [well-known schemas](crate::schemas) are encoded as protobuf. Wrapping a
+//! message in [`Ros2`] encodes it as CDR instead, with a `ros2msg` schema, so that it can be
+//! consumed by ROS 2 tooling as a `foxglove_msgs` message.
+//!
+//! ```no_run
+//! use foxglove::ros2::Ros2;
+//! use foxglove::schemas::Log;
+//! use foxglove::TypedChannel;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = TypedChannel::<Ros2<Log>>::new("/log")?;
+//! channel.log(&Ros2(Log {
+//!     message: "Hello, ROS!".to_string(),
+//!     ..Default::default()
+//! }));
+//! # Ok(()) }
+//! ```
+//!
+//! This module requires the `ros2` feature.
+
+use bytes::BufMut;
+use thiserror::Error;
+
+use crate::schemas::{Duration, Timestamp};
+use crate::time::normalize;
+use crate::{Encode, Schema};
+
+/// The CDR encapsulation header for little-endian plain CDR.
+const CDR_LE_HEADER: [u8; 4] = [0x00, 0x01, 0x00, 0x00];
+
+/// An error encountered while serializing a message as CDR.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum CdrError {
+    /// A string or sequence is too long to be encoded.
+    #[error("Sequence length {0} exceeds the maximum length")]
+    SequenceTooLong(usize),
+    /// A fixed-size array has the wrong number of elements.
+    #[error("Expected an array of {expected} elements, got {actual}")]
+    ArrayLengthMismatch {
+        /// The length of the array in the message definition.
+        expected: usize,
+        /// The length of the array in the message.
+        actual: usize,
+    },
+    /// A time or duration cannot be represented as a ROS 2 builtin type.
+    #[error("Time {0}s is out of range")]
+    TimeOutOfRange(i64),
+}
+
+/// A writer for little-endian, plain CDR-encoded data.
+#[derive(Debug)]
+pub struct CdrWriter {
+    buf: Vec<u8>,
+}
+
+impl Default for CdrWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdrWriter {
+    /// Creates a new writer, beginning with the CDR encapsulation header.
+    pub fn new() -> Self {
+        Self {
+            buf: CDR_LE_HEADER.to_vec(),
+        }
+    }
+
+    /// Returns the encoded data.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Pads the buffer so that the next value is aligned to `size` bytes.
+    ///
+    /// Alignment is relative to the end of the encapsulation header.
+    fn align(&mut self, size: usize) {
+        let offset = self.buf.len() - CDR_LE_HEADER.len();
+        let padding = (size - offset % size) % size;
+        self.buf.resize(self.buf.len() + padding, 0);
+    }
+
+    /// Writes a sequence length.
+    fn write_len(&mut self, len: usize) -> Result<(), CdrError> {
+        let len = u32::try_from(len).map_err(|_| CdrError::SequenceTooLong(len))?;
+        self.write_u32(len);
+        Ok(())
+    }
+
+    /// Writes a `bool`.
+    pub fn write_bool(&mut self, value: bool) {
+        self.buf.push(u8::from(value));
+    }
+
+    /// Writes a `uint8`.
+    pub fn write_u8(&mut self, value: u8) {
+        self.buf.push(value);
+    }
+
+    /// Writes a `uint32`.
+    pub fn write_u32(&mut self, value: u32) {
+        self.align(4);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes an `int32`.
+    pub fn write_i32(&mut self, value: i32) {
+        self.align(4);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes a `float64`.
+    pub fn write_f64(&mut self, value: f64) {
+        self.align(8);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes a `string`.
+    pub fn write_string(&mut self, value: &str) -> Result<(), CdrError> {
+        // The length includes the null terminator.
+        self.write_len(value.len() + 1)?;
+        self.buf.extend_from_slice(value.as_bytes());
+        self.buf.push(0);
+        Ok(())
+    }
+
+    /// Writes a `uint8[]`.
+    pub fn write_bytes(&mut self, value: &[u8]) -> Result<(), CdrError> {
+        self.write_len(value.len())?;
+        self.buf.extend_from_slice(value);
+        Ok(())
+    }
+
+    /// Writes a `builtin_interfaces/Time`.
+    ///
+    /// A missing timestamp is written as zero.
+    pub fn write_time(&mut self, value: Option<&Timestamp>) -> Result<(), CdrError> {
+        let (sec, nanosec) = value.map_or((0, 0), |t| (t.seconds, t.nanos));
+        self.write_sec_nanosec(sec, nanosec)
+    }
+
+    /// Writes a `builtin_interfaces/Duration`.
+    ///
+    /// A missing duration is written as zero.
+    pub fn write_duration(&mut self, value: Option<&Duration>) -> Result<(), CdrError> {
+        let (sec, nanosec) = value.map_or((0, 0), |d| (d.seconds, d.nanos));
+        self.write_sec_nanosec(sec, nanosec)
+    }
+
+    /// Writes an `int32` seconds field followed by a `uint32` nanoseconds field.
+    fn write_sec_nanosec(&mut self, seconds: i64, nanos: i32) -> Result<(), CdrError> {
+        let (sec, nanosec) = normalize(seconds, nanos);
+        let sec = i32::try_from(sec).map_err(|_| CdrError::TimeOutOfRange(seconds))?;
+        self.write_i32(sec);
+        self.write_u32(nanosec);
+        Ok(())
+    }
+
+    /// Writes a nested message.
+    ///
+    /// A missing message is written with default values.
+    pub fn write_message<T: CdrSerialize + Default>(
+        &mut self,
+        value: Option<&T>,
+    ) -> Result<(), CdrError> {
+        match value {
+            Some(value) => value.serialize(self),
+            None => T::default().serialize(self),
+        }
+    }
+
+    /// Writes an unbounded sequence.
+    pub fn write_sequence<T: CdrSerialize>(&mut self, values: &[T]) -> Result<(), CdrError> {
+        self.write_len(values.len())?;
+        values.iter().try_for_each(|v| v.serialize(self))
+    }
+
+    /// Writes a fixed-size array of `float64`.
+    pub fn write_array(&mut self, values: &[f64], len: usize) -> Result<(), CdrError> {
+        if values.len() != len {
+            return Err(CdrError::ArrayLengthMismatch {
+                expected: len,
+                actual: values.len(),
+            });
+        }
+        values.iter().for_each(|v| self.write_f64(*v));
+        Ok(())
+    }
+}
+
+/// A type that can be serialized as CDR.
+pub trait CdrSerialize {
+    /// Serializes the value to the writer.
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError>;
+}
+
+impl CdrSerialize for u32 {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_u32(*self);
+        Ok(())
+    }
+}
+
+impl CdrSerialize for f64 {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_f64(*self);
+        Ok(())
+    }
+}
+
+/// A message with a ROS 2 message definition.
+pub trait Ros2Message: CdrSerialize {
+    /// The fully-qualified type name, e.g. `foxglove_msgs/msg/Log`.
+    const TYPE_NAME: &'static str;
+    /// The message definition, concatenated with the definitions of its dependencies.
+    const DEFINITION: &'static str;
+}
+
+/// Wraps a message so that it is encoded as CDR, with a `ros2msg` schema.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ros2<T>(pub T);
+
+impl<T> From<T> for Ros2<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Ros2Message> Encode for Ros2<T> {
+    type Error = CdrError;
+
+    fn get_schema() -> Option<Schema> {
+        Some(Schema::new(
+            T::TYPE_NAME,
+            "ros2msg",
+            T::DEFINITION.as_bytes(),
+        ))
+    }
+
+    fn get_message_encoding() -> String {
+        "cdr".to_string()
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) -> Result<(), Self::Error> {
+        let mut w = CdrWriter::new();
+        self.0.serialize(&mut w)?;
+        buf.put_slice(&w.into_inner());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::log::Level;
+    use crate::schemas::{Color, Log, Pose, Vector3};
+
+    fn encode<T: Ros2Message>(msg: T) -> Vec<u8> {
+        let mut buf = Vec::new();
+        Ros2(msg).encode(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_encode_log() {
+        let data = encode(Log {
+            timestamp: Some(Timestamp {
+                seconds: 1,
+                nanos: 2,
+            }),
+            level: Level::Info as i32,
+            message: "hi".to_string(),
+            name: String::new(),
+            file: "a".to_string(),
+            line: 7,
+        });
+        #[rustfmt::skip]
+        let expected = [
+            0x00, 0x01, 0x00, 0x00, // header
+            1, 0, 0, 0, // timestamp.sec
+            2, 0, 0, 0, // timestamp.nanosec
+            2, // level
+            0, 0, 0, // padding
+            3, 0, 0, 0, b'h', b'i', 0, // message
+            0, // padding
+            1, 0, 0, 0, 0, // name
+            0, 0, 0, // padding
+            2, 0, 0, 0, b'a', 0, // file
+            0, 0, // padding
+            7, 0, 0, 0, // line
+        ];
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn test_encode_alignment_and_defaults() {
+        let data = encode(Pose {
+            position: Some(Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            }),
+            orientation: None,
+        });
+        let mut expected = CDR_LE_HEADER.to_vec();
+        for v in [1.0f64, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0] {
+            expected.extend_from_slice(&v.to_le_bytes());
+        }
+        assert_eq!(data, expected);
+
+        let mut w = CdrWriter::new();
+        w.write_bool(true);
+        Color::default().serialize(&mut w).unwrap();
+        // The float64 fields are aligned to 8 bytes after the header.
+        assert_eq!(w.into_inner().len(), 4 + 8 + 32);
+    }
+
+    #[test]
+    fn test_time_and_arrays() {
+        let mut w = CdrWriter::new();
+        w.write_duration(Some(&Duration {
+            seconds: -1,
+            nanos: -500_000_000,
+        }))
+        .unwrap();
+        let data = w.into_inner();
+        assert_eq!(&data[4..8], &(-2i32).to_le_bytes());
+        assert_eq!(&data[8..12], &500_000_000u32.to_le_bytes());
+
+        let mut w = CdrWriter::new();
+        assert!(matches!(
+            w.write_time(Some(&Timestamp {
+                seconds: i64::MAX,
+                nanos: 0
+            })),
+            Err(CdrError::TimeOutOfRange(_))
+        ));
+        assert!(matches!(
+            w.write_array(&[1.0, 2.0], 9),
+            Err(CdrError::ArrayLengthMismatch {
+                expected: 9,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Ros2::<Log>::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove_msgs/msg/Log");
+        assert_eq!(schema.encoding, "ros2msg");
+        assert!(schema
+            .data
+            .starts_with(b"# foxglove_msgs/msg/Log\n# A log message"));
+        assert_eq!(Ros2::<Log>::get_message_encoding(), "cdr");
+
+        let schema = Ros2::<Pose>::get_schema().unwrap();
+        assert_eq!(schema.name, "geometry_msgs/msg/Pose");
+        assert!(std::str::from_utf8(&schema.data)
+            .unwrap()
+            .contains("MSG: geometry_msgs/Point\n"));
+    }
+}
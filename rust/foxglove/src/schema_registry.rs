@@ -0,0 +1,231 @@
+//! Deduplication of schemas by content.
+//!
+//! Many channels often share the same schema, e.g. every channel logging `foxglove.SceneUpdate`.
+//! Each [`LogContext`](crate::LogContext) has a [`SchemaRegistry`], which interns the schemas of
+//! the channels built in the context, so that channels with the same schema share a single
+//! [`RegisteredSchema`]. Sinks use the [`SchemaId`] of a registered schema to do per-schema work
+//! once per unique schema rather than once per channel. For example, the MCAP sink writes a single
+//! schema record for each unique schema, and the WebSocket server encodes each unique schema once
+//! for its advertisements.
+//!
+//! A schema is evicted from the registry when the last channel using it is dropped.
+use crate::{FoxgloveError, Schema};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+use std::sync::{Arc, OnceLock, Weak};
+
+/// A key identifying a schema by its name, encoding, and a hash of its data.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct SchemaKey(u64);
+
+impl SchemaKey {
+    fn new(schema: &Schema) -> Self {
+        let mut hasher = DefaultHasher::new();
+        schema.name.hash(&mut hasher);
+        schema.encoding.hash(&mut hasher);
+        schema.data.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+/// A process-unique identifier for a registered schema.
+///
+/// Identifiers are never reused, so sinks can use them as keys for as long as they like.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct SchemaId(u64);
+
+impl SchemaId {
+    fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT_ID.fetch_add(1, Relaxed))
+    }
+}
+
+/// The registered schemas, by key. Hash collisions are resolved by comparing the schemas.
+type Entries = HashMap<SchemaKey, Vec<(SchemaId, Weak<RegisteredSchema>)>>;
+
+/// A schema shared by all the channels in a context with the same schema content.
+pub(crate) struct RegisteredSchema {
+    id: SchemaId,
+    key: SchemaKey,
+    schema: Schema,
+    /// The schema data encoded for a WebSocket advertisement.
+    advertised_data: OnceLock<Arc<str>>,
+    /// The entries of the registry this schema is registered with, if any.
+    registry: Weak<Mutex<Entries>>,
+}
+
+impl RegisteredSchema {
+    /// Returns a schema which is not registered with a registry, and so is not shared.
+    #[cfg(test)]
+    pub fn unregistered(schema: Schema) -> Arc<Self> {
+        Arc::new(Self {
+            id: SchemaId::next(),
+            key: SchemaKey::new(&schema),
+            schema,
+            advertised_data: OnceLock::new(),
+            registry: Weak::new(),
+        })
+    }
+
+    /// Returns the identifier of the schema.
+    pub fn id(&self) -> SchemaId {
+        self.id
+    }
+
+    /// Returns the schema.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Returns the schema data encoded for a WebSocket advertisement, calling `encode` to encode it
+    /// the first time.
+    ///
+    /// If `encode` returns an error, it will be called again next time.
+    pub fn advertised_data(
+        &self,
+        encode: impl FnOnce(&Schema) -> Result<String, FoxgloveError>,
+    ) -> Result<Arc<str>, FoxgloveError> {
+        if let Some(data) = self.advertised_data.get() {
+            return Ok(data.clone());
+        }
+        let data = Arc::from(encode(&self.schema)?);
+        Ok(self.advertised_data.get_or_init(|| data).clone())
+    }
+}
+
+impl Drop for RegisteredSchema {
+    fn drop(&mut self) {
+        let Some(entries) = self.registry.upgrade() else {
+            return;
+        };
+        let mut entries = entries.lock();
+        if let Some(schemas) = entries.get_mut(&self.key) {
+            schemas.retain(|(id, _)| *id != self.id);
+            if schemas.is_empty() {
+                entries.remove(&self.key);
+            }
+        }
+    }
+}
+
+impl PartialEq for RegisteredSchema {
+    fn eq(&self, other: &Self) -> bool {
+        self.schema == other.schema
+    }
+}
+
+impl std::fmt::Debug for RegisteredSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.schema.fmt(f)
+    }
+}
+
+/// The schemas of the channels in a context, keyed by schema content.
+pub(crate) struct SchemaRegistry {
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl SchemaRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::default(),
+        }
+    }
+
+    /// Registers a schema, returning the registered schema with the same name, encoding, and data
+    /// if there is one.
+    pub fn register(&self, schema: Schema) -> Arc<RegisteredSchema> {
+        let key = SchemaKey::new(&schema);
+        // Other schemas with the same key are kept alive until the lock is released, since
+        // dropping the last reference to a schema locks the registry.
+        let mut others = Vec::new();
+        let mut entries = self.entries.lock();
+        let schemas = entries.entry(key).or_default();
+        for (_, registered) in schemas.iter() {
+            match registered.upgrade() {
+                Some(registered) if registered.schema == schema => return registered,
+                Some(registered) => others.push(registered),
+                None => (),
+            }
+        }
+        let registered = Arc::new(RegisteredSchema {
+            id: SchemaId::next(),
+            key,
+            schema,
+            advertised_data: OnceLock::new(),
+            registry: Arc::downgrade(&self.entries),
+        });
+        schemas.push((registered.id, Arc::downgrade(&registered)));
+        drop(entries);
+        registered
+    }
+
+    /// Returns the number of unique schemas in the registry.
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.entries.lock().values().map(Vec::len).sum()
+    }
+}
+
+impl Default for SchemaRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deduplicates_by_content() {
+        let registry = SchemaRegistry::new();
+        let a = registry.register(Schema::new("a", "jsonschema", b"{}".as_slice()));
+        // Same content, different allocation.
+        let a2 = registry.register(Schema::new("a", "jsonschema", vec![b'{', b'}']));
+        assert!(Arc::ptr_eq(&a, &a2));
+
+        let others = [
+            Schema::new("b", "jsonschema", b"{}".as_slice()),
+            Schema::new("a", "protobuf", b"{}".as_slice()),
+            Schema::new("a", "jsonschema", b"[]".as_slice()),
+        ]
+        .map(|schema| registry.register(schema));
+        for other in &others {
+            assert_ne!(other.id(), a.id());
+        }
+        assert_eq!(registry.len(), 4);
+    }
+
+    #[test]
+    fn test_evicts_unused_schemas() {
+        let registry = SchemaRegistry::new();
+        let schema = Schema::new("a", "jsonschema", b"{}".as_slice());
+        let a = registry.register(schema.clone());
+        let a2 = registry.register(schema.clone());
+        drop(a);
+        assert_eq!(registry.len(), 1);
+        let id = a2.id();
+        drop(a2);
+        assert_eq!(registry.len(), 0);
+
+        // A schema registered again gets a new identifier.
+        assert_ne!(registry.register(schema).id(), id);
+    }
+
+    #[test]
+    fn test_advertised_data_is_memoized() {
+        let schema =
+            RegisteredSchema::unregistered(Schema::new("a", "jsonschema", b"{}".as_slice()));
+        let err = schema.advertised_data(|_| Err(FoxgloveError::SchemaRequired));
+        assert!(matches!(err, Err(FoxgloveError::SchemaRequired)));
+        let data = schema.advertised_data(|s| Ok(String::from_utf8(s.data.to_vec()).unwrap()));
+        assert_eq!(data.unwrap().as_ref(), "{}");
+        let data = schema.advertised_data(|_| panic!("encoded twice"));
+        assert_eq!(data.unwrap().as_ref(), "{}");
+    }
+}
@@ -5,13 +5,36 @@
 //!
 //! They're encoded as compact, binary protobuf messages,
 //! and can be conveniently used with the [`TypedChannel`](crate::TypedChannel) API.
+//!
+//! With the `nalgebra` or `glam` features, [`Vector3`], [`Point3`], [`Quaternion`], and [`Pose`]
+//! implement `From` conversions to and from the corresponding math library types.
 
 pub(crate) mod descriptors;
+#[cfg(feature = "flatbuffer")]
+#[rustfmt::skip]
+pub(crate) mod flatbuffer;
 #[allow(missing_docs)]
 #[rustfmt::skip]
 mod foxglove;
+#[cfg(feature = "glam")]
+mod glam;
 #[rustfmt::skip]
 mod impls;
+#[rustfmt::skip]
+mod json;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "omgidl")]
+#[rustfmt::skip]
+mod omgidl;
+#[cfg(feature = "ros1")]
+#[rustfmt::skip]
+mod ros1;
+#[cfg(feature = "ros2")]
+#[rustfmt::skip]
+mod ros2;
+mod scene;
 
 pub use self::foxglove::*;
+pub use self::scene::{SceneEntityBuilder, ScenePrimitive, SceneUpdateBuilder};
 pub use prost_types::{Duration, Timestamp};
@@ -0,0 +1,197 @@
+// This file is @generated by foxglove-proto-gen
+use crate::flatbuffer::FlatbufferMessage;
+
+#[allow(clippy::all, dead_code, deprecated, missing_docs, mismatched_lifetime_syntaxes, rustdoc::all, unused_imports)]
+mod generated;
+
+pub use generated::foxglove::*;
+
+impl FlatbufferMessage for ArrowPrimitive<'_> {
+    const TYPE_NAME: &'static str = "foxglove.ArrowPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/ArrowPrimitive.bfbs");
+}
+
+impl FlatbufferMessage for CameraCalibration<'_> {
+    const TYPE_NAME: &'static str = "foxglove.CameraCalibration";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/CameraCalibration.bfbs");
+}
+
+impl FlatbufferMessage for CircleAnnotation<'_> {
+    const TYPE_NAME: &'static str = "foxglove.CircleAnnotation";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/CircleAnnotation.bfbs");
+}
+
+impl FlatbufferMessage for Color<'_> {
+    const TYPE_NAME: &'static str = "foxglove.Color";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/Color.bfbs");
+}
+
+impl FlatbufferMessage for CompressedImage<'_> {
+    const TYPE_NAME: &'static str = "foxglove.CompressedImage";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/CompressedImage.bfbs");
+}
+
+impl FlatbufferMessage for CompressedVideo<'_> {
+    const TYPE_NAME: &'static str = "foxglove.CompressedVideo";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/CompressedVideo.bfbs");
+}
+
+impl FlatbufferMessage for CubePrimitive<'_> {
+    const TYPE_NAME: &'static str = "foxglove.CubePrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/CubePrimitive.bfbs");
+}
+
+impl FlatbufferMessage for CylinderPrimitive<'_> {
+    const TYPE_NAME: &'static str = "foxglove.CylinderPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/CylinderPrimitive.bfbs");
+}
+
+impl FlatbufferMessage for FrameTransform<'_> {
+    const TYPE_NAME: &'static str = "foxglove.FrameTransform";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/FrameTransform.bfbs");
+}
+
+impl FlatbufferMessage for FrameTransforms<'_> {
+    const TYPE_NAME: &'static str = "foxglove.FrameTransforms";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/FrameTransforms.bfbs");
+}
+
+impl FlatbufferMessage for GeoJSON<'_> {
+    const TYPE_NAME: &'static str = "foxglove.GeoJSON";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/GeoJSON.bfbs");
+}
+
+impl FlatbufferMessage for Grid<'_> {
+    const TYPE_NAME: &'static str = "foxglove.Grid";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/Grid.bfbs");
+}
+
+impl FlatbufferMessage for ImageAnnotations<'_> {
+    const TYPE_NAME: &'static str = "foxglove.ImageAnnotations";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/ImageAnnotations.bfbs");
+}
+
+impl FlatbufferMessage for KeyValuePair<'_> {
+    const TYPE_NAME: &'static str = "foxglove.KeyValuePair";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/KeyValuePair.bfbs");
+}
+
+impl FlatbufferMessage for LaserScan<'_> {
+    const TYPE_NAME: &'static str = "foxglove.LaserScan";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/LaserScan.bfbs");
+}
+
+impl FlatbufferMessage for LinePrimitive<'_> {
+    const TYPE_NAME: &'static str = "foxglove.LinePrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/LinePrimitive.bfbs");
+}
+
+impl FlatbufferMessage for LocationFix<'_> {
+    const TYPE_NAME: &'static str = "foxglove.LocationFix";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/LocationFix.bfbs");
+}
+
+impl FlatbufferMessage for Log<'_> {
+    const TYPE_NAME: &'static str = "foxglove.Log";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/Log.bfbs");
+}
+
+impl FlatbufferMessage for ModelPrimitive<'_> {
+    const TYPE_NAME: &'static str = "foxglove.ModelPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/ModelPrimitive.bfbs");
+}
+
+impl FlatbufferMessage for PackedElementField<'_> {
+    const TYPE_NAME: &'static str = "foxglove.PackedElementField";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/PackedElementField.bfbs");
+}
+
+impl FlatbufferMessage for Point2<'_> {
+    const TYPE_NAME: &'static str = "foxglove.Point2";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/Point2.bfbs");
+}
+
+impl FlatbufferMessage for Point3<'_> {
+    const TYPE_NAME: &'static str = "foxglove.Point3";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/Point3.bfbs");
+}
+
+impl FlatbufferMessage for PointCloud<'_> {
+    const TYPE_NAME: &'static str = "foxglove.PointCloud";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/PointCloud.bfbs");
+}
+
+impl FlatbufferMessage for PointsAnnotation<'_> {
+    const TYPE_NAME: &'static str = "foxglove.PointsAnnotation";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/PointsAnnotation.bfbs");
+}
+
+impl FlatbufferMessage for Pose<'_> {
+    const TYPE_NAME: &'static str = "foxglove.Pose";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/Pose.bfbs");
+}
+
+impl FlatbufferMessage for PoseInFrame<'_> {
+    const TYPE_NAME: &'static str = "foxglove.PoseInFrame";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/PoseInFrame.bfbs");
+}
+
+impl FlatbufferMessage for PosesInFrame<'_> {
+    const TYPE_NAME: &'static str = "foxglove.PosesInFrame";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/PosesInFrame.bfbs");
+}
+
+impl FlatbufferMessage for Quaternion<'_> {
+    const TYPE_NAME: &'static str = "foxglove.Quaternion";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/Quaternion.bfbs");
+}
+
+impl FlatbufferMessage for RawImage<'_> {
+    const TYPE_NAME: &'static str = "foxglove.RawImage";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/RawImage.bfbs");
+}
+
+impl FlatbufferMessage for SceneEntity<'_> {
+    const TYPE_NAME: &'static str = "foxglove.SceneEntity";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/SceneEntity.bfbs");
+}
+
+impl FlatbufferMessage for SceneEntityDeletion<'_> {
+    const TYPE_NAME: &'static str = "foxglove.SceneEntityDeletion";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/SceneEntityDeletion.bfbs");
+}
+
+impl FlatbufferMessage for SceneUpdate<'_> {
+    const TYPE_NAME: &'static str = "foxglove.SceneUpdate";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/SceneUpdate.bfbs");
+}
+
+impl FlatbufferMessage for SpherePrimitive<'_> {
+    const TYPE_NAME: &'static str = "foxglove.SpherePrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/SpherePrimitive.bfbs");
+}
+
+impl FlatbufferMessage for TextAnnotation<'_> {
+    const TYPE_NAME: &'static str = "foxglove.TextAnnotation";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/TextAnnotation.bfbs");
+}
+
+impl FlatbufferMessage for TextPrimitive<'_> {
+    const TYPE_NAME: &'static str = "foxglove.TextPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/TextPrimitive.bfbs");
+}
+
+impl FlatbufferMessage for TriangleListPrimitive<'_> {
+    const TYPE_NAME: &'static str = "foxglove.TriangleListPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/TriangleListPrimitive.bfbs");
+}
+
+impl FlatbufferMessage for Vector2<'_> {
+    const TYPE_NAME: &'static str = "foxglove.Vector2";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/Vector2.bfbs");
+}
+
+impl FlatbufferMessage for Vector3<'_> {
+    const TYPE_NAME: &'static str = "foxglove.Vector3";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("flatbuffer/Vector3.bfbs");
+}
@@ -0,0 +1,271 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum ArrowPrimitiveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A primitive representing an arrow
+pub struct ArrowPrimitive<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ArrowPrimitive<'a> {
+  type Inner = ArrowPrimitive<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ArrowPrimitive<'a> {
+  pub const VT_POSE: flatbuffers::VOffsetT = 4;
+  pub const VT_SHAFT_LENGTH: flatbuffers::VOffsetT = 6;
+  pub const VT_SHAFT_DIAMETER: flatbuffers::VOffsetT = 8;
+  pub const VT_HEAD_LENGTH: flatbuffers::VOffsetT = 10;
+  pub const VT_HEAD_DIAMETER: flatbuffers::VOffsetT = 12;
+  pub const VT_COLOR: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ArrowPrimitive { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ArrowPrimitiveArgs<'args>
+  ) -> flatbuffers::WIPOffset<ArrowPrimitive<'bldr>> {
+    let mut builder = ArrowPrimitiveBuilder::new(_fbb);
+    builder.add_head_diameter(args.head_diameter);
+    builder.add_head_length(args.head_length);
+    builder.add_shaft_diameter(args.shaft_diameter);
+    builder.add_shaft_length(args.shaft_length);
+    if let Some(x) = args.color { builder.add_color(x); }
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    builder.finish()
+  }
+
+
+  /// Position of the arrow's tail and orientation of the arrow. Identity orientation means the arrow points in the +x direction.
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(ArrowPrimitive::VT_POSE, None)}
+  }
+  /// Length of the arrow shaft
+  #[inline]
+  pub fn shaft_length(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ArrowPrimitive::VT_SHAFT_LENGTH, Some(0.0)).unwrap()}
+  }
+  /// Diameter of the arrow shaft
+  #[inline]
+  pub fn shaft_diameter(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ArrowPrimitive::VT_SHAFT_DIAMETER, Some(0.0)).unwrap()}
+  }
+  /// Length of the arrow head
+  #[inline]
+  pub fn head_length(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ArrowPrimitive::VT_HEAD_LENGTH, Some(0.0)).unwrap()}
+  }
+  /// Diameter of the arrow head
+  #[inline]
+  pub fn head_diameter(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ArrowPrimitive::VT_HEAD_DIAMETER, Some(0.0)).unwrap()}
+  }
+  /// Color of the arrow
+  #[inline]
+  pub fn color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(ArrowPrimitive::VT_COLOR, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ArrowPrimitive<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<f64>("shaft_length", Self::VT_SHAFT_LENGTH, false)?
+     .visit_field::<f64>("shaft_diameter", Self::VT_SHAFT_DIAMETER, false)?
+     .visit_field::<f64>("head_length", Self::VT_HEAD_LENGTH, false)?
+     .visit_field::<f64>("head_diameter", Self::VT_HEAD_DIAMETER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("color", Self::VT_COLOR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ArrowPrimitiveArgs<'a> {
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub shaft_length: f64,
+    pub shaft_diameter: f64,
+    pub head_length: f64,
+    pub head_diameter: f64,
+    pub color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+}
+impl<'a> Default for ArrowPrimitiveArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ArrowPrimitiveArgs {
+      pose: None,
+      shaft_length: 0.0,
+      shaft_diameter: 0.0,
+      head_length: 0.0,
+      head_diameter: 0.0,
+      color: None,
+    }
+  }
+}
+
+pub struct ArrowPrimitiveBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ArrowPrimitiveBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(ArrowPrimitive::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_shaft_length(&mut self, shaft_length: f64) {
+    self.fbb_.push_slot::<f64>(ArrowPrimitive::VT_SHAFT_LENGTH, shaft_length, 0.0);
+  }
+  #[inline]
+  pub fn add_shaft_diameter(&mut self, shaft_diameter: f64) {
+    self.fbb_.push_slot::<f64>(ArrowPrimitive::VT_SHAFT_DIAMETER, shaft_diameter, 0.0);
+  }
+  #[inline]
+  pub fn add_head_length(&mut self, head_length: f64) {
+    self.fbb_.push_slot::<f64>(ArrowPrimitive::VT_HEAD_LENGTH, head_length, 0.0);
+  }
+  #[inline]
+  pub fn add_head_diameter(&mut self, head_diameter: f64) {
+    self.fbb_.push_slot::<f64>(ArrowPrimitive::VT_HEAD_DIAMETER, head_diameter, 0.0);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(ArrowPrimitive::VT_COLOR, color);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ArrowPrimitiveBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ArrowPrimitiveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ArrowPrimitive<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ArrowPrimitive<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ArrowPrimitive");
+      ds.field("pose", &self.pose());
+      ds.field("shaft_length", &self.shaft_length());
+      ds.field("shaft_diameter", &self.shaft_diameter());
+      ds.field("head_length", &self.head_length());
+      ds.field("head_diameter", &self.head_diameter());
+      ds.field("color", &self.color());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ArrowPrimitive`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_arrow_primitive_unchecked`.
+pub fn root_as_arrow_primitive(buf: &[u8]) -> Result<ArrowPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<ArrowPrimitive>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ArrowPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_arrow_primitive_unchecked`.
+pub fn size_prefixed_root_as_arrow_primitive(buf: &[u8]) -> Result<ArrowPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<ArrowPrimitive>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ArrowPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_arrow_primitive_unchecked`.
+pub fn root_as_arrow_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ArrowPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<ArrowPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ArrowPrimitive` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_arrow_primitive_unchecked`.
+pub fn size_prefixed_root_as_arrow_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ArrowPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<ArrowPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ArrowPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ArrowPrimitive`.
+pub unsafe fn root_as_arrow_primitive_unchecked(buf: &[u8]) -> ArrowPrimitive {
+  flatbuffers::root_unchecked::<ArrowPrimitive>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ArrowPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ArrowPrimitive`.
+pub unsafe fn size_prefixed_root_as_arrow_primitive_unchecked(buf: &[u8]) -> ArrowPrimitive {
+  flatbuffers::size_prefixed_root_unchecked::<ArrowPrimitive>(buf)
+}
+#[inline]
+pub fn finish_arrow_primitive_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<ArrowPrimitive<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_arrow_primitive_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<ArrowPrimitive<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,366 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum CameraCalibrationOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Camera calibration parameters
+pub struct CameraCalibration<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CameraCalibration<'a> {
+  type Inner = CameraCalibration<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CameraCalibration<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_WIDTH: flatbuffers::VOffsetT = 8;
+  pub const VT_HEIGHT: flatbuffers::VOffsetT = 10;
+  pub const VT_DISTORTION_MODEL: flatbuffers::VOffsetT = 12;
+  pub const VT_D: flatbuffers::VOffsetT = 14;
+  pub const VT_K: flatbuffers::VOffsetT = 16;
+  pub const VT_R: flatbuffers::VOffsetT = 18;
+  pub const VT_P: flatbuffers::VOffsetT = 20;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CameraCalibration { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CameraCalibrationArgs<'args>
+  ) -> flatbuffers::WIPOffset<CameraCalibration<'bldr>> {
+    let mut builder = CameraCalibrationBuilder::new(_fbb);
+    if let Some(x) = args.p { builder.add_p(x); }
+    if let Some(x) = args.r { builder.add_r(x); }
+    if let Some(x) = args.k { builder.add_k(x); }
+    if let Some(x) = args.d { builder.add_d(x); }
+    if let Some(x) = args.distortion_model { builder.add_distortion_model(x); }
+    builder.add_height(args.height);
+    builder.add_width(args.width);
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of calibration data
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(CameraCalibration::VT_TIMESTAMP, None)}
+  }
+  /// Frame of reference for the camera. The origin of the frame is the optical center of the camera. +x points to the right in the image, +y points down, and +z points into the plane of the image.
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CameraCalibration::VT_FRAME_ID, None)}
+  }
+  /// Image width
+  #[inline]
+  pub fn width(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(CameraCalibration::VT_WIDTH, Some(0)).unwrap()}
+  }
+  /// Image height
+  #[inline]
+  pub fn height(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(CameraCalibration::VT_HEIGHT, Some(0)).unwrap()}
+  }
+  /// Name of distortion model
+  ///
+  /// Supported parameters: `plumb_bob` (k1, k2, p1, p2, k3) and `rational_polynomial` (k1, k2, p1, p2, k3, k4, k5, k6). Distortion models are based on [OpenCV's](https://docs.opencv.org/2.4/modules/calib3d/doc/camera_calibration_and_3d_reconstruction.html) [pinhole camera model](https://en.wikipedia.org/wiki/Distortion_%28optics%29#Software_correction). This is the same [implementation used by ROS](http://docs.ros.org/en/diamondback/api/image_geometry/html/c++/pinhole__camera__model_8cpp_source.html)
+  #[inline]
+  pub fn distortion_model(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CameraCalibration::VT_DISTORTION_MODEL, None)}
+  }
+  /// Distortion parameters
+  #[inline]
+  pub fn d(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(CameraCalibration::VT_D, None)}
+  }
+  /// Intrinsic camera matrix (3x3 row-major matrix)
+  ///
+  /// A 3x3 row-major matrix for the raw (distorted) image.
+  ///
+  /// Projects 3D points in the camera coordinate frame to 2D pixel coordinates using the focal lengths (fx, fy) and principal point (cx, cy).
+  ///
+  /// ```text
+  ///     [fx  0 cx]
+  /// K = [ 0 fy cy]
+  ///     [ 0  0  1]
+  /// ```
+  /// length 9
+  #[inline]
+  pub fn k(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(CameraCalibration::VT_K, None)}
+  }
+  /// Rectification matrix (stereo cameras only, 3x3 row-major matrix)
+  ///
+  /// A rotation matrix aligning the camera coordinate system to the ideal stereo image plane so that epipolar lines in both stereo images are parallel.
+  /// length 9
+  #[inline]
+  pub fn r(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(CameraCalibration::VT_R, None)}
+  }
+  /// Projection/camera matrix (3x4 row-major matrix)
+  ///
+  /// ```text
+  ///     [fx'  0  cx' Tx]
+  /// P = [ 0  fy' cy' Ty]
+  ///     [ 0   0   1   0]
+  /// ```
+  ///
+  /// By convention, this matrix specifies the intrinsic (camera) matrix of the processed (rectified) image. That is, the left 3x3 portion is the normal camera intrinsic matrix for the rectified image.
+  ///
+  /// It projects 3D points in the camera coordinate frame to 2D pixel coordinates using the focal lengths (fx', fy') and principal point (cx', cy') - these may differ from the values in K.
+  ///
+  /// For monocular cameras, Tx = Ty = 0. Normally, monocular cameras will also have R = the identity and P[1:3,1:3] = K.
+  ///
+  /// For a stereo pair, the fourth column [Tx Ty 0]' is related to the position of the optical center of the second camera in the first camera's frame. We assume Tz = 0 so both cameras are in the same stereo image plane. The first camera always has Tx = Ty = 0. For the right (second) camera of a horizontal stereo pair, Ty = 0 and Tx = -fx' * B, where B is the baseline between the cameras.
+  ///
+  /// Given a 3D point [X Y Z]', the projection (x, y) of the point onto the rectified image is given by:
+  ///
+  /// ```text
+  /// [u v w]' = P * [X Y Z 1]'
+  ///        x = u / w
+  ///        y = v / w
+  /// ```
+  ///
+  /// This holds for both images of a stereo pair.
+  /// length 12
+  #[inline]
+  pub fn p(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(CameraCalibration::VT_P, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CameraCalibration<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<u32>("width", Self::VT_WIDTH, false)?
+     .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("distortion_model", Self::VT_DISTORTION_MODEL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("d", Self::VT_D, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("k", Self::VT_K, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("r", Self::VT_R, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("p", Self::VT_P, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CameraCalibrationArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub width: u32,
+    pub height: u32,
+    pub distortion_model: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub d: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub k: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub r: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub p: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+}
+impl<'a> Default for CameraCalibrationArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CameraCalibrationArgs {
+      timestamp: None,
+      frame_id: None,
+      width: 0,
+      height: 0,
+      distortion_model: None,
+      d: None,
+      k: None,
+      r: None,
+      p: None,
+    }
+  }
+}
+
+pub struct CameraCalibrationBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CameraCalibrationBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(CameraCalibration::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CameraCalibration::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_width(&mut self, width: u32) {
+    self.fbb_.push_slot::<u32>(CameraCalibration::VT_WIDTH, width, 0);
+  }
+  #[inline]
+  pub fn add_height(&mut self, height: u32) {
+    self.fbb_.push_slot::<u32>(CameraCalibration::VT_HEIGHT, height, 0);
+  }
+  #[inline]
+  pub fn add_distortion_model(&mut self, distortion_model: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CameraCalibration::VT_DISTORTION_MODEL, distortion_model);
+  }
+  #[inline]
+  pub fn add_d(&mut self, d: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CameraCalibration::VT_D, d);
+  }
+  #[inline]
+  pub fn add_k(&mut self, k: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CameraCalibration::VT_K, k);
+  }
+  #[inline]
+  pub fn add_r(&mut self, r: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CameraCalibration::VT_R, r);
+  }
+  #[inline]
+  pub fn add_p(&mut self, p: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CameraCalibration::VT_P, p);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CameraCalibrationBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CameraCalibrationBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CameraCalibration<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CameraCalibration<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CameraCalibration");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("width", &self.width());
+      ds.field("height", &self.height());
+      ds.field("distortion_model", &self.distortion_model());
+      ds.field("d", &self.d());
+      ds.field("k", &self.k());
+      ds.field("r", &self.r());
+      ds.field("p", &self.p());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `CameraCalibration`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_camera_calibration_unchecked`.
+pub fn root_as_camera_calibration(buf: &[u8]) -> Result<CameraCalibration, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<CameraCalibration>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `CameraCalibration` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_camera_calibration_unchecked`.
+pub fn size_prefixed_root_as_camera_calibration(buf: &[u8]) -> Result<CameraCalibration, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<CameraCalibration>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `CameraCalibration` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_camera_calibration_unchecked`.
+pub fn root_as_camera_calibration_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CameraCalibration<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<CameraCalibration<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `CameraCalibration` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_camera_calibration_unchecked`.
+pub fn size_prefixed_root_as_camera_calibration_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CameraCalibration<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<CameraCalibration<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a CameraCalibration and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `CameraCalibration`.
+pub unsafe fn root_as_camera_calibration_unchecked(buf: &[u8]) -> CameraCalibration {
+  flatbuffers::root_unchecked::<CameraCalibration>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed CameraCalibration and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `CameraCalibration`.
+pub unsafe fn size_prefixed_root_as_camera_calibration_unchecked(buf: &[u8]) -> CameraCalibration {
+  flatbuffers::size_prefixed_root_unchecked::<CameraCalibration>(buf)
+}
+#[inline]
+pub fn finish_camera_calibration_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<CameraCalibration<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_camera_calibration_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<CameraCalibration<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,272 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum CircleAnnotationOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A circle annotation on a 2D image
+pub struct CircleAnnotation<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CircleAnnotation<'a> {
+  type Inner = CircleAnnotation<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CircleAnnotation<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_POSITION: flatbuffers::VOffsetT = 6;
+  pub const VT_DIAMETER: flatbuffers::VOffsetT = 8;
+  pub const VT_THICKNESS: flatbuffers::VOffsetT = 10;
+  pub const VT_FILL_COLOR: flatbuffers::VOffsetT = 12;
+  pub const VT_OUTLINE_COLOR: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CircleAnnotation { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CircleAnnotationArgs<'args>
+  ) -> flatbuffers::WIPOffset<CircleAnnotation<'bldr>> {
+    let mut builder = CircleAnnotationBuilder::new(_fbb);
+    builder.add_thickness(args.thickness);
+    builder.add_diameter(args.diameter);
+    if let Some(x) = args.outline_color { builder.add_outline_color(x); }
+    if let Some(x) = args.fill_color { builder.add_fill_color(x); }
+    if let Some(x) = args.position { builder.add_position(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of circle
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(CircleAnnotation::VT_TIMESTAMP, None)}
+  }
+  /// Center of the circle in 2D image coordinates (pixels).
+  /// The coordinate uses the top-left corner of the top-left pixel of the image as the origin.
+  #[inline]
+  pub fn position(&self) -> Option<Point2<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Point2>>(CircleAnnotation::VT_POSITION, None)}
+  }
+  /// Circle diameter in pixels
+  #[inline]
+  pub fn diameter(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(CircleAnnotation::VT_DIAMETER, Some(0.0)).unwrap()}
+  }
+  /// Line thickness in pixels
+  #[inline]
+  pub fn thickness(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(CircleAnnotation::VT_THICKNESS, Some(0.0)).unwrap()}
+  }
+  /// Fill color
+  #[inline]
+  pub fn fill_color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(CircleAnnotation::VT_FILL_COLOR, None)}
+  }
+  /// Outline color
+  #[inline]
+  pub fn outline_color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(CircleAnnotation::VT_OUTLINE_COLOR, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CircleAnnotation<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Point2>>("position", Self::VT_POSITION, false)?
+     .visit_field::<f64>("diameter", Self::VT_DIAMETER, false)?
+     .visit_field::<f64>("thickness", Self::VT_THICKNESS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("fill_color", Self::VT_FILL_COLOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("outline_color", Self::VT_OUTLINE_COLOR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CircleAnnotationArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub position: Option<flatbuffers::WIPOffset<Point2<'a>>>,
+    pub diameter: f64,
+    pub thickness: f64,
+    pub fill_color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+    pub outline_color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+}
+impl<'a> Default for CircleAnnotationArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CircleAnnotationArgs {
+      timestamp: None,
+      position: None,
+      diameter: 0.0,
+      thickness: 0.0,
+      fill_color: None,
+      outline_color: None,
+    }
+  }
+}
+
+pub struct CircleAnnotationBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CircleAnnotationBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(CircleAnnotation::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_position(&mut self, position: flatbuffers::WIPOffset<Point2<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Point2>>(CircleAnnotation::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn add_diameter(&mut self, diameter: f64) {
+    self.fbb_.push_slot::<f64>(CircleAnnotation::VT_DIAMETER, diameter, 0.0);
+  }
+  #[inline]
+  pub fn add_thickness(&mut self, thickness: f64) {
+    self.fbb_.push_slot::<f64>(CircleAnnotation::VT_THICKNESS, thickness, 0.0);
+  }
+  #[inline]
+  pub fn add_fill_color(&mut self, fill_color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(CircleAnnotation::VT_FILL_COLOR, fill_color);
+  }
+  #[inline]
+  pub fn add_outline_color(&mut self, outline_color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(CircleAnnotation::VT_OUTLINE_COLOR, outline_color);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CircleAnnotationBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CircleAnnotationBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CircleAnnotation<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CircleAnnotation<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CircleAnnotation");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("position", &self.position());
+      ds.field("diameter", &self.diameter());
+      ds.field("thickness", &self.thickness());
+      ds.field("fill_color", &self.fill_color());
+      ds.field("outline_color", &self.outline_color());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `CircleAnnotation`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_circle_annotation_unchecked`.
+pub fn root_as_circle_annotation(buf: &[u8]) -> Result<CircleAnnotation, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<CircleAnnotation>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `CircleAnnotation` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_circle_annotation_unchecked`.
+pub fn size_prefixed_root_as_circle_annotation(buf: &[u8]) -> Result<CircleAnnotation, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<CircleAnnotation>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `CircleAnnotation` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_circle_annotation_unchecked`.
+pub fn root_as_circle_annotation_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CircleAnnotation<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<CircleAnnotation<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `CircleAnnotation` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_circle_annotation_unchecked`.
+pub fn size_prefixed_root_as_circle_annotation_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CircleAnnotation<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<CircleAnnotation<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a CircleAnnotation and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `CircleAnnotation`.
+pub unsafe fn root_as_circle_annotation_unchecked(buf: &[u8]) -> CircleAnnotation {
+  flatbuffers::root_unchecked::<CircleAnnotation>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed CircleAnnotation and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `CircleAnnotation`.
+pub unsafe fn size_prefixed_root_as_circle_annotation_unchecked(buf: &[u8]) -> CircleAnnotation {
+  flatbuffers::size_prefixed_root_unchecked::<CircleAnnotation>(buf)
+}
+#[inline]
+pub fn finish_circle_annotation_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<CircleAnnotation<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_circle_annotation_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<CircleAnnotation<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,235 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum ColorOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A color in RGBA format
+pub struct Color<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Color<'a> {
+  type Inner = Color<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Color<'a> {
+  pub const VT_R: flatbuffers::VOffsetT = 4;
+  pub const VT_G: flatbuffers::VOffsetT = 6;
+  pub const VT_B: flatbuffers::VOffsetT = 8;
+  pub const VT_A: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Color { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ColorArgs
+  ) -> flatbuffers::WIPOffset<Color<'bldr>> {
+    let mut builder = ColorBuilder::new(_fbb);
+    builder.add_a(args.a);
+    builder.add_b(args.b);
+    builder.add_g(args.g);
+    builder.add_r(args.r);
+    builder.finish()
+  }
+
+
+  /// Red value between 0 and 1
+  #[inline]
+  pub fn r(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Color::VT_R, Some(1.0)).unwrap()}
+  }
+  /// Green value between 0 and 1
+  #[inline]
+  pub fn g(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Color::VT_G, Some(1.0)).unwrap()}
+  }
+  /// Blue value between 0 and 1
+  #[inline]
+  pub fn b(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Color::VT_B, Some(1.0)).unwrap()}
+  }
+  /// Alpha value between 0 and 1
+  #[inline]
+  pub fn a(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Color::VT_A, Some(1.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Color<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f64>("r", Self::VT_R, false)?
+     .visit_field::<f64>("g", Self::VT_G, false)?
+     .visit_field::<f64>("b", Self::VT_B, false)?
+     .visit_field::<f64>("a", Self::VT_A, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ColorArgs {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+impl<'a> Default for ColorArgs {
+  #[inline]
+  fn default() -> Self {
+    ColorArgs {
+      r: 1.0,
+      g: 1.0,
+      b: 1.0,
+      a: 1.0,
+    }
+  }
+}
+
+pub struct ColorBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ColorBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_r(&mut self, r: f64) {
+    self.fbb_.push_slot::<f64>(Color::VT_R, r, 1.0);
+  }
+  #[inline]
+  pub fn add_g(&mut self, g: f64) {
+    self.fbb_.push_slot::<f64>(Color::VT_G, g, 1.0);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: f64) {
+    self.fbb_.push_slot::<f64>(Color::VT_B, b, 1.0);
+  }
+  #[inline]
+  pub fn add_a(&mut self, a: f64) {
+    self.fbb_.push_slot::<f64>(Color::VT_A, a, 1.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ColorBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ColorBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Color<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Color<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Color");
+      ds.field("r", &self.r());
+      ds.field("g", &self.g());
+      ds.field("b", &self.b());
+      ds.field("a", &self.a());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `Color`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_color_unchecked`.
+pub fn root_as_color(buf: &[u8]) -> Result<Color, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<Color>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `Color` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_color_unchecked`.
+pub fn size_prefixed_root_as_color(buf: &[u8]) -> Result<Color, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<Color>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `Color` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_color_unchecked`.
+pub fn root_as_color_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Color<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<Color<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `Color` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_color_unchecked`.
+pub fn size_prefixed_root_as_color_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Color<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<Color<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a Color and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `Color`.
+pub unsafe fn root_as_color_unchecked(buf: &[u8]) -> Color {
+  flatbuffers::root_unchecked::<Color>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed Color and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `Color`.
+pub unsafe fn size_prefixed_root_as_color_unchecked(buf: &[u8]) -> Color {
+  flatbuffers::size_prefixed_root_unchecked::<Color>(buf)
+}
+#[inline]
+pub fn finish_color_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Color<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_color_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Color<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
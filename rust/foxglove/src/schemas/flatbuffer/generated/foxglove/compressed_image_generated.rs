@@ -0,0 +1,237 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum CompressedImageOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A compressed image
+pub struct CompressedImage<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CompressedImage<'a> {
+  type Inner = CompressedImage<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CompressedImage<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_DATA: flatbuffers::VOffsetT = 8;
+  pub const VT_FORMAT: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CompressedImage { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CompressedImageArgs<'args>
+  ) -> flatbuffers::WIPOffset<CompressedImage<'bldr>> {
+    let mut builder = CompressedImageBuilder::new(_fbb);
+    if let Some(x) = args.format { builder.add_format(x); }
+    if let Some(x) = args.data { builder.add_data(x); }
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of image
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(CompressedImage::VT_TIMESTAMP, None)}
+  }
+  /// Frame of reference for the image. The origin of the frame is the optical center of the camera. +x points to the right in the image, +y points down, and +z points into the plane of the image.
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CompressedImage::VT_FRAME_ID, None)}
+  }
+  /// Compressed image data
+  #[inline]
+  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(CompressedImage::VT_DATA, None)}
+  }
+  /// Image format
+  ///
+  /// Supported values: image media types supported by Chrome, such as `webp`, `jpeg`, `png`
+  #[inline]
+  pub fn format(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CompressedImage::VT_FORMAT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CompressedImage<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("data", Self::VT_DATA, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("format", Self::VT_FORMAT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CompressedImageArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub format: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for CompressedImageArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CompressedImageArgs {
+      timestamp: None,
+      frame_id: None,
+      data: None,
+      format: None,
+    }
+  }
+}
+
+pub struct CompressedImageBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CompressedImageBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(CompressedImage::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompressedImage::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompressedImage::VT_DATA, data);
+  }
+  #[inline]
+  pub fn add_format(&mut self, format: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompressedImage::VT_FORMAT, format);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CompressedImageBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CompressedImageBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CompressedImage<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CompressedImage<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CompressedImage");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("data", &self.data());
+      ds.field("format", &self.format());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `CompressedImage`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_compressed_image_unchecked`.
+pub fn root_as_compressed_image(buf: &[u8]) -> Result<CompressedImage, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<CompressedImage>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `CompressedImage` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_compressed_image_unchecked`.
+pub fn size_prefixed_root_as_compressed_image(buf: &[u8]) -> Result<CompressedImage, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<CompressedImage>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `CompressedImage` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_compressed_image_unchecked`.
+pub fn root_as_compressed_image_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CompressedImage<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<CompressedImage<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `CompressedImage` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_compressed_image_unchecked`.
+pub fn size_prefixed_root_as_compressed_image_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CompressedImage<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<CompressedImage<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a CompressedImage and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `CompressedImage`.
+pub unsafe fn root_as_compressed_image_unchecked(buf: &[u8]) -> CompressedImage {
+  flatbuffers::root_unchecked::<CompressedImage>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed CompressedImage and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `CompressedImage`.
+pub unsafe fn size_prefixed_root_as_compressed_image_unchecked(buf: &[u8]) -> CompressedImage {
+  flatbuffers::size_prefixed_root_unchecked::<CompressedImage>(buf)
+}
+#[inline]
+pub fn finish_compressed_image_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<CompressedImage<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_compressed_image_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<CompressedImage<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
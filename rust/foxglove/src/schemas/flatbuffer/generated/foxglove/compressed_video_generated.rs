@@ -0,0 +1,263 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum CompressedVideoOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A single frame of a compressed video bitstream
+pub struct CompressedVideo<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CompressedVideo<'a> {
+  type Inner = CompressedVideo<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CompressedVideo<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_DATA: flatbuffers::VOffsetT = 8;
+  pub const VT_FORMAT: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CompressedVideo { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CompressedVideoArgs<'args>
+  ) -> flatbuffers::WIPOffset<CompressedVideo<'bldr>> {
+    let mut builder = CompressedVideoBuilder::new(_fbb);
+    if let Some(x) = args.format { builder.add_format(x); }
+    if let Some(x) = args.data { builder.add_data(x); }
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of video frame
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(CompressedVideo::VT_TIMESTAMP, None)}
+  }
+  /// Frame of reference for the video.
+  ///
+  /// The origin of the frame is the optical center of the camera. +x points to the right in the video, +y points down, and +z points into the plane of the video.
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CompressedVideo::VT_FRAME_ID, None)}
+  }
+  /// Compressed video frame data.
+  ///
+  /// For packet-based video codecs this data must begin and end on packet boundaries (no partial packets), and must contain enough video packets to decode exactly one image (either a keyframe or delta frame). Note: Foxglove does not support video streams that include B frames because they require lookahead.
+  ///
+  /// Specifically, the requirements for different `format` values are:
+  ///
+  /// - `h264`
+  ///   - Use Annex B formatted data
+  ///   - Each CompressedVideo message should contain enough NAL units to decode exactly one video frame
+  ///   - Each message containing a key frame (IDR) must also include a SPS NAL unit
+  ///
+  /// - `h265` (HEVC)
+  ///   - Use Annex B formatted data
+  ///   - Each CompressedVideo message should contain enough NAL units to decode exactly one video frame
+  ///   - Each message containing a key frame (IRAP) must also include relevant VPS/SPS/PPS NAL units
+  ///
+  /// - `vp9`
+  ///   - Each CompressedVideo message should contain exactly one video frame
+  ///
+  /// - `av1`
+  ///   - Use the "Low overhead bitstream format" (section 5.2)
+  ///   - Each CompressedVideo message should contain enough OBUs to decode exactly one video frame
+  ///   - Each message containing a key frame must also include a Sequence Header OBU
+  #[inline]
+  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(CompressedVideo::VT_DATA, None)}
+  }
+  /// Video format.
+  ///
+  /// Supported values: `h264`, `h265`, `vp9`, `av1`.
+  ///
+  /// Note: compressed video support is subject to hardware limitations and patent licensing, so not all encodings may be supported on all platforms. See more about [H.265 support](https://caniuse.com/hevc), [VP9 support](https://caniuse.com/webm), and [AV1 support](https://caniuse.com/av1).
+  #[inline]
+  pub fn format(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CompressedVideo::VT_FORMAT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CompressedVideo<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("data", Self::VT_DATA, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("format", Self::VT_FORMAT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CompressedVideoArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub format: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for CompressedVideoArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CompressedVideoArgs {
+      timestamp: None,
+      frame_id: None,
+      data: None,
+      format: None,
+    }
+  }
+}
+
+pub struct CompressedVideoBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CompressedVideoBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(CompressedVideo::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompressedVideo::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompressedVideo::VT_DATA, data);
+  }
+  #[inline]
+  pub fn add_format(&mut self, format: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompressedVideo::VT_FORMAT, format);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CompressedVideoBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CompressedVideoBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CompressedVideo<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CompressedVideo<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CompressedVideo");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("data", &self.data());
+      ds.field("format", &self.format());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `CompressedVideo`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_compressed_video_unchecked`.
+pub fn root_as_compressed_video(buf: &[u8]) -> Result<CompressedVideo, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<CompressedVideo>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `CompressedVideo` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_compressed_video_unchecked`.
+pub fn size_prefixed_root_as_compressed_video(buf: &[u8]) -> Result<CompressedVideo, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<CompressedVideo>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `CompressedVideo` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_compressed_video_unchecked`.
+pub fn root_as_compressed_video_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CompressedVideo<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<CompressedVideo<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `CompressedVideo` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_compressed_video_unchecked`.
+pub fn size_prefixed_root_as_compressed_video_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CompressedVideo<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<CompressedVideo<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a CompressedVideo and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `CompressedVideo`.
+pub unsafe fn root_as_compressed_video_unchecked(buf: &[u8]) -> CompressedVideo {
+  flatbuffers::root_unchecked::<CompressedVideo>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed CompressedVideo and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `CompressedVideo`.
+pub unsafe fn size_prefixed_root_as_compressed_video_unchecked(buf: &[u8]) -> CompressedVideo {
+  flatbuffers::size_prefixed_root_unchecked::<CompressedVideo>(buf)
+}
+#[inline]
+pub fn finish_compressed_video_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<CompressedVideo<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_compressed_video_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<CompressedVideo<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
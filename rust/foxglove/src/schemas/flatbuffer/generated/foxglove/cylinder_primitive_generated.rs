@@ -0,0 +1,253 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum CylinderPrimitiveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A primitive representing a cylinder, elliptic cylinder, or truncated cone
+pub struct CylinderPrimitive<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CylinderPrimitive<'a> {
+  type Inner = CylinderPrimitive<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CylinderPrimitive<'a> {
+  pub const VT_POSE: flatbuffers::VOffsetT = 4;
+  pub const VT_SIZE: flatbuffers::VOffsetT = 6;
+  pub const VT_BOTTOM_SCALE: flatbuffers::VOffsetT = 8;
+  pub const VT_TOP_SCALE: flatbuffers::VOffsetT = 10;
+  pub const VT_COLOR: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CylinderPrimitive { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CylinderPrimitiveArgs<'args>
+  ) -> flatbuffers::WIPOffset<CylinderPrimitive<'bldr>> {
+    let mut builder = CylinderPrimitiveBuilder::new(_fbb);
+    builder.add_top_scale(args.top_scale);
+    builder.add_bottom_scale(args.bottom_scale);
+    if let Some(x) = args.color { builder.add_color(x); }
+    if let Some(x) = args.size { builder.add_size(x); }
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    builder.finish()
+  }
+
+
+  /// Position of the center of the cylinder and orientation of the cylinder. The flat face(s) are perpendicular to the z-axis.
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(CylinderPrimitive::VT_POSE, None)}
+  }
+  /// Size of the cylinder's bounding box
+  #[inline]
+  pub fn size(&self) -> Option<Vector3<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Vector3>>(CylinderPrimitive::VT_SIZE, None)}
+  }
+  /// 0-1, ratio of the diameter of the cylinder's bottom face (min z) to the bottom of the bounding box
+  #[inline]
+  pub fn bottom_scale(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(CylinderPrimitive::VT_BOTTOM_SCALE, Some(0.0)).unwrap()}
+  }
+  /// 0-1, ratio of the diameter of the cylinder's top face (max z) to the top of the bounding box
+  #[inline]
+  pub fn top_scale(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(CylinderPrimitive::VT_TOP_SCALE, Some(0.0)).unwrap()}
+  }
+  /// Color of the cylinder
+  #[inline]
+  pub fn color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(CylinderPrimitive::VT_COLOR, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CylinderPrimitive<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Vector3>>("size", Self::VT_SIZE, false)?
+     .visit_field::<f64>("bottom_scale", Self::VT_BOTTOM_SCALE, false)?
+     .visit_field::<f64>("top_scale", Self::VT_TOP_SCALE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("color", Self::VT_COLOR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CylinderPrimitiveArgs<'a> {
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub size: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+    pub bottom_scale: f64,
+    pub top_scale: f64,
+    pub color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+}
+impl<'a> Default for CylinderPrimitiveArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CylinderPrimitiveArgs {
+      pose: None,
+      size: None,
+      bottom_scale: 0.0,
+      top_scale: 0.0,
+      color: None,
+    }
+  }
+}
+
+pub struct CylinderPrimitiveBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CylinderPrimitiveBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(CylinderPrimitive::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_size(&mut self, size: flatbuffers::WIPOffset<Vector3<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Vector3>>(CylinderPrimitive::VT_SIZE, size);
+  }
+  #[inline]
+  pub fn add_bottom_scale(&mut self, bottom_scale: f64) {
+    self.fbb_.push_slot::<f64>(CylinderPrimitive::VT_BOTTOM_SCALE, bottom_scale, 0.0);
+  }
+  #[inline]
+  pub fn add_top_scale(&mut self, top_scale: f64) {
+    self.fbb_.push_slot::<f64>(CylinderPrimitive::VT_TOP_SCALE, top_scale, 0.0);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(CylinderPrimitive::VT_COLOR, color);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CylinderPrimitiveBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CylinderPrimitiveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CylinderPrimitive<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CylinderPrimitive<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CylinderPrimitive");
+      ds.field("pose", &self.pose());
+      ds.field("size", &self.size());
+      ds.field("bottom_scale", &self.bottom_scale());
+      ds.field("top_scale", &self.top_scale());
+      ds.field("color", &self.color());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `CylinderPrimitive`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_cylinder_primitive_unchecked`.
+pub fn root_as_cylinder_primitive(buf: &[u8]) -> Result<CylinderPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<CylinderPrimitive>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `CylinderPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_cylinder_primitive_unchecked`.
+pub fn size_prefixed_root_as_cylinder_primitive(buf: &[u8]) -> Result<CylinderPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<CylinderPrimitive>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `CylinderPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_cylinder_primitive_unchecked`.
+pub fn root_as_cylinder_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CylinderPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<CylinderPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `CylinderPrimitive` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_cylinder_primitive_unchecked`.
+pub fn size_prefixed_root_as_cylinder_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CylinderPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<CylinderPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a CylinderPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `CylinderPrimitive`.
+pub unsafe fn root_as_cylinder_primitive_unchecked(buf: &[u8]) -> CylinderPrimitive {
+  flatbuffers::root_unchecked::<CylinderPrimitive>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed CylinderPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `CylinderPrimitive`.
+pub unsafe fn size_prefixed_root_as_cylinder_primitive_unchecked(buf: &[u8]) -> CylinderPrimitive {
+  flatbuffers::size_prefixed_root_unchecked::<CylinderPrimitive>(buf)
+}
+#[inline]
+pub fn finish_cylinder_primitive_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<CylinderPrimitive<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_cylinder_primitive_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<CylinderPrimitive<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,253 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum FrameTransformOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A transform between two reference frames in 3D space
+pub struct FrameTransform<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FrameTransform<'a> {
+  type Inner = FrameTransform<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FrameTransform<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_PARENT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_CHILD_FRAME_ID: flatbuffers::VOffsetT = 8;
+  pub const VT_TRANSLATION: flatbuffers::VOffsetT = 10;
+  pub const VT_ROTATION: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FrameTransform { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FrameTransformArgs<'args>
+  ) -> flatbuffers::WIPOffset<FrameTransform<'bldr>> {
+    let mut builder = FrameTransformBuilder::new(_fbb);
+    if let Some(x) = args.rotation { builder.add_rotation(x); }
+    if let Some(x) = args.translation { builder.add_translation(x); }
+    if let Some(x) = args.child_frame_id { builder.add_child_frame_id(x); }
+    if let Some(x) = args.parent_frame_id { builder.add_parent_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of transform
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(FrameTransform::VT_TIMESTAMP, None)}
+  }
+  /// Name of the parent frame
+  #[inline]
+  pub fn parent_frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FrameTransform::VT_PARENT_FRAME_ID, None)}
+  }
+  /// Name of the child frame
+  #[inline]
+  pub fn child_frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FrameTransform::VT_CHILD_FRAME_ID, None)}
+  }
+  /// Translation component of the transform
+  #[inline]
+  pub fn translation(&self) -> Option<Vector3<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Vector3>>(FrameTransform::VT_TRANSLATION, None)}
+  }
+  /// Rotation component of the transform
+  #[inline]
+  pub fn rotation(&self) -> Option<Quaternion<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Quaternion>>(FrameTransform::VT_ROTATION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for FrameTransform<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("parent_frame_id", Self::VT_PARENT_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("child_frame_id", Self::VT_CHILD_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Vector3>>("translation", Self::VT_TRANSLATION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Quaternion>>("rotation", Self::VT_ROTATION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FrameTransformArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub parent_frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub child_frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub translation: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+    pub rotation: Option<flatbuffers::WIPOffset<Quaternion<'a>>>,
+}
+impl<'a> Default for FrameTransformArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FrameTransformArgs {
+      timestamp: None,
+      parent_frame_id: None,
+      child_frame_id: None,
+      translation: None,
+      rotation: None,
+    }
+  }
+}
+
+pub struct FrameTransformBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FrameTransformBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(FrameTransform::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_parent_frame_id(&mut self, parent_frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FrameTransform::VT_PARENT_FRAME_ID, parent_frame_id);
+  }
+  #[inline]
+  pub fn add_child_frame_id(&mut self, child_frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FrameTransform::VT_CHILD_FRAME_ID, child_frame_id);
+  }
+  #[inline]
+  pub fn add_translation(&mut self, translation: flatbuffers::WIPOffset<Vector3<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Vector3>>(FrameTransform::VT_TRANSLATION, translation);
+  }
+  #[inline]
+  pub fn add_rotation(&mut self, rotation: flatbuffers::WIPOffset<Quaternion<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Quaternion>>(FrameTransform::VT_ROTATION, rotation);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FrameTransformBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FrameTransformBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FrameTransform<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FrameTransform<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FrameTransform");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("parent_frame_id", &self.parent_frame_id());
+      ds.field("child_frame_id", &self.child_frame_id());
+      ds.field("translation", &self.translation());
+      ds.field("rotation", &self.rotation());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `FrameTransform`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_frame_transform_unchecked`.
+pub fn root_as_frame_transform(buf: &[u8]) -> Result<FrameTransform, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<FrameTransform>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `FrameTransform` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_frame_transform_unchecked`.
+pub fn size_prefixed_root_as_frame_transform(buf: &[u8]) -> Result<FrameTransform, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<FrameTransform>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `FrameTransform` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_frame_transform_unchecked`.
+pub fn root_as_frame_transform_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<FrameTransform<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<FrameTransform<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `FrameTransform` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_frame_transform_unchecked`.
+pub fn size_prefixed_root_as_frame_transform_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<FrameTransform<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<FrameTransform<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a FrameTransform and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `FrameTransform`.
+pub unsafe fn root_as_frame_transform_unchecked(buf: &[u8]) -> FrameTransform {
+  flatbuffers::root_unchecked::<FrameTransform>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed FrameTransform and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `FrameTransform`.
+pub unsafe fn size_prefixed_root_as_frame_transform_unchecked(buf: &[u8]) -> FrameTransform {
+  flatbuffers::size_prefixed_root_unchecked::<FrameTransform>(buf)
+}
+#[inline]
+pub fn finish_frame_transform_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<FrameTransform<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_frame_transform_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<FrameTransform<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
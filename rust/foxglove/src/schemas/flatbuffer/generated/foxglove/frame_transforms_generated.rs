@@ -0,0 +1,181 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum FrameTransformsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// An array of FrameTransform messages
+pub struct FrameTransforms<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FrameTransforms<'a> {
+  type Inner = FrameTransforms<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FrameTransforms<'a> {
+  pub const VT_TRANSFORMS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FrameTransforms { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args FrameTransformsArgs<'args>
+  ) -> flatbuffers::WIPOffset<FrameTransforms<'bldr>> {
+    let mut builder = FrameTransformsBuilder::new(_fbb);
+    if let Some(x) = args.transforms { builder.add_transforms(x); }
+    builder.finish()
+  }
+
+
+  /// Array of transforms
+  #[inline]
+  pub fn transforms(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FrameTransform<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FrameTransform>>>>(FrameTransforms::VT_TRANSFORMS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for FrameTransforms<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<FrameTransform>>>>("transforms", Self::VT_TRANSFORMS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FrameTransformsArgs<'a> {
+    pub transforms: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FrameTransform<'a>>>>>,
+}
+impl<'a> Default for FrameTransformsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FrameTransformsArgs {
+      transforms: None,
+    }
+  }
+}
+
+pub struct FrameTransformsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FrameTransformsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_transforms(&mut self, transforms: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<FrameTransform<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FrameTransforms::VT_TRANSFORMS, transforms);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FrameTransformsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FrameTransformsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FrameTransforms<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FrameTransforms<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FrameTransforms");
+      ds.field("transforms", &self.transforms());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `FrameTransforms`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_frame_transforms_unchecked`.
+pub fn root_as_frame_transforms(buf: &[u8]) -> Result<FrameTransforms, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<FrameTransforms>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `FrameTransforms` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_frame_transforms_unchecked`.
+pub fn size_prefixed_root_as_frame_transforms(buf: &[u8]) -> Result<FrameTransforms, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<FrameTransforms>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `FrameTransforms` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_frame_transforms_unchecked`.
+pub fn root_as_frame_transforms_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<FrameTransforms<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<FrameTransforms<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `FrameTransforms` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_frame_transforms_unchecked`.
+pub fn size_prefixed_root_as_frame_transforms_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<FrameTransforms<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<FrameTransforms<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a FrameTransforms and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `FrameTransforms`.
+pub unsafe fn root_as_frame_transforms_unchecked(buf: &[u8]) -> FrameTransforms {
+  flatbuffers::root_unchecked::<FrameTransforms>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed FrameTransforms and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `FrameTransforms`.
+pub unsafe fn size_prefixed_root_as_frame_transforms_unchecked(buf: &[u8]) -> FrameTransforms {
+  flatbuffers::size_prefixed_root_unchecked::<FrameTransforms>(buf)
+}
+#[inline]
+pub fn finish_frame_transforms_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<FrameTransforms<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_frame_transforms_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<FrameTransforms<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
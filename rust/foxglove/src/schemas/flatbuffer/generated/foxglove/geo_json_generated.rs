@@ -0,0 +1,181 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum GeoJSONOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// GeoJSON data for annotating maps
+pub struct GeoJSON<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GeoJSON<'a> {
+  type Inner = GeoJSON<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GeoJSON<'a> {
+  pub const VT_GEOJSON: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GeoJSON { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args GeoJSONArgs<'args>
+  ) -> flatbuffers::WIPOffset<GeoJSON<'bldr>> {
+    let mut builder = GeoJSONBuilder::new(_fbb);
+    if let Some(x) = args.geojson { builder.add_geojson(x); }
+    builder.finish()
+  }
+
+
+  /// GeoJSON data encoded as a UTF-8 string
+  #[inline]
+  pub fn geojson(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(GeoJSON::VT_GEOJSON, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for GeoJSON<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("geojson", Self::VT_GEOJSON, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GeoJSONArgs<'a> {
+    pub geojson: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for GeoJSONArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GeoJSONArgs {
+      geojson: None,
+    }
+  }
+}
+
+pub struct GeoJSONBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GeoJSONBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_geojson(&mut self, geojson: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GeoJSON::VT_GEOJSON, geojson);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GeoJSONBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GeoJSONBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GeoJSON<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GeoJSON<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GeoJSON");
+      ds.field("geojson", &self.geojson());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `GeoJSON`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_geo_json_unchecked`.
+pub fn root_as_geo_json(buf: &[u8]) -> Result<GeoJSON, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<GeoJSON>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `GeoJSON` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_geo_json_unchecked`.
+pub fn size_prefixed_root_as_geo_json(buf: &[u8]) -> Result<GeoJSON, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<GeoJSON>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `GeoJSON` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_geo_json_unchecked`.
+pub fn root_as_geo_json_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<GeoJSON<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<GeoJSON<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `GeoJSON` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_geo_json_unchecked`.
+pub fn size_prefixed_root_as_geo_json_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<GeoJSON<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<GeoJSON<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a GeoJSON and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `GeoJSON`.
+pub unsafe fn root_as_geo_json_unchecked(buf: &[u8]) -> GeoJSON {
+  flatbuffers::root_unchecked::<GeoJSON>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed GeoJSON and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `GeoJSON`.
+pub unsafe fn size_prefixed_root_as_geo_json_unchecked(buf: &[u8]) -> GeoJSON {
+  flatbuffers::size_prefixed_root_unchecked::<GeoJSON>(buf)
+}
+#[inline]
+pub fn finish_geo_json_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<GeoJSON<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_geo_json_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<GeoJSON<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
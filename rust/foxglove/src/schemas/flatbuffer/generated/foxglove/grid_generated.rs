@@ -0,0 +1,325 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum GridOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A 2D grid of data
+pub struct Grid<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Grid<'a> {
+  type Inner = Grid<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Grid<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_POSE: flatbuffers::VOffsetT = 8;
+  pub const VT_COLUMN_COUNT: flatbuffers::VOffsetT = 10;
+  pub const VT_CELL_SIZE: flatbuffers::VOffsetT = 12;
+  pub const VT_ROW_STRIDE: flatbuffers::VOffsetT = 14;
+  pub const VT_CELL_STRIDE: flatbuffers::VOffsetT = 16;
+  pub const VT_FIELDS: flatbuffers::VOffsetT = 18;
+  pub const VT_DATA: flatbuffers::VOffsetT = 20;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Grid { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args GridArgs<'args>
+  ) -> flatbuffers::WIPOffset<Grid<'bldr>> {
+    let mut builder = GridBuilder::new(_fbb);
+    if let Some(x) = args.data { builder.add_data(x); }
+    if let Some(x) = args.fields { builder.add_fields(x); }
+    builder.add_cell_stride(args.cell_stride);
+    builder.add_row_stride(args.row_stride);
+    if let Some(x) = args.cell_size { builder.add_cell_size(x); }
+    builder.add_column_count(args.column_count);
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of grid
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(Grid::VT_TIMESTAMP, None)}
+  }
+  /// Frame of reference
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Grid::VT_FRAME_ID, None)}
+  }
+  /// Origin of grid's corner relative to frame of reference; grid is positioned in the x-y plane relative to this origin
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(Grid::VT_POSE, None)}
+  }
+  /// Number of grid columns
+  #[inline]
+  pub fn column_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Grid::VT_COLUMN_COUNT, Some(0)).unwrap()}
+  }
+  /// Size of single grid cell along x and y axes, relative to `pose`
+  #[inline]
+  pub fn cell_size(&self) -> Option<Vector2<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Vector2>>(Grid::VT_CELL_SIZE, None)}
+  }
+  /// Number of bytes between rows in `data`
+  #[inline]
+  pub fn row_stride(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Grid::VT_ROW_STRIDE, Some(0)).unwrap()}
+  }
+  /// Number of bytes between cells within a row in `data`
+  #[inline]
+  pub fn cell_stride(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Grid::VT_CELL_STRIDE, Some(0)).unwrap()}
+  }
+  /// Fields in `data`. `red`, `green`, `blue`, and `alpha` are optional for customizing the grid's color.
+  #[inline]
+  pub fn fields(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PackedElementField<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PackedElementField>>>>(Grid::VT_FIELDS, None)}
+  }
+  /// Grid cell data, interpreted using `fields`, in row-major (y-major) order
+  #[inline]
+  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Grid::VT_DATA, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Grid<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<u32>("column_count", Self::VT_COLUMN_COUNT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Vector2>>("cell_size", Self::VT_CELL_SIZE, false)?
+     .visit_field::<u32>("row_stride", Self::VT_ROW_STRIDE, false)?
+     .visit_field::<u32>("cell_stride", Self::VT_CELL_STRIDE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PackedElementField>>>>("fields", Self::VT_FIELDS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("data", Self::VT_DATA, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GridArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub column_count: u32,
+    pub cell_size: Option<flatbuffers::WIPOffset<Vector2<'a>>>,
+    pub row_stride: u32,
+    pub cell_stride: u32,
+    pub fields: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PackedElementField<'a>>>>>,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for GridArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GridArgs {
+      timestamp: None,
+      frame_id: None,
+      pose: None,
+      column_count: 0,
+      cell_size: None,
+      row_stride: 0,
+      cell_stride: 0,
+      fields: None,
+      data: None,
+    }
+  }
+}
+
+pub struct GridBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GridBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(Grid::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Grid::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(Grid::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_column_count(&mut self, column_count: u32) {
+    self.fbb_.push_slot::<u32>(Grid::VT_COLUMN_COUNT, column_count, 0);
+  }
+  #[inline]
+  pub fn add_cell_size(&mut self, cell_size: flatbuffers::WIPOffset<Vector2<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Vector2>>(Grid::VT_CELL_SIZE, cell_size);
+  }
+  #[inline]
+  pub fn add_row_stride(&mut self, row_stride: u32) {
+    self.fbb_.push_slot::<u32>(Grid::VT_ROW_STRIDE, row_stride, 0);
+  }
+  #[inline]
+  pub fn add_cell_stride(&mut self, cell_stride: u32) {
+    self.fbb_.push_slot::<u32>(Grid::VT_CELL_STRIDE, cell_stride, 0);
+  }
+  #[inline]
+  pub fn add_fields(&mut self, fields: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PackedElementField<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Grid::VT_FIELDS, fields);
+  }
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Grid::VT_DATA, data);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GridBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GridBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Grid<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Grid<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Grid");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("pose", &self.pose());
+      ds.field("column_count", &self.column_count());
+      ds.field("cell_size", &self.cell_size());
+      ds.field("row_stride", &self.row_stride());
+      ds.field("cell_stride", &self.cell_stride());
+      ds.field("fields", &self.fields());
+      ds.field("data", &self.data());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `Grid`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_grid_unchecked`.
+pub fn root_as_grid(buf: &[u8]) -> Result<Grid, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<Grid>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `Grid` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_grid_unchecked`.
+pub fn size_prefixed_root_as_grid(buf: &[u8]) -> Result<Grid, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<Grid>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `Grid` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_grid_unchecked`.
+pub fn root_as_grid_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Grid<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<Grid<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `Grid` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_grid_unchecked`.
+pub fn size_prefixed_root_as_grid_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Grid<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<Grid<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a Grid and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `Grid`.
+pub unsafe fn root_as_grid_unchecked(buf: &[u8]) -> Grid {
+  flatbuffers::root_unchecked::<Grid>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed Grid and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `Grid`.
+pub unsafe fn size_prefixed_root_as_grid_unchecked(buf: &[u8]) -> Grid {
+  flatbuffers::size_prefixed_root_unchecked::<Grid>(buf)
+}
+#[inline]
+pub fn finish_grid_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Grid<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_grid_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Grid<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,217 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum ImageAnnotationsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Array of annotations for a 2D image
+pub struct ImageAnnotations<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ImageAnnotations<'a> {
+  type Inner = ImageAnnotations<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ImageAnnotations<'a> {
+  pub const VT_CIRCLES: flatbuffers::VOffsetT = 4;
+  pub const VT_POINTS: flatbuffers::VOffsetT = 6;
+  pub const VT_TEXTS: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ImageAnnotations { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ImageAnnotationsArgs<'args>
+  ) -> flatbuffers::WIPOffset<ImageAnnotations<'bldr>> {
+    let mut builder = ImageAnnotationsBuilder::new(_fbb);
+    if let Some(x) = args.texts { builder.add_texts(x); }
+    if let Some(x) = args.points { builder.add_points(x); }
+    if let Some(x) = args.circles { builder.add_circles(x); }
+    builder.finish()
+  }
+
+
+  /// Circle annotations
+  #[inline]
+  pub fn circles(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CircleAnnotation<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CircleAnnotation>>>>(ImageAnnotations::VT_CIRCLES, None)}
+  }
+  /// Points annotations
+  #[inline]
+  pub fn points(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PointsAnnotation<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PointsAnnotation>>>>(ImageAnnotations::VT_POINTS, None)}
+  }
+  /// Text annotations
+  #[inline]
+  pub fn texts(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextAnnotation<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextAnnotation>>>>(ImageAnnotations::VT_TEXTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ImageAnnotations<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CircleAnnotation>>>>("circles", Self::VT_CIRCLES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PointsAnnotation>>>>("points", Self::VT_POINTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TextAnnotation>>>>("texts", Self::VT_TEXTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ImageAnnotationsArgs<'a> {
+    pub circles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CircleAnnotation<'a>>>>>,
+    pub points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PointsAnnotation<'a>>>>>,
+    pub texts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextAnnotation<'a>>>>>,
+}
+impl<'a> Default for ImageAnnotationsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ImageAnnotationsArgs {
+      circles: None,
+      points: None,
+      texts: None,
+    }
+  }
+}
+
+pub struct ImageAnnotationsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ImageAnnotationsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_circles(&mut self, circles: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CircleAnnotation<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ImageAnnotations::VT_CIRCLES, circles);
+  }
+  #[inline]
+  pub fn add_points(&mut self, points: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PointsAnnotation<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ImageAnnotations::VT_POINTS, points);
+  }
+  #[inline]
+  pub fn add_texts(&mut self, texts: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TextAnnotation<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ImageAnnotations::VT_TEXTS, texts);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ImageAnnotationsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ImageAnnotationsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ImageAnnotations<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ImageAnnotations<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ImageAnnotations");
+      ds.field("circles", &self.circles());
+      ds.field("points", &self.points());
+      ds.field("texts", &self.texts());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ImageAnnotations`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_image_annotations_unchecked`.
+pub fn root_as_image_annotations(buf: &[u8]) -> Result<ImageAnnotations, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<ImageAnnotations>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ImageAnnotations` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_image_annotations_unchecked`.
+pub fn size_prefixed_root_as_image_annotations(buf: &[u8]) -> Result<ImageAnnotations, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<ImageAnnotations>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ImageAnnotations` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_image_annotations_unchecked`.
+pub fn root_as_image_annotations_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ImageAnnotations<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<ImageAnnotations<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ImageAnnotations` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_image_annotations_unchecked`.
+pub fn size_prefixed_root_as_image_annotations_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ImageAnnotations<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<ImageAnnotations<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ImageAnnotations and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ImageAnnotations`.
+pub unsafe fn root_as_image_annotations_unchecked(buf: &[u8]) -> ImageAnnotations {
+  flatbuffers::root_unchecked::<ImageAnnotations>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ImageAnnotations and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ImageAnnotations`.
+pub unsafe fn size_prefixed_root_as_image_annotations_unchecked(buf: &[u8]) -> ImageAnnotations {
+  flatbuffers::size_prefixed_root_unchecked::<ImageAnnotations>(buf)
+}
+#[inline]
+pub fn finish_image_annotations_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<ImageAnnotations<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_image_annotations_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<ImageAnnotations<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
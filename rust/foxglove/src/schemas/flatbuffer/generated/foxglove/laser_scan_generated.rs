@@ -0,0 +1,289 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum LaserScanOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A single scan from a planar laser range-finder
+pub struct LaserScan<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LaserScan<'a> {
+  type Inner = LaserScan<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> LaserScan<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_POSE: flatbuffers::VOffsetT = 8;
+  pub const VT_START_ANGLE: flatbuffers::VOffsetT = 10;
+  pub const VT_END_ANGLE: flatbuffers::VOffsetT = 12;
+  pub const VT_RANGES: flatbuffers::VOffsetT = 14;
+  pub const VT_INTENSITIES: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LaserScan { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args LaserScanArgs<'args>
+  ) -> flatbuffers::WIPOffset<LaserScan<'bldr>> {
+    let mut builder = LaserScanBuilder::new(_fbb);
+    builder.add_end_angle(args.end_angle);
+    builder.add_start_angle(args.start_angle);
+    if let Some(x) = args.intensities { builder.add_intensities(x); }
+    if let Some(x) = args.ranges { builder.add_ranges(x); }
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of scan
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(LaserScan::VT_TIMESTAMP, None)}
+  }
+  /// Frame of reference
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LaserScan::VT_FRAME_ID, None)}
+  }
+  /// Origin of scan relative to frame of reference; points are positioned in the x-y plane relative to this origin; angles are interpreted as counterclockwise rotations around the z axis with 0 rad being in the +x direction
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(LaserScan::VT_POSE, None)}
+  }
+  /// Bearing of first point, in radians
+  #[inline]
+  pub fn start_angle(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(LaserScan::VT_START_ANGLE, Some(0.0)).unwrap()}
+  }
+  /// Bearing of last point, in radians
+  #[inline]
+  pub fn end_angle(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(LaserScan::VT_END_ANGLE, Some(0.0)).unwrap()}
+  }
+  /// Distance of detections from origin; assumed to be at equally-spaced angles between `start_angle` and `end_angle`
+  #[inline]
+  pub fn ranges(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(LaserScan::VT_RANGES, None)}
+  }
+  /// Intensity of detections
+  #[inline]
+  pub fn intensities(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(LaserScan::VT_INTENSITIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for LaserScan<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<f64>("start_angle", Self::VT_START_ANGLE, false)?
+     .visit_field::<f64>("end_angle", Self::VT_END_ANGLE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("ranges", Self::VT_RANGES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("intensities", Self::VT_INTENSITIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LaserScanArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub start_angle: f64,
+    pub end_angle: f64,
+    pub ranges: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub intensities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+}
+impl<'a> Default for LaserScanArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LaserScanArgs {
+      timestamp: None,
+      frame_id: None,
+      pose: None,
+      start_angle: 0.0,
+      end_angle: 0.0,
+      ranges: None,
+      intensities: None,
+    }
+  }
+}
+
+pub struct LaserScanBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> LaserScanBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(LaserScan::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LaserScan::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(LaserScan::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_start_angle(&mut self, start_angle: f64) {
+    self.fbb_.push_slot::<f64>(LaserScan::VT_START_ANGLE, start_angle, 0.0);
+  }
+  #[inline]
+  pub fn add_end_angle(&mut self, end_angle: f64) {
+    self.fbb_.push_slot::<f64>(LaserScan::VT_END_ANGLE, end_angle, 0.0);
+  }
+  #[inline]
+  pub fn add_ranges(&mut self, ranges: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LaserScan::VT_RANGES, ranges);
+  }
+  #[inline]
+  pub fn add_intensities(&mut self, intensities: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LaserScan::VT_INTENSITIES, intensities);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LaserScanBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    LaserScanBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LaserScan<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LaserScan<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LaserScan");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("pose", &self.pose());
+      ds.field("start_angle", &self.start_angle());
+      ds.field("end_angle", &self.end_angle());
+      ds.field("ranges", &self.ranges());
+      ds.field("intensities", &self.intensities());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `LaserScan`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_laser_scan_unchecked`.
+pub fn root_as_laser_scan(buf: &[u8]) -> Result<LaserScan, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<LaserScan>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `LaserScan` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_laser_scan_unchecked`.
+pub fn size_prefixed_root_as_laser_scan(buf: &[u8]) -> Result<LaserScan, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<LaserScan>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `LaserScan` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_laser_scan_unchecked`.
+pub fn root_as_laser_scan_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<LaserScan<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<LaserScan<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `LaserScan` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_laser_scan_unchecked`.
+pub fn size_prefixed_root_as_laser_scan_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<LaserScan<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<LaserScan<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a LaserScan and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `LaserScan`.
+pub unsafe fn root_as_laser_scan_unchecked(buf: &[u8]) -> LaserScan {
+  flatbuffers::root_unchecked::<LaserScan>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed LaserScan and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `LaserScan`.
+pub unsafe fn size_prefixed_root_as_laser_scan_unchecked(buf: &[u8]) -> LaserScan {
+  flatbuffers::size_prefixed_root_unchecked::<LaserScan>(buf)
+}
+#[inline]
+pub fn finish_laser_scan_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<LaserScan<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_laser_scan_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<LaserScan<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,309 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum LinePrimitiveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A primitive representing a series of points connected by lines
+pub struct LinePrimitive<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LinePrimitive<'a> {
+  type Inner = LinePrimitive<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> LinePrimitive<'a> {
+  pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
+  pub const VT_POSE: flatbuffers::VOffsetT = 6;
+  pub const VT_THICKNESS: flatbuffers::VOffsetT = 8;
+  pub const VT_SCALE_INVARIANT: flatbuffers::VOffsetT = 10;
+  pub const VT_POINTS: flatbuffers::VOffsetT = 12;
+  pub const VT_COLOR: flatbuffers::VOffsetT = 14;
+  pub const VT_COLORS: flatbuffers::VOffsetT = 16;
+  pub const VT_INDICES: flatbuffers::VOffsetT = 18;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LinePrimitive { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args LinePrimitiveArgs<'args>
+  ) -> flatbuffers::WIPOffset<LinePrimitive<'bldr>> {
+    let mut builder = LinePrimitiveBuilder::new(_fbb);
+    builder.add_thickness(args.thickness);
+    if let Some(x) = args.indices { builder.add_indices(x); }
+    if let Some(x) = args.colors { builder.add_colors(x); }
+    if let Some(x) = args.color { builder.add_color(x); }
+    if let Some(x) = args.points { builder.add_points(x); }
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    builder.add_scale_invariant(args.scale_invariant);
+    builder.add_type_(args.type_);
+    builder.finish()
+  }
+
+
+  /// Drawing primitive to use for lines
+  #[inline]
+  pub fn type_(&self) -> LineType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<LineType>(LinePrimitive::VT_TYPE_, Some(LineType::LINE_STRIP)).unwrap()}
+  }
+  /// Origin of lines relative to reference frame
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(LinePrimitive::VT_POSE, None)}
+  }
+  /// Line thickness
+  #[inline]
+  pub fn thickness(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(LinePrimitive::VT_THICKNESS, Some(0.0)).unwrap()}
+  }
+  /// Indicates whether `thickness` is a fixed size in screen pixels (true), or specified in world coordinates and scales with distance from the camera (false)
+  #[inline]
+  pub fn scale_invariant(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(LinePrimitive::VT_SCALE_INVARIANT, Some(false)).unwrap()}
+  }
+  /// Points along the line
+  #[inline]
+  pub fn points(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Point3<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Point3>>>>(LinePrimitive::VT_POINTS, None)}
+  }
+  /// Solid color to use for the whole line. One of `color` or `colors` must be provided.
+  #[inline]
+  pub fn color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(LinePrimitive::VT_COLOR, None)}
+  }
+  /// Per-point colors (if specified, must have the same length as `points`). One of `color` or `colors` must be provided.
+  #[inline]
+  pub fn colors(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Color<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Color>>>>(LinePrimitive::VT_COLORS, None)}
+  }
+  /// Indices into the `points` and `colors` attribute arrays, which can be used to avoid duplicating attribute data.
+  ///
+  /// If omitted or empty, indexing will not be used. This default behavior is equivalent to specifying [0, 1, ..., N-1] for the indices (where N is the number of `points` provided).
+  #[inline]
+  pub fn indices(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(LinePrimitive::VT_INDICES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for LinePrimitive<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<LineType>("type_", Self::VT_TYPE_, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<f64>("thickness", Self::VT_THICKNESS, false)?
+     .visit_field::<bool>("scale_invariant", Self::VT_SCALE_INVARIANT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Point3>>>>("points", Self::VT_POINTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("color", Self::VT_COLOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Color>>>>("colors", Self::VT_COLORS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("indices", Self::VT_INDICES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LinePrimitiveArgs<'a> {
+    pub type_: LineType,
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub thickness: f64,
+    pub scale_invariant: bool,
+    pub points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Point3<'a>>>>>,
+    pub color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+    pub colors: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Color<'a>>>>>,
+    pub indices: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for LinePrimitiveArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LinePrimitiveArgs {
+      type_: LineType::LINE_STRIP,
+      pose: None,
+      thickness: 0.0,
+      scale_invariant: false,
+      points: None,
+      color: None,
+      colors: None,
+      indices: None,
+    }
+  }
+}
+
+pub struct LinePrimitiveBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> LinePrimitiveBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_type_(&mut self, type_: LineType) {
+    self.fbb_.push_slot::<LineType>(LinePrimitive::VT_TYPE_, type_, LineType::LINE_STRIP);
+  }
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(LinePrimitive::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_thickness(&mut self, thickness: f64) {
+    self.fbb_.push_slot::<f64>(LinePrimitive::VT_THICKNESS, thickness, 0.0);
+  }
+  #[inline]
+  pub fn add_scale_invariant(&mut self, scale_invariant: bool) {
+    self.fbb_.push_slot::<bool>(LinePrimitive::VT_SCALE_INVARIANT, scale_invariant, false);
+  }
+  #[inline]
+  pub fn add_points(&mut self, points: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Point3<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LinePrimitive::VT_POINTS, points);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(LinePrimitive::VT_COLOR, color);
+  }
+  #[inline]
+  pub fn add_colors(&mut self, colors: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Color<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LinePrimitive::VT_COLORS, colors);
+  }
+  #[inline]
+  pub fn add_indices(&mut self, indices: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LinePrimitive::VT_INDICES, indices);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LinePrimitiveBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    LinePrimitiveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LinePrimitive<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LinePrimitive<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LinePrimitive");
+      ds.field("type_", &self.type_());
+      ds.field("pose", &self.pose());
+      ds.field("thickness", &self.thickness());
+      ds.field("scale_invariant", &self.scale_invariant());
+      ds.field("points", &self.points());
+      ds.field("color", &self.color());
+      ds.field("colors", &self.colors());
+      ds.field("indices", &self.indices());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `LinePrimitive`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_line_primitive_unchecked`.
+pub fn root_as_line_primitive(buf: &[u8]) -> Result<LinePrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<LinePrimitive>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `LinePrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_line_primitive_unchecked`.
+pub fn size_prefixed_root_as_line_primitive(buf: &[u8]) -> Result<LinePrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<LinePrimitive>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `LinePrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_line_primitive_unchecked`.
+pub fn root_as_line_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<LinePrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<LinePrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `LinePrimitive` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_line_primitive_unchecked`.
+pub fn size_prefixed_root_as_line_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<LinePrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<LinePrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a LinePrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `LinePrimitive`.
+pub unsafe fn root_as_line_primitive_unchecked(buf: &[u8]) -> LinePrimitive {
+  flatbuffers::root_unchecked::<LinePrimitive>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed LinePrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `LinePrimitive`.
+pub unsafe fn size_prefixed_root_as_line_primitive_unchecked(buf: &[u8]) -> LinePrimitive {
+  flatbuffers::size_prefixed_root_unchecked::<LinePrimitive>(buf)
+}
+#[inline]
+pub fn finish_line_primitive_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<LinePrimitive<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_line_primitive_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<LinePrimitive<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
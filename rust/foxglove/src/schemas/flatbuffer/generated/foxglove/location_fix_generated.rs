@@ -0,0 +1,290 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum LocationFixOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A navigation satellite fix for any Global Navigation Satellite System
+pub struct LocationFix<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LocationFix<'a> {
+  type Inner = LocationFix<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> LocationFix<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_LATITUDE: flatbuffers::VOffsetT = 8;
+  pub const VT_LONGITUDE: flatbuffers::VOffsetT = 10;
+  pub const VT_ALTITUDE: flatbuffers::VOffsetT = 12;
+  pub const VT_POSITION_COVARIANCE: flatbuffers::VOffsetT = 14;
+  pub const VT_POSITION_COVARIANCE_TYPE: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LocationFix { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args LocationFixArgs<'args>
+  ) -> flatbuffers::WIPOffset<LocationFix<'bldr>> {
+    let mut builder = LocationFixBuilder::new(_fbb);
+    builder.add_altitude(args.altitude);
+    builder.add_longitude(args.longitude);
+    builder.add_latitude(args.latitude);
+    if let Some(x) = args.position_covariance { builder.add_position_covariance(x); }
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.add_position_covariance_type(args.position_covariance_type);
+    builder.finish()
+  }
+
+
+  /// Timestamp of the message
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(LocationFix::VT_TIMESTAMP, None)}
+  }
+  /// Frame for the sensor. Latitude and longitude readings are at the origin of the frame.
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LocationFix::VT_FRAME_ID, None)}
+  }
+  /// Latitude in degrees
+  #[inline]
+  pub fn latitude(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(LocationFix::VT_LATITUDE, Some(0.0)).unwrap()}
+  }
+  /// Longitude in degrees
+  #[inline]
+  pub fn longitude(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(LocationFix::VT_LONGITUDE, Some(0.0)).unwrap()}
+  }
+  /// Altitude in meters
+  #[inline]
+  pub fn altitude(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(LocationFix::VT_ALTITUDE, Some(0.0)).unwrap()}
+  }
+  /// Position covariance (m^2) defined relative to a tangential plane through the reported position. The components are East, North, and Up (ENU), in row-major order.
+  /// length 9
+  #[inline]
+  pub fn position_covariance(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(LocationFix::VT_POSITION_COVARIANCE, None)}
+  }
+  /// If `position_covariance` is available, `position_covariance_type` must be set to indicate the type of covariance.
+  #[inline]
+  pub fn position_covariance_type(&self) -> PositionCovarianceType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PositionCovarianceType>(LocationFix::VT_POSITION_COVARIANCE_TYPE, Some(PositionCovarianceType::UNKNOWN)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for LocationFix<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<f64>("latitude", Self::VT_LATITUDE, false)?
+     .visit_field::<f64>("longitude", Self::VT_LONGITUDE, false)?
+     .visit_field::<f64>("altitude", Self::VT_ALTITUDE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("position_covariance", Self::VT_POSITION_COVARIANCE, false)?
+     .visit_field::<PositionCovarianceType>("position_covariance_type", Self::VT_POSITION_COVARIANCE_TYPE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LocationFixArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: f64,
+    pub position_covariance: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub position_covariance_type: PositionCovarianceType,
+}
+impl<'a> Default for LocationFixArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LocationFixArgs {
+      timestamp: None,
+      frame_id: None,
+      latitude: 0.0,
+      longitude: 0.0,
+      altitude: 0.0,
+      position_covariance: None,
+      position_covariance_type: PositionCovarianceType::UNKNOWN,
+    }
+  }
+}
+
+pub struct LocationFixBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> LocationFixBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(LocationFix::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LocationFix::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_latitude(&mut self, latitude: f64) {
+    self.fbb_.push_slot::<f64>(LocationFix::VT_LATITUDE, latitude, 0.0);
+  }
+  #[inline]
+  pub fn add_longitude(&mut self, longitude: f64) {
+    self.fbb_.push_slot::<f64>(LocationFix::VT_LONGITUDE, longitude, 0.0);
+  }
+  #[inline]
+  pub fn add_altitude(&mut self, altitude: f64) {
+    self.fbb_.push_slot::<f64>(LocationFix::VT_ALTITUDE, altitude, 0.0);
+  }
+  #[inline]
+  pub fn add_position_covariance(&mut self, position_covariance: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LocationFix::VT_POSITION_COVARIANCE, position_covariance);
+  }
+  #[inline]
+  pub fn add_position_covariance_type(&mut self, position_covariance_type: PositionCovarianceType) {
+    self.fbb_.push_slot::<PositionCovarianceType>(LocationFix::VT_POSITION_COVARIANCE_TYPE, position_covariance_type, PositionCovarianceType::UNKNOWN);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LocationFixBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    LocationFixBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LocationFix<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LocationFix<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LocationFix");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("latitude", &self.latitude());
+      ds.field("longitude", &self.longitude());
+      ds.field("altitude", &self.altitude());
+      ds.field("position_covariance", &self.position_covariance());
+      ds.field("position_covariance_type", &self.position_covariance_type());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `LocationFix`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_location_fix_unchecked`.
+pub fn root_as_location_fix(buf: &[u8]) -> Result<LocationFix, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<LocationFix>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `LocationFix` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_location_fix_unchecked`.
+pub fn size_prefixed_root_as_location_fix(buf: &[u8]) -> Result<LocationFix, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<LocationFix>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `LocationFix` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_location_fix_unchecked`.
+pub fn root_as_location_fix_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<LocationFix<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<LocationFix<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `LocationFix` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_location_fix_unchecked`.
+pub fn size_prefixed_root_as_location_fix_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<LocationFix<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<LocationFix<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a LocationFix and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `LocationFix`.
+pub unsafe fn root_as_location_fix_unchecked(buf: &[u8]) -> LocationFix {
+  flatbuffers::root_unchecked::<LocationFix>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed LocationFix and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `LocationFix`.
+pub unsafe fn size_prefixed_root_as_location_fix_unchecked(buf: &[u8]) -> LocationFix {
+  flatbuffers::size_prefixed_root_unchecked::<LocationFix>(buf)
+}
+#[inline]
+pub fn finish_location_fix_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<LocationFix<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_location_fix_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<LocationFix<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,271 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum LogOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A log message
+pub struct Log<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Log<'a> {
+  type Inner = Log<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Log<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_LEVEL: flatbuffers::VOffsetT = 6;
+  pub const VT_MESSAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_NAME: flatbuffers::VOffsetT = 10;
+  pub const VT_FILE: flatbuffers::VOffsetT = 12;
+  pub const VT_LINE: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Log { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args LogArgs<'args>
+  ) -> flatbuffers::WIPOffset<Log<'bldr>> {
+    let mut builder = LogBuilder::new(_fbb);
+    builder.add_line(args.line);
+    if let Some(x) = args.file { builder.add_file(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    if let Some(x) = args.message { builder.add_message(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.add_level(args.level);
+    builder.finish()
+  }
+
+
+  /// Timestamp of log message
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(Log::VT_TIMESTAMP, None)}
+  }
+  /// Log level
+  #[inline]
+  pub fn level(&self) -> LogLevel {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<LogLevel>(Log::VT_LEVEL, Some(LogLevel::UNKNOWN)).unwrap()}
+  }
+  /// Log message
+  #[inline]
+  pub fn message(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Log::VT_MESSAGE, None)}
+  }
+  /// Process or node name
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Log::VT_NAME, None)}
+  }
+  /// Filename
+  #[inline]
+  pub fn file(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Log::VT_FILE, None)}
+  }
+  /// Line number in the file
+  #[inline]
+  pub fn line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Log::VT_LINE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Log<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<LogLevel>("level", Self::VT_LEVEL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("file", Self::VT_FILE, false)?
+     .visit_field::<u32>("line", Self::VT_LINE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LogArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub level: LogLevel,
+    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub file: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub line: u32,
+}
+impl<'a> Default for LogArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LogArgs {
+      timestamp: None,
+      level: LogLevel::UNKNOWN,
+      message: None,
+      name: None,
+      file: None,
+      line: 0,
+    }
+  }
+}
+
+pub struct LogBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> LogBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(Log::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_level(&mut self, level: LogLevel) {
+    self.fbb_.push_slot::<LogLevel>(Log::VT_LEVEL, level, LogLevel::UNKNOWN);
+  }
+  #[inline]
+  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Log::VT_MESSAGE, message);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Log::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_file(&mut self, file: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Log::VT_FILE, file);
+  }
+  #[inline]
+  pub fn add_line(&mut self, line: u32) {
+    self.fbb_.push_slot::<u32>(Log::VT_LINE, line, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LogBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    LogBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Log<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Log<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Log");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("level", &self.level());
+      ds.field("message", &self.message());
+      ds.field("name", &self.name());
+      ds.field("file", &self.file());
+      ds.field("line", &self.line());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `Log`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_log_unchecked`.
+pub fn root_as_log(buf: &[u8]) -> Result<Log, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<Log>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `Log` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_log_unchecked`.
+pub fn size_prefixed_root_as_log(buf: &[u8]) -> Result<Log, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<Log>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `Log` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_log_unchecked`.
+pub fn root_as_log_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Log<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<Log<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `Log` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_log_unchecked`.
+pub fn size_prefixed_root_as_log_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Log<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<Log<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a Log and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `Log`.
+pub unsafe fn root_as_log_unchecked(buf: &[u8]) -> Log {
+  flatbuffers::root_unchecked::<Log>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed Log and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `Log`.
+pub unsafe fn size_prefixed_root_as_log_unchecked(buf: &[u8]) -> Log {
+  flatbuffers::size_prefixed_root_unchecked::<Log>(buf)
+}
+#[inline]
+pub fn finish_log_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Log<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_log_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Log<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
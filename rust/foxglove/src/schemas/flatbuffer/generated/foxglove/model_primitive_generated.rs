@@ -0,0 +1,289 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum ModelPrimitiveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A primitive representing a 3D model file loaded from an external URL or embedded data
+pub struct ModelPrimitive<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ModelPrimitive<'a> {
+  type Inner = ModelPrimitive<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ModelPrimitive<'a> {
+  pub const VT_POSE: flatbuffers::VOffsetT = 4;
+  pub const VT_SCALE: flatbuffers::VOffsetT = 6;
+  pub const VT_COLOR: flatbuffers::VOffsetT = 8;
+  pub const VT_OVERRIDE_COLOR: flatbuffers::VOffsetT = 10;
+  pub const VT_URL: flatbuffers::VOffsetT = 12;
+  pub const VT_MEDIA_TYPE: flatbuffers::VOffsetT = 14;
+  pub const VT_DATA: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ModelPrimitive { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ModelPrimitiveArgs<'args>
+  ) -> flatbuffers::WIPOffset<ModelPrimitive<'bldr>> {
+    let mut builder = ModelPrimitiveBuilder::new(_fbb);
+    if let Some(x) = args.data { builder.add_data(x); }
+    if let Some(x) = args.media_type { builder.add_media_type(x); }
+    if let Some(x) = args.url { builder.add_url(x); }
+    if let Some(x) = args.color { builder.add_color(x); }
+    if let Some(x) = args.scale { builder.add_scale(x); }
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    builder.add_override_color(args.override_color);
+    builder.finish()
+  }
+
+
+  /// Origin of model relative to reference frame
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(ModelPrimitive::VT_POSE, None)}
+  }
+  /// Scale factor to apply to the model along each axis
+  #[inline]
+  pub fn scale(&self) -> Option<Vector3<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Vector3>>(ModelPrimitive::VT_SCALE, None)}
+  }
+  /// Solid color to use for the whole model if `override_color` is true.
+  #[inline]
+  pub fn color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(ModelPrimitive::VT_COLOR, None)}
+  }
+  /// Whether to use the color specified in `color` instead of any materials embedded in the original model.
+  #[inline]
+  pub fn override_color(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ModelPrimitive::VT_OVERRIDE_COLOR, Some(false)).unwrap()}
+  }
+  /// URL pointing to model file. One of `url` or `data` should be provided.
+  #[inline]
+  pub fn url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ModelPrimitive::VT_URL, None)}
+  }
+  /// [Media type](https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types) of embedded model (e.g. `model/gltf-binary`). Required if `data` is provided instead of `url`. Overrides the inferred media type if `url` is provided.
+  #[inline]
+  pub fn media_type(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ModelPrimitive::VT_MEDIA_TYPE, None)}
+  }
+  /// Embedded model. One of `url` or `data` should be provided. If `data` is provided, `media_type` must be set to indicate the type of the data.
+  #[inline]
+  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ModelPrimitive::VT_DATA, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ModelPrimitive<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Vector3>>("scale", Self::VT_SCALE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("color", Self::VT_COLOR, false)?
+     .visit_field::<bool>("override_color", Self::VT_OVERRIDE_COLOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("media_type", Self::VT_MEDIA_TYPE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("data", Self::VT_DATA, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ModelPrimitiveArgs<'a> {
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub scale: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+    pub color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+    pub override_color: bool,
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub media_type: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for ModelPrimitiveArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ModelPrimitiveArgs {
+      pose: None,
+      scale: None,
+      color: None,
+      override_color: false,
+      url: None,
+      media_type: None,
+      data: None,
+    }
+  }
+}
+
+pub struct ModelPrimitiveBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ModelPrimitiveBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(ModelPrimitive::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_scale(&mut self, scale: flatbuffers::WIPOffset<Vector3<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Vector3>>(ModelPrimitive::VT_SCALE, scale);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(ModelPrimitive::VT_COLOR, color);
+  }
+  #[inline]
+  pub fn add_override_color(&mut self, override_color: bool) {
+    self.fbb_.push_slot::<bool>(ModelPrimitive::VT_OVERRIDE_COLOR, override_color, false);
+  }
+  #[inline]
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ModelPrimitive::VT_URL, url);
+  }
+  #[inline]
+  pub fn add_media_type(&mut self, media_type: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ModelPrimitive::VT_MEDIA_TYPE, media_type);
+  }
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ModelPrimitive::VT_DATA, data);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ModelPrimitiveBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ModelPrimitiveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ModelPrimitive<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ModelPrimitive<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ModelPrimitive");
+      ds.field("pose", &self.pose());
+      ds.field("scale", &self.scale());
+      ds.field("color", &self.color());
+      ds.field("override_color", &self.override_color());
+      ds.field("url", &self.url());
+      ds.field("media_type", &self.media_type());
+      ds.field("data", &self.data());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ModelPrimitive`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_model_primitive_unchecked`.
+pub fn root_as_model_primitive(buf: &[u8]) -> Result<ModelPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<ModelPrimitive>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ModelPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_model_primitive_unchecked`.
+pub fn size_prefixed_root_as_model_primitive(buf: &[u8]) -> Result<ModelPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<ModelPrimitive>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ModelPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_model_primitive_unchecked`.
+pub fn root_as_model_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ModelPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<ModelPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ModelPrimitive` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_model_primitive_unchecked`.
+pub fn size_prefixed_root_as_model_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ModelPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<ModelPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ModelPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ModelPrimitive`.
+pub unsafe fn root_as_model_primitive_unchecked(buf: &[u8]) -> ModelPrimitive {
+  flatbuffers::root_unchecked::<ModelPrimitive>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ModelPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ModelPrimitive`.
+pub unsafe fn size_prefixed_root_as_model_primitive_unchecked(buf: &[u8]) -> ModelPrimitive {
+  flatbuffers::size_prefixed_root_unchecked::<ModelPrimitive>(buf)
+}
+#[inline]
+pub fn finish_model_primitive_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<ModelPrimitive<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_model_primitive_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<ModelPrimitive<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
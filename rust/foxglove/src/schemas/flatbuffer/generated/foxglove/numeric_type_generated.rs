@@ -0,0 +1,125 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_NUMERIC_TYPE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_NUMERIC_TYPE: u8 = 8;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_NUMERIC_TYPE: [NumericType; 9] = [
+  NumericType::UNKNOWN,
+  NumericType::UINT8,
+  NumericType::INT8,
+  NumericType::UINT16,
+  NumericType::INT16,
+  NumericType::UINT32,
+  NumericType::INT32,
+  NumericType::FLOAT32,
+  NumericType::FLOAT64,
+];
+
+/// Numeric type
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct NumericType(pub u8);
+#[allow(non_upper_case_globals)]
+impl NumericType {
+  pub const UNKNOWN: Self = Self(0);
+  pub const UINT8: Self = Self(1);
+  pub const INT8: Self = Self(2);
+  pub const UINT16: Self = Self(3);
+  pub const INT16: Self = Self(4);
+  pub const UINT32: Self = Self(5);
+  pub const INT32: Self = Self(6);
+  pub const FLOAT32: Self = Self(7);
+  pub const FLOAT64: Self = Self(8);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 8;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::UNKNOWN,
+    Self::UINT8,
+    Self::INT8,
+    Self::UINT16,
+    Self::INT16,
+    Self::UINT32,
+    Self::INT32,
+    Self::FLOAT32,
+    Self::FLOAT64,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::UNKNOWN => Some("UNKNOWN"),
+      Self::UINT8 => Some("UINT8"),
+      Self::INT8 => Some("INT8"),
+      Self::UINT16 => Some("UINT16"),
+      Self::INT16 => Some("INT16"),
+      Self::UINT32 => Some("UINT32"),
+      Self::INT32 => Some("INT32"),
+      Self::FLOAT32 => Some("FLOAT32"),
+      Self::FLOAT64 => Some("FLOAT64"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for NumericType {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for NumericType {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for NumericType {
+    type Output = NumericType;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for NumericType {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for NumericType {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for NumericType {}
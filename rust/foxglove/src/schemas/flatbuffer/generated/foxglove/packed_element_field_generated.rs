@@ -0,0 +1,217 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum PackedElementFieldOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A field present within each element in a byte array of packed elements.
+pub struct PackedElementField<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PackedElementField<'a> {
+  type Inner = PackedElementField<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PackedElementField<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_OFFSET: flatbuffers::VOffsetT = 6;
+  pub const VT_TYPE_: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PackedElementField { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PackedElementFieldArgs<'args>
+  ) -> flatbuffers::WIPOffset<PackedElementField<'bldr>> {
+    let mut builder = PackedElementFieldBuilder::new(_fbb);
+    builder.add_offset(args.offset);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_type_(args.type_);
+    builder.finish()
+  }
+
+
+  /// Name of the field
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PackedElementField::VT_NAME, None)}
+  }
+  /// Byte offset from start of data buffer
+  #[inline]
+  pub fn offset(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(PackedElementField::VT_OFFSET, Some(0)).unwrap()}
+  }
+  /// Type of data in the field. Integers are stored using little-endian byte order.
+  #[inline]
+  pub fn type_(&self) -> NumericType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<NumericType>(PackedElementField::VT_TYPE_, Some(NumericType::UNKNOWN)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PackedElementField<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u32>("offset", Self::VT_OFFSET, false)?
+     .visit_field::<NumericType>("type_", Self::VT_TYPE_, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PackedElementFieldArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub offset: u32,
+    pub type_: NumericType,
+}
+impl<'a> Default for PackedElementFieldArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PackedElementFieldArgs {
+      name: None,
+      offset: 0,
+      type_: NumericType::UNKNOWN,
+    }
+  }
+}
+
+pub struct PackedElementFieldBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PackedElementFieldBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PackedElementField::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_offset(&mut self, offset: u32) {
+    self.fbb_.push_slot::<u32>(PackedElementField::VT_OFFSET, offset, 0);
+  }
+  #[inline]
+  pub fn add_type_(&mut self, type_: NumericType) {
+    self.fbb_.push_slot::<NumericType>(PackedElementField::VT_TYPE_, type_, NumericType::UNKNOWN);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PackedElementFieldBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PackedElementFieldBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PackedElementField<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PackedElementField<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PackedElementField");
+      ds.field("name", &self.name());
+      ds.field("offset", &self.offset());
+      ds.field("type_", &self.type_());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `PackedElementField`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_packed_element_field_unchecked`.
+pub fn root_as_packed_element_field(buf: &[u8]) -> Result<PackedElementField, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<PackedElementField>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `PackedElementField` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_packed_element_field_unchecked`.
+pub fn size_prefixed_root_as_packed_element_field(buf: &[u8]) -> Result<PackedElementField, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<PackedElementField>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `PackedElementField` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_packed_element_field_unchecked`.
+pub fn root_as_packed_element_field_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PackedElementField<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<PackedElementField<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `PackedElementField` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_packed_element_field_unchecked`.
+pub fn size_prefixed_root_as_packed_element_field_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PackedElementField<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<PackedElementField<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a PackedElementField and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `PackedElementField`.
+pub unsafe fn root_as_packed_element_field_unchecked(buf: &[u8]) -> PackedElementField {
+  flatbuffers::root_unchecked::<PackedElementField>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed PackedElementField and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `PackedElementField`.
+pub unsafe fn size_prefixed_root_as_packed_element_field_unchecked(buf: &[u8]) -> PackedElementField {
+  flatbuffers::size_prefixed_root_unchecked::<PackedElementField>(buf)
+}
+#[inline]
+pub fn finish_packed_element_field_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<PackedElementField<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_packed_element_field_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<PackedElementField<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,199 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum Point2Offset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A point representing a position in 2D space
+pub struct Point2<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Point2<'a> {
+  type Inner = Point2<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Point2<'a> {
+  pub const VT_X: flatbuffers::VOffsetT = 4;
+  pub const VT_Y: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Point2 { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args Point2Args
+  ) -> flatbuffers::WIPOffset<Point2<'bldr>> {
+    let mut builder = Point2Builder::new(_fbb);
+    builder.add_y(args.y);
+    builder.add_x(args.x);
+    builder.finish()
+  }
+
+
+  /// x coordinate position
+  #[inline]
+  pub fn x(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Point2::VT_X, Some(0.0)).unwrap()}
+  }
+  /// y coordinate position
+  #[inline]
+  pub fn y(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Point2::VT_Y, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Point2<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f64>("x", Self::VT_X, false)?
+     .visit_field::<f64>("y", Self::VT_Y, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct Point2Args {
+    pub x: f64,
+    pub y: f64,
+}
+impl<'a> Default for Point2Args {
+  #[inline]
+  fn default() -> Self {
+    Point2Args {
+      x: 0.0,
+      y: 0.0,
+    }
+  }
+}
+
+pub struct Point2Builder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> Point2Builder<'a, 'b> {
+  #[inline]
+  pub fn add_x(&mut self, x: f64) {
+    self.fbb_.push_slot::<f64>(Point2::VT_X, x, 0.0);
+  }
+  #[inline]
+  pub fn add_y(&mut self, y: f64) {
+    self.fbb_.push_slot::<f64>(Point2::VT_Y, y, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Point2Builder<'a, 'b> {
+    let start = _fbb.start_table();
+    Point2Builder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Point2<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Point2<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Point2");
+      ds.field("x", &self.x());
+      ds.field("y", &self.y());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `Point2`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_point_2_unchecked`.
+pub fn root_as_point_2(buf: &[u8]) -> Result<Point2, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<Point2>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `Point2` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_point_2_unchecked`.
+pub fn size_prefixed_root_as_point_2(buf: &[u8]) -> Result<Point2, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<Point2>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `Point2` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_point_2_unchecked`.
+pub fn root_as_point_2_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Point2<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<Point2<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `Point2` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_point_2_unchecked`.
+pub fn size_prefixed_root_as_point_2_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Point2<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<Point2<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a Point2 and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `Point2`.
+pub unsafe fn root_as_point_2_unchecked(buf: &[u8]) -> Point2 {
+  flatbuffers::root_unchecked::<Point2>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed Point2 and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `Point2`.
+pub unsafe fn size_prefixed_root_as_point_2_unchecked(buf: &[u8]) -> Point2 {
+  flatbuffers::size_prefixed_root_unchecked::<Point2>(buf)
+}
+#[inline]
+pub fn finish_point_2_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Point2<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_point_2_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Point2<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
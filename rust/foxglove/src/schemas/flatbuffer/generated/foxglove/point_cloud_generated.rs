@@ -0,0 +1,271 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum PointCloudOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A collection of N-dimensional points, which may contain additional fields with information like normals, intensity, etc.
+pub struct PointCloud<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PointCloud<'a> {
+  type Inner = PointCloud<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PointCloud<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_POSE: flatbuffers::VOffsetT = 8;
+  pub const VT_POINT_STRIDE: flatbuffers::VOffsetT = 10;
+  pub const VT_FIELDS: flatbuffers::VOffsetT = 12;
+  pub const VT_DATA: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PointCloud { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PointCloudArgs<'args>
+  ) -> flatbuffers::WIPOffset<PointCloud<'bldr>> {
+    let mut builder = PointCloudBuilder::new(_fbb);
+    if let Some(x) = args.data { builder.add_data(x); }
+    if let Some(x) = args.fields { builder.add_fields(x); }
+    builder.add_point_stride(args.point_stride);
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of point cloud
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(PointCloud::VT_TIMESTAMP, None)}
+  }
+  /// Frame of reference
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PointCloud::VT_FRAME_ID, None)}
+  }
+  /// The origin of the point cloud relative to the frame of reference
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(PointCloud::VT_POSE, None)}
+  }
+  /// Number of bytes between points in the `data`
+  #[inline]
+  pub fn point_stride(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(PointCloud::VT_POINT_STRIDE, Some(0)).unwrap()}
+  }
+  /// Fields in `data`. At least 2 coordinate fields from `x`, `y`, and `z` are required for each point's position; `red`, `green`, `blue`, and `alpha` are optional for customizing each point's color.
+  #[inline]
+  pub fn fields(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PackedElementField<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PackedElementField>>>>(PointCloud::VT_FIELDS, None)}
+  }
+  /// Point data, interpreted using `fields`
+  #[inline]
+  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(PointCloud::VT_DATA, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PointCloud<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<u32>("point_stride", Self::VT_POINT_STRIDE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PackedElementField>>>>("fields", Self::VT_FIELDS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("data", Self::VT_DATA, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PointCloudArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub point_stride: u32,
+    pub fields: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PackedElementField<'a>>>>>,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for PointCloudArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PointCloudArgs {
+      timestamp: None,
+      frame_id: None,
+      pose: None,
+      point_stride: 0,
+      fields: None,
+      data: None,
+    }
+  }
+}
+
+pub struct PointCloudBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PointCloudBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(PointCloud::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PointCloud::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(PointCloud::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_point_stride(&mut self, point_stride: u32) {
+    self.fbb_.push_slot::<u32>(PointCloud::VT_POINT_STRIDE, point_stride, 0);
+  }
+  #[inline]
+  pub fn add_fields(&mut self, fields: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PackedElementField<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PointCloud::VT_FIELDS, fields);
+  }
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PointCloud::VT_DATA, data);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PointCloudBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PointCloudBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PointCloud<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PointCloud<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PointCloud");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("pose", &self.pose());
+      ds.field("point_stride", &self.point_stride());
+      ds.field("fields", &self.fields());
+      ds.field("data", &self.data());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `PointCloud`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_point_cloud_unchecked`.
+pub fn root_as_point_cloud(buf: &[u8]) -> Result<PointCloud, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<PointCloud>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `PointCloud` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_point_cloud_unchecked`.
+pub fn size_prefixed_root_as_point_cloud(buf: &[u8]) -> Result<PointCloud, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<PointCloud>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `PointCloud` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_point_cloud_unchecked`.
+pub fn root_as_point_cloud_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PointCloud<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<PointCloud<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `PointCloud` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_point_cloud_unchecked`.
+pub fn size_prefixed_root_as_point_cloud_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PointCloud<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<PointCloud<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a PointCloud and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `PointCloud`.
+pub unsafe fn root_as_point_cloud_unchecked(buf: &[u8]) -> PointCloud {
+  flatbuffers::root_unchecked::<PointCloud>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed PointCloud and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `PointCloud`.
+pub unsafe fn size_prefixed_root_as_point_cloud_unchecked(buf: &[u8]) -> PointCloud {
+  flatbuffers::size_prefixed_root_unchecked::<PointCloud>(buf)
+}
+#[inline]
+pub fn finish_point_cloud_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<PointCloud<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_point_cloud_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<PointCloud<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
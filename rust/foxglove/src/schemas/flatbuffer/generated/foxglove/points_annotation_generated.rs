@@ -0,0 +1,290 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum PointsAnnotationOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// An array of points on a 2D image
+pub struct PointsAnnotation<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PointsAnnotation<'a> {
+  type Inner = PointsAnnotation<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PointsAnnotation<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_TYPE_: flatbuffers::VOffsetT = 6;
+  pub const VT_POINTS: flatbuffers::VOffsetT = 8;
+  pub const VT_OUTLINE_COLOR: flatbuffers::VOffsetT = 10;
+  pub const VT_OUTLINE_COLORS: flatbuffers::VOffsetT = 12;
+  pub const VT_FILL_COLOR: flatbuffers::VOffsetT = 14;
+  pub const VT_THICKNESS: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PointsAnnotation { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PointsAnnotationArgs<'args>
+  ) -> flatbuffers::WIPOffset<PointsAnnotation<'bldr>> {
+    let mut builder = PointsAnnotationBuilder::new(_fbb);
+    builder.add_thickness(args.thickness);
+    if let Some(x) = args.fill_color { builder.add_fill_color(x); }
+    if let Some(x) = args.outline_colors { builder.add_outline_colors(x); }
+    if let Some(x) = args.outline_color { builder.add_outline_color(x); }
+    if let Some(x) = args.points { builder.add_points(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.add_type_(args.type_);
+    builder.finish()
+  }
+
+
+  /// Timestamp of annotation
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(PointsAnnotation::VT_TIMESTAMP, None)}
+  }
+  /// Type of points annotation to draw
+  #[inline]
+  pub fn type_(&self) -> PointsAnnotationType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PointsAnnotationType>(PointsAnnotation::VT_TYPE_, Some(PointsAnnotationType::UNKNOWN)).unwrap()}
+  }
+  /// Points in 2D image coordinates (pixels).
+  /// These coordinates use the top-left corner of the top-left pixel of the image as the origin.
+  #[inline]
+  pub fn points(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Point2<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Point2>>>>(PointsAnnotation::VT_POINTS, None)}
+  }
+  /// Outline color
+  #[inline]
+  pub fn outline_color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(PointsAnnotation::VT_OUTLINE_COLOR, None)}
+  }
+  /// Per-point colors, if `type` is `POINTS`, or per-segment stroke colors, if `type` is `LINE_LIST`, `LINE_STRIP` or `LINE_LOOP`.
+  #[inline]
+  pub fn outline_colors(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Color<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Color>>>>(PointsAnnotation::VT_OUTLINE_COLORS, None)}
+  }
+  /// Fill color
+  #[inline]
+  pub fn fill_color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(PointsAnnotation::VT_FILL_COLOR, None)}
+  }
+  /// Stroke thickness in pixels
+  #[inline]
+  pub fn thickness(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(PointsAnnotation::VT_THICKNESS, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PointsAnnotation<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<PointsAnnotationType>("type_", Self::VT_TYPE_, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Point2>>>>("points", Self::VT_POINTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("outline_color", Self::VT_OUTLINE_COLOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Color>>>>("outline_colors", Self::VT_OUTLINE_COLORS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("fill_color", Self::VT_FILL_COLOR, false)?
+     .visit_field::<f64>("thickness", Self::VT_THICKNESS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PointsAnnotationArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub type_: PointsAnnotationType,
+    pub points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Point2<'a>>>>>,
+    pub outline_color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+    pub outline_colors: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Color<'a>>>>>,
+    pub fill_color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+    pub thickness: f64,
+}
+impl<'a> Default for PointsAnnotationArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PointsAnnotationArgs {
+      timestamp: None,
+      type_: PointsAnnotationType::UNKNOWN,
+      points: None,
+      outline_color: None,
+      outline_colors: None,
+      fill_color: None,
+      thickness: 0.0,
+    }
+  }
+}
+
+pub struct PointsAnnotationBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PointsAnnotationBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(PointsAnnotation::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_type_(&mut self, type_: PointsAnnotationType) {
+    self.fbb_.push_slot::<PointsAnnotationType>(PointsAnnotation::VT_TYPE_, type_, PointsAnnotationType::UNKNOWN);
+  }
+  #[inline]
+  pub fn add_points(&mut self, points: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Point2<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PointsAnnotation::VT_POINTS, points);
+  }
+  #[inline]
+  pub fn add_outline_color(&mut self, outline_color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(PointsAnnotation::VT_OUTLINE_COLOR, outline_color);
+  }
+  #[inline]
+  pub fn add_outline_colors(&mut self, outline_colors: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Color<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PointsAnnotation::VT_OUTLINE_COLORS, outline_colors);
+  }
+  #[inline]
+  pub fn add_fill_color(&mut self, fill_color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(PointsAnnotation::VT_FILL_COLOR, fill_color);
+  }
+  #[inline]
+  pub fn add_thickness(&mut self, thickness: f64) {
+    self.fbb_.push_slot::<f64>(PointsAnnotation::VT_THICKNESS, thickness, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PointsAnnotationBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PointsAnnotationBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PointsAnnotation<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PointsAnnotation<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PointsAnnotation");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("type_", &self.type_());
+      ds.field("points", &self.points());
+      ds.field("outline_color", &self.outline_color());
+      ds.field("outline_colors", &self.outline_colors());
+      ds.field("fill_color", &self.fill_color());
+      ds.field("thickness", &self.thickness());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `PointsAnnotation`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_points_annotation_unchecked`.
+pub fn root_as_points_annotation(buf: &[u8]) -> Result<PointsAnnotation, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<PointsAnnotation>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `PointsAnnotation` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_points_annotation_unchecked`.
+pub fn size_prefixed_root_as_points_annotation(buf: &[u8]) -> Result<PointsAnnotation, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<PointsAnnotation>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `PointsAnnotation` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_points_annotation_unchecked`.
+pub fn root_as_points_annotation_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PointsAnnotation<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<PointsAnnotation<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `PointsAnnotation` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_points_annotation_unchecked`.
+pub fn size_prefixed_root_as_points_annotation_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PointsAnnotation<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<PointsAnnotation<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a PointsAnnotation and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `PointsAnnotation`.
+pub unsafe fn root_as_points_annotation_unchecked(buf: &[u8]) -> PointsAnnotation {
+  flatbuffers::root_unchecked::<PointsAnnotation>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed PointsAnnotation and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `PointsAnnotation`.
+pub unsafe fn size_prefixed_root_as_points_annotation_unchecked(buf: &[u8]) -> PointsAnnotation {
+  flatbuffers::size_prefixed_root_unchecked::<PointsAnnotation>(buf)
+}
+#[inline]
+pub fn finish_points_annotation_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<PointsAnnotation<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_points_annotation_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<PointsAnnotation<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,113 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_POINTS_ANNOTATION_TYPE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_POINTS_ANNOTATION_TYPE: u8 = 4;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_POINTS_ANNOTATION_TYPE: [PointsAnnotationType; 5] = [
+  PointsAnnotationType::UNKNOWN,
+  PointsAnnotationType::POINTS,
+  PointsAnnotationType::LINE_LOOP,
+  PointsAnnotationType::LINE_STRIP,
+  PointsAnnotationType::LINE_LIST,
+];
+
+/// Type of points annotation
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct PointsAnnotationType(pub u8);
+#[allow(non_upper_case_globals)]
+impl PointsAnnotationType {
+  pub const UNKNOWN: Self = Self(0);
+  /// Individual points: 0, 1, 2, ...
+  pub const POINTS: Self = Self(1);
+  /// Closed polygon: 0-1, 1-2, ..., (n-1)-n, n-0
+  pub const LINE_LOOP: Self = Self(2);
+  /// Connected line segments: 0-1, 1-2, ..., (n-1)-n
+  pub const LINE_STRIP: Self = Self(3);
+  /// Individual line segments: 0-1, 2-3, 4-5, ...
+  pub const LINE_LIST: Self = Self(4);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 4;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::UNKNOWN,
+    Self::POINTS,
+    Self::LINE_LOOP,
+    Self::LINE_STRIP,
+    Self::LINE_LIST,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::UNKNOWN => Some("UNKNOWN"),
+      Self::POINTS => Some("POINTS"),
+      Self::LINE_LOOP => Some("LINE_LOOP"),
+      Self::LINE_STRIP => Some("LINE_STRIP"),
+      Self::LINE_LIST => Some("LINE_LIST"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for PointsAnnotationType {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for PointsAnnotationType {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for PointsAnnotationType {
+    type Output = PointsAnnotationType;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for PointsAnnotationType {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for PointsAnnotationType {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for PointsAnnotationType {}
@@ -0,0 +1,199 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum PoseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A position and orientation for an object or reference frame in 3D space
+pub struct Pose<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Pose<'a> {
+  type Inner = Pose<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Pose<'a> {
+  pub const VT_POSITION: flatbuffers::VOffsetT = 4;
+  pub const VT_ORIENTATION: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Pose { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PoseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Pose<'bldr>> {
+    let mut builder = PoseBuilder::new(_fbb);
+    if let Some(x) = args.orientation { builder.add_orientation(x); }
+    if let Some(x) = args.position { builder.add_position(x); }
+    builder.finish()
+  }
+
+
+  /// Point denoting position in 3D space
+  #[inline]
+  pub fn position(&self) -> Option<Vector3<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Vector3>>(Pose::VT_POSITION, None)}
+  }
+  /// Quaternion denoting orientation in 3D space
+  #[inline]
+  pub fn orientation(&self) -> Option<Quaternion<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Quaternion>>(Pose::VT_ORIENTATION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Pose<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Vector3>>("position", Self::VT_POSITION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Quaternion>>("orientation", Self::VT_ORIENTATION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PoseArgs<'a> {
+    pub position: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+    pub orientation: Option<flatbuffers::WIPOffset<Quaternion<'a>>>,
+}
+impl<'a> Default for PoseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PoseArgs {
+      position: None,
+      orientation: None,
+    }
+  }
+}
+
+pub struct PoseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PoseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_position(&mut self, position: flatbuffers::WIPOffset<Vector3<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Vector3>>(Pose::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn add_orientation(&mut self, orientation: flatbuffers::WIPOffset<Quaternion<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Quaternion>>(Pose::VT_ORIENTATION, orientation);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PoseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PoseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Pose<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Pose<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Pose");
+      ds.field("position", &self.position());
+      ds.field("orientation", &self.orientation());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `Pose`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_pose_unchecked`.
+pub fn root_as_pose(buf: &[u8]) -> Result<Pose, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<Pose>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `Pose` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_pose_unchecked`.
+pub fn size_prefixed_root_as_pose(buf: &[u8]) -> Result<Pose, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<Pose>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `Pose` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_pose_unchecked`.
+pub fn root_as_pose_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Pose<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<Pose<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `Pose` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_pose_unchecked`.
+pub fn size_prefixed_root_as_pose_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Pose<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<Pose<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a Pose and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `Pose`.
+pub unsafe fn root_as_pose_unchecked(buf: &[u8]) -> Pose {
+  flatbuffers::root_unchecked::<Pose>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed Pose and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `Pose`.
+pub unsafe fn size_prefixed_root_as_pose_unchecked(buf: &[u8]) -> Pose {
+  flatbuffers::size_prefixed_root_unchecked::<Pose>(buf)
+}
+#[inline]
+pub fn finish_pose_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Pose<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_pose_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Pose<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,217 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum PoseInFrameOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A timestamped pose for an object or reference frame in 3D space
+pub struct PoseInFrame<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PoseInFrame<'a> {
+  type Inner = PoseInFrame<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PoseInFrame<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_POSE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PoseInFrame { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PoseInFrameArgs<'args>
+  ) -> flatbuffers::WIPOffset<PoseInFrame<'bldr>> {
+    let mut builder = PoseInFrameBuilder::new(_fbb);
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of pose
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(PoseInFrame::VT_TIMESTAMP, None)}
+  }
+  /// Frame of reference for pose position and orientation
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PoseInFrame::VT_FRAME_ID, None)}
+  }
+  /// Pose in 3D space
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(PoseInFrame::VT_POSE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PoseInFrame<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PoseInFrameArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+}
+impl<'a> Default for PoseInFrameArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PoseInFrameArgs {
+      timestamp: None,
+      frame_id: None,
+      pose: None,
+    }
+  }
+}
+
+pub struct PoseInFrameBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PoseInFrameBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(PoseInFrame::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PoseInFrame::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(PoseInFrame::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PoseInFrameBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PoseInFrameBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PoseInFrame<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PoseInFrame<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PoseInFrame");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("pose", &self.pose());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `PoseInFrame`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_pose_in_frame_unchecked`.
+pub fn root_as_pose_in_frame(buf: &[u8]) -> Result<PoseInFrame, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<PoseInFrame>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `PoseInFrame` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_pose_in_frame_unchecked`.
+pub fn size_prefixed_root_as_pose_in_frame(buf: &[u8]) -> Result<PoseInFrame, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<PoseInFrame>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `PoseInFrame` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_pose_in_frame_unchecked`.
+pub fn root_as_pose_in_frame_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PoseInFrame<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<PoseInFrame<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `PoseInFrame` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_pose_in_frame_unchecked`.
+pub fn size_prefixed_root_as_pose_in_frame_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PoseInFrame<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<PoseInFrame<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a PoseInFrame and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `PoseInFrame`.
+pub unsafe fn root_as_pose_in_frame_unchecked(buf: &[u8]) -> PoseInFrame {
+  flatbuffers::root_unchecked::<PoseInFrame>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed PoseInFrame and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `PoseInFrame`.
+pub unsafe fn size_prefixed_root_as_pose_in_frame_unchecked(buf: &[u8]) -> PoseInFrame {
+  flatbuffers::size_prefixed_root_unchecked::<PoseInFrame>(buf)
+}
+#[inline]
+pub fn finish_pose_in_frame_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<PoseInFrame<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_pose_in_frame_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<PoseInFrame<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
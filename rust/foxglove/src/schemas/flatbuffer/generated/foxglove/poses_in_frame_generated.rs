@@ -0,0 +1,217 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum PosesInFrameOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// An array of timestamped poses for an object or reference frame in 3D space
+pub struct PosesInFrame<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PosesInFrame<'a> {
+  type Inner = PosesInFrame<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PosesInFrame<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_POSES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PosesInFrame { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PosesInFrameArgs<'args>
+  ) -> flatbuffers::WIPOffset<PosesInFrame<'bldr>> {
+    let mut builder = PosesInFrameBuilder::new(_fbb);
+    if let Some(x) = args.poses { builder.add_poses(x); }
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of pose
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(PosesInFrame::VT_TIMESTAMP, None)}
+  }
+  /// Frame of reference for pose position and orientation
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PosesInFrame::VT_FRAME_ID, None)}
+  }
+  /// Poses in 3D space
+  #[inline]
+  pub fn poses(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Pose<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Pose>>>>(PosesInFrame::VT_POSES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PosesInFrame<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Pose>>>>("poses", Self::VT_POSES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PosesInFrameArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub poses: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Pose<'a>>>>>,
+}
+impl<'a> Default for PosesInFrameArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PosesInFrameArgs {
+      timestamp: None,
+      frame_id: None,
+      poses: None,
+    }
+  }
+}
+
+pub struct PosesInFrameBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PosesInFrameBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(PosesInFrame::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PosesInFrame::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_poses(&mut self, poses: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Pose<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PosesInFrame::VT_POSES, poses);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PosesInFrameBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PosesInFrameBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PosesInFrame<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PosesInFrame<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PosesInFrame");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("poses", &self.poses());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `PosesInFrame`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_poses_in_frame_unchecked`.
+pub fn root_as_poses_in_frame(buf: &[u8]) -> Result<PosesInFrame, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<PosesInFrame>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `PosesInFrame` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_poses_in_frame_unchecked`.
+pub fn size_prefixed_root_as_poses_in_frame(buf: &[u8]) -> Result<PosesInFrame, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<PosesInFrame>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `PosesInFrame` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_poses_in_frame_unchecked`.
+pub fn root_as_poses_in_frame_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PosesInFrame<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<PosesInFrame<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `PosesInFrame` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_poses_in_frame_unchecked`.
+pub fn size_prefixed_root_as_poses_in_frame_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PosesInFrame<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<PosesInFrame<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a PosesInFrame and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `PosesInFrame`.
+pub unsafe fn root_as_poses_in_frame_unchecked(buf: &[u8]) -> PosesInFrame {
+  flatbuffers::root_unchecked::<PosesInFrame>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed PosesInFrame and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `PosesInFrame`.
+pub unsafe fn size_prefixed_root_as_poses_in_frame_unchecked(buf: &[u8]) -> PosesInFrame {
+  flatbuffers::size_prefixed_root_unchecked::<PosesInFrame>(buf)
+}
+#[inline]
+pub fn finish_poses_in_frame_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<PosesInFrame<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_poses_in_frame_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<PosesInFrame<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
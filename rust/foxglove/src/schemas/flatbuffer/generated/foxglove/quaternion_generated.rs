@@ -0,0 +1,235 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum QuaternionOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A [quaternion](https://eater.net/quaternions) representing a rotation in 3D space
+pub struct Quaternion<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Quaternion<'a> {
+  type Inner = Quaternion<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Quaternion<'a> {
+  pub const VT_X: flatbuffers::VOffsetT = 4;
+  pub const VT_Y: flatbuffers::VOffsetT = 6;
+  pub const VT_Z: flatbuffers::VOffsetT = 8;
+  pub const VT_W: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Quaternion { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QuaternionArgs
+  ) -> flatbuffers::WIPOffset<Quaternion<'bldr>> {
+    let mut builder = QuaternionBuilder::new(_fbb);
+    builder.add_w(args.w);
+    builder.add_z(args.z);
+    builder.add_y(args.y);
+    builder.add_x(args.x);
+    builder.finish()
+  }
+
+
+  /// x value
+  #[inline]
+  pub fn x(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Quaternion::VT_X, Some(0.0)).unwrap()}
+  }
+  /// y value
+  #[inline]
+  pub fn y(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Quaternion::VT_Y, Some(0.0)).unwrap()}
+  }
+  /// z value
+  #[inline]
+  pub fn z(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Quaternion::VT_Z, Some(0.0)).unwrap()}
+  }
+  /// w value
+  #[inline]
+  pub fn w(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Quaternion::VT_W, Some(1.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Quaternion<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f64>("x", Self::VT_X, false)?
+     .visit_field::<f64>("y", Self::VT_Y, false)?
+     .visit_field::<f64>("z", Self::VT_Z, false)?
+     .visit_field::<f64>("w", Self::VT_W, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QuaternionArgs {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+impl<'a> Default for QuaternionArgs {
+  #[inline]
+  fn default() -> Self {
+    QuaternionArgs {
+      x: 0.0,
+      y: 0.0,
+      z: 0.0,
+      w: 1.0,
+    }
+  }
+}
+
+pub struct QuaternionBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QuaternionBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_x(&mut self, x: f64) {
+    self.fbb_.push_slot::<f64>(Quaternion::VT_X, x, 0.0);
+  }
+  #[inline]
+  pub fn add_y(&mut self, y: f64) {
+    self.fbb_.push_slot::<f64>(Quaternion::VT_Y, y, 0.0);
+  }
+  #[inline]
+  pub fn add_z(&mut self, z: f64) {
+    self.fbb_.push_slot::<f64>(Quaternion::VT_Z, z, 0.0);
+  }
+  #[inline]
+  pub fn add_w(&mut self, w: f64) {
+    self.fbb_.push_slot::<f64>(Quaternion::VT_W, w, 1.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QuaternionBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QuaternionBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Quaternion<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Quaternion<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Quaternion");
+      ds.field("x", &self.x());
+      ds.field("y", &self.y());
+      ds.field("z", &self.z());
+      ds.field("w", &self.w());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `Quaternion`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_quaternion_unchecked`.
+pub fn root_as_quaternion(buf: &[u8]) -> Result<Quaternion, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<Quaternion>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `Quaternion` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_quaternion_unchecked`.
+pub fn size_prefixed_root_as_quaternion(buf: &[u8]) -> Result<Quaternion, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<Quaternion>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `Quaternion` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_quaternion_unchecked`.
+pub fn root_as_quaternion_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Quaternion<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<Quaternion<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `Quaternion` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_quaternion_unchecked`.
+pub fn size_prefixed_root_as_quaternion_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Quaternion<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<Quaternion<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a Quaternion and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `Quaternion`.
+pub unsafe fn root_as_quaternion_unchecked(buf: &[u8]) -> Quaternion {
+  flatbuffers::root_unchecked::<Quaternion>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed Quaternion and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `Quaternion`.
+pub unsafe fn size_prefixed_root_as_quaternion_unchecked(buf: &[u8]) -> Quaternion {
+  flatbuffers::size_prefixed_root_unchecked::<Quaternion>(buf)
+}
+#[inline]
+pub fn finish_quaternion_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Quaternion<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_quaternion_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Quaternion<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
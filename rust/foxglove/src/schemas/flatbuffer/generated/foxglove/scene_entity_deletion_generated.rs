@@ -0,0 +1,217 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum SceneEntityDeletionOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Command to remove previously published entities
+pub struct SceneEntityDeletion<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SceneEntityDeletion<'a> {
+  type Inner = SceneEntityDeletion<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SceneEntityDeletion<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_TYPE_: flatbuffers::VOffsetT = 6;
+  pub const VT_ID: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SceneEntityDeletion { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SceneEntityDeletionArgs<'args>
+  ) -> flatbuffers::WIPOffset<SceneEntityDeletion<'bldr>> {
+    let mut builder = SceneEntityDeletionBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.add_type_(args.type_);
+    builder.finish()
+  }
+
+
+  /// Timestamp of the deletion. Only matching entities earlier than this timestamp will be deleted.
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(SceneEntityDeletion::VT_TIMESTAMP, None)}
+  }
+  /// Type of deletion action to perform
+  #[inline]
+  pub fn type_(&self) -> SceneEntityDeletionType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SceneEntityDeletionType>(SceneEntityDeletion::VT_TYPE_, Some(SceneEntityDeletionType::MATCHING_ID)).unwrap()}
+  }
+  /// Identifier which must match if `type` is `MATCHING_ID`.
+  #[inline]
+  pub fn id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SceneEntityDeletion::VT_ID, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SceneEntityDeletion<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<SceneEntityDeletionType>("type_", Self::VT_TYPE_, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SceneEntityDeletionArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub type_: SceneEntityDeletionType,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SceneEntityDeletionArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SceneEntityDeletionArgs {
+      timestamp: None,
+      type_: SceneEntityDeletionType::MATCHING_ID,
+      id: None,
+    }
+  }
+}
+
+pub struct SceneEntityDeletionBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SceneEntityDeletionBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(SceneEntityDeletion::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_type_(&mut self, type_: SceneEntityDeletionType) {
+    self.fbb_.push_slot::<SceneEntityDeletionType>(SceneEntityDeletion::VT_TYPE_, type_, SceneEntityDeletionType::MATCHING_ID);
+  }
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntityDeletion::VT_ID, id);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SceneEntityDeletionBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SceneEntityDeletionBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SceneEntityDeletion<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SceneEntityDeletion<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SceneEntityDeletion");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("type_", &self.type_());
+      ds.field("id", &self.id());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `SceneEntityDeletion`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_scene_entity_deletion_unchecked`.
+pub fn root_as_scene_entity_deletion(buf: &[u8]) -> Result<SceneEntityDeletion, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<SceneEntityDeletion>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `SceneEntityDeletion` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_scene_entity_deletion_unchecked`.
+pub fn size_prefixed_root_as_scene_entity_deletion(buf: &[u8]) -> Result<SceneEntityDeletion, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<SceneEntityDeletion>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `SceneEntityDeletion` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_scene_entity_deletion_unchecked`.
+pub fn root_as_scene_entity_deletion_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SceneEntityDeletion<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<SceneEntityDeletion<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `SceneEntityDeletion` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_scene_entity_deletion_unchecked`.
+pub fn size_prefixed_root_as_scene_entity_deletion_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SceneEntityDeletion<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<SceneEntityDeletion<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a SceneEntityDeletion and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `SceneEntityDeletion`.
+pub unsafe fn root_as_scene_entity_deletion_unchecked(buf: &[u8]) -> SceneEntityDeletion {
+  flatbuffers::root_unchecked::<SceneEntityDeletion>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed SceneEntityDeletion and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `SceneEntityDeletion`.
+pub unsafe fn size_prefixed_root_as_scene_entity_deletion_unchecked(buf: &[u8]) -> SceneEntityDeletion {
+  flatbuffers::size_prefixed_root_unchecked::<SceneEntityDeletion>(buf)
+}
+#[inline]
+pub fn finish_scene_entity_deletion_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<SceneEntityDeletion<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_scene_entity_deletion_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<SceneEntityDeletion<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,415 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum SceneEntityOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A visual element in a 3D scene. An entity may be composed of multiple primitives which all share the same frame of reference.
+pub struct SceneEntity<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SceneEntity<'a> {
+  type Inner = SceneEntity<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SceneEntity<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_ID: flatbuffers::VOffsetT = 8;
+  pub const VT_LIFETIME: flatbuffers::VOffsetT = 10;
+  pub const VT_FRAME_LOCKED: flatbuffers::VOffsetT = 12;
+  pub const VT_METADATA: flatbuffers::VOffsetT = 14;
+  pub const VT_ARROWS: flatbuffers::VOffsetT = 16;
+  pub const VT_CUBES: flatbuffers::VOffsetT = 18;
+  pub const VT_SPHERES: flatbuffers::VOffsetT = 20;
+  pub const VT_CYLINDERS: flatbuffers::VOffsetT = 22;
+  pub const VT_LINES: flatbuffers::VOffsetT = 24;
+  pub const VT_TRIANGLES: flatbuffers::VOffsetT = 26;
+  pub const VT_TEXTS: flatbuffers::VOffsetT = 28;
+  pub const VT_MODELS: flatbuffers::VOffsetT = 30;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SceneEntity { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SceneEntityArgs<'args>
+  ) -> flatbuffers::WIPOffset<SceneEntity<'bldr>> {
+    let mut builder = SceneEntityBuilder::new(_fbb);
+    if let Some(x) = args.models { builder.add_models(x); }
+    if let Some(x) = args.texts { builder.add_texts(x); }
+    if let Some(x) = args.triangles { builder.add_triangles(x); }
+    if let Some(x) = args.lines { builder.add_lines(x); }
+    if let Some(x) = args.cylinders { builder.add_cylinders(x); }
+    if let Some(x) = args.spheres { builder.add_spheres(x); }
+    if let Some(x) = args.cubes { builder.add_cubes(x); }
+    if let Some(x) = args.arrows { builder.add_arrows(x); }
+    if let Some(x) = args.metadata { builder.add_metadata(x); }
+    if let Some(x) = args.lifetime { builder.add_lifetime(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    if let Some(x) = args.frame_id { builder.add_frame_id(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.add_frame_locked(args.frame_locked);
+    builder.finish()
+  }
+
+
+  /// Timestamp of the entity
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(SceneEntity::VT_TIMESTAMP, None)}
+  }
+  /// Frame of reference
+  #[inline]
+  pub fn frame_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SceneEntity::VT_FRAME_ID, None)}
+  }
+  /// Identifier for the entity. A entity will replace any prior entity on the same topic with the same `id`.
+  #[inline]
+  pub fn id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SceneEntity::VT_ID, None)}
+  }
+  /// Length of time (relative to `timestamp`) after which the entity should be automatically removed. Zero value indicates the entity should remain visible until it is replaced or deleted.
+  #[inline]
+  pub fn lifetime(&self) -> Option<&'a Duration> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Duration>(SceneEntity::VT_LIFETIME, None)}
+  }
+  /// Whether the entity should keep its location in the fixed frame (false) or follow the frame specified in `frame_id` as it moves relative to the fixed frame (true)
+  #[inline]
+  pub fn frame_locked(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SceneEntity::VT_FRAME_LOCKED, Some(false)).unwrap()}
+  }
+  /// Additional user-provided metadata associated with the entity. Keys must be unique.
+  #[inline]
+  pub fn metadata(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValuePair<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValuePair>>>>(SceneEntity::VT_METADATA, None)}
+  }
+  /// Arrow primitives
+  #[inline]
+  pub fn arrows(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ArrowPrimitive<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ArrowPrimitive>>>>(SceneEntity::VT_ARROWS, None)}
+  }
+  /// Cube primitives
+  #[inline]
+  pub fn cubes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CubePrimitive<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CubePrimitive>>>>(SceneEntity::VT_CUBES, None)}
+  }
+  /// Sphere primitives
+  #[inline]
+  pub fn spheres(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SpherePrimitive<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SpherePrimitive>>>>(SceneEntity::VT_SPHERES, None)}
+  }
+  /// Cylinder primitives
+  #[inline]
+  pub fn cylinders(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CylinderPrimitive<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CylinderPrimitive>>>>(SceneEntity::VT_CYLINDERS, None)}
+  }
+  /// Line primitives
+  #[inline]
+  pub fn lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LinePrimitive<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LinePrimitive>>>>(SceneEntity::VT_LINES, None)}
+  }
+  /// Triangle list primitives
+  #[inline]
+  pub fn triangles(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TriangleListPrimitive<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TriangleListPrimitive>>>>(SceneEntity::VT_TRIANGLES, None)}
+  }
+  /// Text primitives
+  #[inline]
+  pub fn texts(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextPrimitive<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextPrimitive>>>>(SceneEntity::VT_TEXTS, None)}
+  }
+  /// Model primitives
+  #[inline]
+  pub fn models(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ModelPrimitive<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ModelPrimitive>>>>(SceneEntity::VT_MODELS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SceneEntity<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("frame_id", Self::VT_FRAME_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
+     .visit_field::<Duration>("lifetime", Self::VT_LIFETIME, false)?
+     .visit_field::<bool>("frame_locked", Self::VT_FRAME_LOCKED, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValuePair>>>>("metadata", Self::VT_METADATA, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ArrowPrimitive>>>>("arrows", Self::VT_ARROWS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CubePrimitive>>>>("cubes", Self::VT_CUBES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SpherePrimitive>>>>("spheres", Self::VT_SPHERES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CylinderPrimitive>>>>("cylinders", Self::VT_CYLINDERS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LinePrimitive>>>>("lines", Self::VT_LINES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TriangleListPrimitive>>>>("triangles", Self::VT_TRIANGLES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TextPrimitive>>>>("texts", Self::VT_TEXTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ModelPrimitive>>>>("models", Self::VT_MODELS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SceneEntityArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub frame_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub lifetime: Option<&'a Duration>,
+    pub frame_locked: bool,
+    pub metadata: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValuePair<'a>>>>>,
+    pub arrows: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ArrowPrimitive<'a>>>>>,
+    pub cubes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CubePrimitive<'a>>>>>,
+    pub spheres: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SpherePrimitive<'a>>>>>,
+    pub cylinders: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CylinderPrimitive<'a>>>>>,
+    pub lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LinePrimitive<'a>>>>>,
+    pub triangles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TriangleListPrimitive<'a>>>>>,
+    pub texts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TextPrimitive<'a>>>>>,
+    pub models: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ModelPrimitive<'a>>>>>,
+}
+impl<'a> Default for SceneEntityArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SceneEntityArgs {
+      timestamp: None,
+      frame_id: None,
+      id: None,
+      lifetime: None,
+      frame_locked: false,
+      metadata: None,
+      arrows: None,
+      cubes: None,
+      spheres: None,
+      cylinders: None,
+      lines: None,
+      triangles: None,
+      texts: None,
+      models: None,
+    }
+  }
+}
+
+pub struct SceneEntityBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SceneEntityBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(SceneEntity::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_frame_id(&mut self, frame_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_FRAME_ID, frame_id);
+  }
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_ID, id);
+  }
+  #[inline]
+  pub fn add_lifetime(&mut self, lifetime: &Duration) {
+    self.fbb_.push_slot_always::<&Duration>(SceneEntity::VT_LIFETIME, lifetime);
+  }
+  #[inline]
+  pub fn add_frame_locked(&mut self, frame_locked: bool) {
+    self.fbb_.push_slot::<bool>(SceneEntity::VT_FRAME_LOCKED, frame_locked, false);
+  }
+  #[inline]
+  pub fn add_metadata(&mut self, metadata: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<KeyValuePair<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_METADATA, metadata);
+  }
+  #[inline]
+  pub fn add_arrows(&mut self, arrows: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ArrowPrimitive<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_ARROWS, arrows);
+  }
+  #[inline]
+  pub fn add_cubes(&mut self, cubes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CubePrimitive<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_CUBES, cubes);
+  }
+  #[inline]
+  pub fn add_spheres(&mut self, spheres: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SpherePrimitive<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_SPHERES, spheres);
+  }
+  #[inline]
+  pub fn add_cylinders(&mut self, cylinders: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CylinderPrimitive<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_CYLINDERS, cylinders);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LinePrimitive<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_LINES, lines);
+  }
+  #[inline]
+  pub fn add_triangles(&mut self, triangles: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TriangleListPrimitive<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_TRIANGLES, triangles);
+  }
+  #[inline]
+  pub fn add_texts(&mut self, texts: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TextPrimitive<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_TEXTS, texts);
+  }
+  #[inline]
+  pub fn add_models(&mut self, models: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ModelPrimitive<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneEntity::VT_MODELS, models);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SceneEntityBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SceneEntityBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SceneEntity<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SceneEntity<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SceneEntity");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("frame_id", &self.frame_id());
+      ds.field("id", &self.id());
+      ds.field("lifetime", &self.lifetime());
+      ds.field("frame_locked", &self.frame_locked());
+      ds.field("metadata", &self.metadata());
+      ds.field("arrows", &self.arrows());
+      ds.field("cubes", &self.cubes());
+      ds.field("spheres", &self.spheres());
+      ds.field("cylinders", &self.cylinders());
+      ds.field("lines", &self.lines());
+      ds.field("triangles", &self.triangles());
+      ds.field("texts", &self.texts());
+      ds.field("models", &self.models());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `SceneEntity`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_scene_entity_unchecked`.
+pub fn root_as_scene_entity(buf: &[u8]) -> Result<SceneEntity, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<SceneEntity>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `SceneEntity` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_scene_entity_unchecked`.
+pub fn size_prefixed_root_as_scene_entity(buf: &[u8]) -> Result<SceneEntity, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<SceneEntity>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `SceneEntity` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_scene_entity_unchecked`.
+pub fn root_as_scene_entity_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SceneEntity<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<SceneEntity<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `SceneEntity` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_scene_entity_unchecked`.
+pub fn size_prefixed_root_as_scene_entity_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SceneEntity<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<SceneEntity<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a SceneEntity and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `SceneEntity`.
+pub unsafe fn root_as_scene_entity_unchecked(buf: &[u8]) -> SceneEntity {
+  flatbuffers::root_unchecked::<SceneEntity>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed SceneEntity and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `SceneEntity`.
+pub unsafe fn size_prefixed_root_as_scene_entity_unchecked(buf: &[u8]) -> SceneEntity {
+  flatbuffers::size_prefixed_root_unchecked::<SceneEntity>(buf)
+}
+#[inline]
+pub fn finish_scene_entity_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<SceneEntity<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_scene_entity_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<SceneEntity<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
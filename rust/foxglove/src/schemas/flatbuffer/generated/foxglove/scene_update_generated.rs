@@ -0,0 +1,199 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum SceneUpdateOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// An update to the entities displayed in a 3D scene
+pub struct SceneUpdate<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SceneUpdate<'a> {
+  type Inner = SceneUpdate<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SceneUpdate<'a> {
+  pub const VT_DELETIONS: flatbuffers::VOffsetT = 4;
+  pub const VT_ENTITIES: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SceneUpdate { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SceneUpdateArgs<'args>
+  ) -> flatbuffers::WIPOffset<SceneUpdate<'bldr>> {
+    let mut builder = SceneUpdateBuilder::new(_fbb);
+    if let Some(x) = args.entities { builder.add_entities(x); }
+    if let Some(x) = args.deletions { builder.add_deletions(x); }
+    builder.finish()
+  }
+
+
+  /// Scene entities to delete
+  #[inline]
+  pub fn deletions(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SceneEntityDeletion<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SceneEntityDeletion>>>>(SceneUpdate::VT_DELETIONS, None)}
+  }
+  /// Scene entities to add or replace
+  #[inline]
+  pub fn entities(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SceneEntity<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SceneEntity>>>>(SceneUpdate::VT_ENTITIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SceneUpdate<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SceneEntityDeletion>>>>("deletions", Self::VT_DELETIONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SceneEntity>>>>("entities", Self::VT_ENTITIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SceneUpdateArgs<'a> {
+    pub deletions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SceneEntityDeletion<'a>>>>>,
+    pub entities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SceneEntity<'a>>>>>,
+}
+impl<'a> Default for SceneUpdateArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SceneUpdateArgs {
+      deletions: None,
+      entities: None,
+    }
+  }
+}
+
+pub struct SceneUpdateBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SceneUpdateBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_deletions(&mut self, deletions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SceneEntityDeletion<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneUpdate::VT_DELETIONS, deletions);
+  }
+  #[inline]
+  pub fn add_entities(&mut self, entities: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SceneEntity<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SceneUpdate::VT_ENTITIES, entities);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SceneUpdateBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SceneUpdateBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SceneUpdate<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SceneUpdate<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SceneUpdate");
+      ds.field("deletions", &self.deletions());
+      ds.field("entities", &self.entities());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `SceneUpdate`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_scene_update_unchecked`.
+pub fn root_as_scene_update(buf: &[u8]) -> Result<SceneUpdate, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<SceneUpdate>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `SceneUpdate` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_scene_update_unchecked`.
+pub fn size_prefixed_root_as_scene_update(buf: &[u8]) -> Result<SceneUpdate, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<SceneUpdate>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `SceneUpdate` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_scene_update_unchecked`.
+pub fn root_as_scene_update_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SceneUpdate<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<SceneUpdate<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `SceneUpdate` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_scene_update_unchecked`.
+pub fn size_prefixed_root_as_scene_update_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SceneUpdate<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<SceneUpdate<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a SceneUpdate and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `SceneUpdate`.
+pub unsafe fn root_as_scene_update_unchecked(buf: &[u8]) -> SceneUpdate {
+  flatbuffers::root_unchecked::<SceneUpdate>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed SceneUpdate and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `SceneUpdate`.
+pub unsafe fn size_prefixed_root_as_scene_update_unchecked(buf: &[u8]) -> SceneUpdate {
+  flatbuffers::size_prefixed_root_unchecked::<SceneUpdate>(buf)
+}
+#[inline]
+pub fn finish_scene_update_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<SceneUpdate<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_scene_update_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<SceneUpdate<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,217 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum SpherePrimitiveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A primitive representing a sphere or ellipsoid
+pub struct SpherePrimitive<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpherePrimitive<'a> {
+  type Inner = SpherePrimitive<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpherePrimitive<'a> {
+  pub const VT_POSE: flatbuffers::VOffsetT = 4;
+  pub const VT_SIZE: flatbuffers::VOffsetT = 6;
+  pub const VT_COLOR: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpherePrimitive { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SpherePrimitiveArgs<'args>
+  ) -> flatbuffers::WIPOffset<SpherePrimitive<'bldr>> {
+    let mut builder = SpherePrimitiveBuilder::new(_fbb);
+    if let Some(x) = args.color { builder.add_color(x); }
+    if let Some(x) = args.size { builder.add_size(x); }
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    builder.finish()
+  }
+
+
+  /// Position of the center of the sphere and orientation of the sphere
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(SpherePrimitive::VT_POSE, None)}
+  }
+  /// Size (diameter) of the sphere along each axis
+  #[inline]
+  pub fn size(&self) -> Option<Vector3<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Vector3>>(SpherePrimitive::VT_SIZE, None)}
+  }
+  /// Color of the sphere
+  #[inline]
+  pub fn color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(SpherePrimitive::VT_COLOR, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SpherePrimitive<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Vector3>>("size", Self::VT_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("color", Self::VT_COLOR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpherePrimitiveArgs<'a> {
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub size: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+    pub color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+}
+impl<'a> Default for SpherePrimitiveArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpherePrimitiveArgs {
+      pose: None,
+      size: None,
+      color: None,
+    }
+  }
+}
+
+pub struct SpherePrimitiveBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SpherePrimitiveBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(SpherePrimitive::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_size(&mut self, size: flatbuffers::WIPOffset<Vector3<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Vector3>>(SpherePrimitive::VT_SIZE, size);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(SpherePrimitive::VT_COLOR, color);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SpherePrimitiveBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SpherePrimitiveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpherePrimitive<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpherePrimitive<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpherePrimitive");
+      ds.field("pose", &self.pose());
+      ds.field("size", &self.size());
+      ds.field("color", &self.color());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `SpherePrimitive`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_sphere_primitive_unchecked`.
+pub fn root_as_sphere_primitive(buf: &[u8]) -> Result<SpherePrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<SpherePrimitive>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `SpherePrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_sphere_primitive_unchecked`.
+pub fn size_prefixed_root_as_sphere_primitive(buf: &[u8]) -> Result<SpherePrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<SpherePrimitive>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `SpherePrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_sphere_primitive_unchecked`.
+pub fn root_as_sphere_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SpherePrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<SpherePrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `SpherePrimitive` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_sphere_primitive_unchecked`.
+pub fn size_prefixed_root_as_sphere_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SpherePrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<SpherePrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a SpherePrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `SpherePrimitive`.
+pub unsafe fn root_as_sphere_primitive_unchecked(buf: &[u8]) -> SpherePrimitive {
+  flatbuffers::root_unchecked::<SpherePrimitive>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed SpherePrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `SpherePrimitive`.
+pub unsafe fn size_prefixed_root_as_sphere_primitive_unchecked(buf: &[u8]) -> SpherePrimitive {
+  flatbuffers::size_prefixed_root_unchecked::<SpherePrimitive>(buf)
+}
+#[inline]
+pub fn finish_sphere_primitive_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<SpherePrimitive<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_sphere_primitive_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<SpherePrimitive<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,272 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum TextAnnotationOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A text label on a 2D image
+pub struct TextAnnotation<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TextAnnotation<'a> {
+  type Inner = TextAnnotation<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TextAnnotation<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_POSITION: flatbuffers::VOffsetT = 6;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 8;
+  pub const VT_FONT_SIZE: flatbuffers::VOffsetT = 10;
+  pub const VT_TEXT_COLOR: flatbuffers::VOffsetT = 12;
+  pub const VT_BACKGROUND_COLOR: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TextAnnotation { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TextAnnotationArgs<'args>
+  ) -> flatbuffers::WIPOffset<TextAnnotation<'bldr>> {
+    let mut builder = TextAnnotationBuilder::new(_fbb);
+    builder.add_font_size(args.font_size);
+    if let Some(x) = args.background_color { builder.add_background_color(x); }
+    if let Some(x) = args.text_color { builder.add_text_color(x); }
+    if let Some(x) = args.text { builder.add_text(x); }
+    if let Some(x) = args.position { builder.add_position(x); }
+    if let Some(x) = args.timestamp { builder.add_timestamp(x); }
+    builder.finish()
+  }
+
+
+  /// Timestamp of annotation
+  #[inline]
+  pub fn timestamp(&self) -> Option<&'a Time> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Time>(TextAnnotation::VT_TIMESTAMP, None)}
+  }
+  /// Bottom-left origin of the text label in 2D image coordinates (pixels).
+  /// The coordinate uses the top-left corner of the top-left pixel of the image as the origin.
+  #[inline]
+  pub fn position(&self) -> Option<Point2<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Point2>>(TextAnnotation::VT_POSITION, None)}
+  }
+  /// Text to display
+  #[inline]
+  pub fn text(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TextAnnotation::VT_TEXT, None)}
+  }
+  /// Font size in pixels
+  #[inline]
+  pub fn font_size(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(TextAnnotation::VT_FONT_SIZE, Some(12.0)).unwrap()}
+  }
+  /// Text color
+  #[inline]
+  pub fn text_color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(TextAnnotation::VT_TEXT_COLOR, None)}
+  }
+  /// Background fill color
+  #[inline]
+  pub fn background_color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(TextAnnotation::VT_BACKGROUND_COLOR, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TextAnnotation<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Time>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Point2>>("position", Self::VT_POSITION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .visit_field::<f64>("font_size", Self::VT_FONT_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("text_color", Self::VT_TEXT_COLOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("background_color", Self::VT_BACKGROUND_COLOR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TextAnnotationArgs<'a> {
+    pub timestamp: Option<&'a Time>,
+    pub position: Option<flatbuffers::WIPOffset<Point2<'a>>>,
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub font_size: f64,
+    pub text_color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+    pub background_color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+}
+impl<'a> Default for TextAnnotationArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TextAnnotationArgs {
+      timestamp: None,
+      position: None,
+      text: None,
+      font_size: 12.0,
+      text_color: None,
+      background_color: None,
+    }
+  }
+}
+
+pub struct TextAnnotationBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TextAnnotationBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: &Time) {
+    self.fbb_.push_slot_always::<&Time>(TextAnnotation::VT_TIMESTAMP, timestamp);
+  }
+  #[inline]
+  pub fn add_position(&mut self, position: flatbuffers::WIPOffset<Point2<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Point2>>(TextAnnotation::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TextAnnotation::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn add_font_size(&mut self, font_size: f64) {
+    self.fbb_.push_slot::<f64>(TextAnnotation::VT_FONT_SIZE, font_size, 12.0);
+  }
+  #[inline]
+  pub fn add_text_color(&mut self, text_color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(TextAnnotation::VT_TEXT_COLOR, text_color);
+  }
+  #[inline]
+  pub fn add_background_color(&mut self, background_color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(TextAnnotation::VT_BACKGROUND_COLOR, background_color);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TextAnnotationBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TextAnnotationBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TextAnnotation<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TextAnnotation<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TextAnnotation");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("position", &self.position());
+      ds.field("text", &self.text());
+      ds.field("font_size", &self.font_size());
+      ds.field("text_color", &self.text_color());
+      ds.field("background_color", &self.background_color());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `TextAnnotation`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_text_annotation_unchecked`.
+pub fn root_as_text_annotation(buf: &[u8]) -> Result<TextAnnotation, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<TextAnnotation>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `TextAnnotation` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_text_annotation_unchecked`.
+pub fn size_prefixed_root_as_text_annotation(buf: &[u8]) -> Result<TextAnnotation, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<TextAnnotation>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `TextAnnotation` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_text_annotation_unchecked`.
+pub fn root_as_text_annotation_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TextAnnotation<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<TextAnnotation<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `TextAnnotation` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_text_annotation_unchecked`.
+pub fn size_prefixed_root_as_text_annotation_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TextAnnotation<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<TextAnnotation<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a TextAnnotation and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `TextAnnotation`.
+pub unsafe fn root_as_text_annotation_unchecked(buf: &[u8]) -> TextAnnotation {
+  flatbuffers::root_unchecked::<TextAnnotation>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed TextAnnotation and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `TextAnnotation`.
+pub unsafe fn size_prefixed_root_as_text_annotation_unchecked(buf: &[u8]) -> TextAnnotation {
+  flatbuffers::size_prefixed_root_unchecked::<TextAnnotation>(buf)
+}
+#[inline]
+pub fn finish_text_annotation_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<TextAnnotation<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_text_annotation_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<TextAnnotation<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,271 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum TextPrimitiveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A primitive representing a text label
+pub struct TextPrimitive<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TextPrimitive<'a> {
+  type Inner = TextPrimitive<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TextPrimitive<'a> {
+  pub const VT_POSE: flatbuffers::VOffsetT = 4;
+  pub const VT_BILLBOARD: flatbuffers::VOffsetT = 6;
+  pub const VT_FONT_SIZE: flatbuffers::VOffsetT = 8;
+  pub const VT_SCALE_INVARIANT: flatbuffers::VOffsetT = 10;
+  pub const VT_COLOR: flatbuffers::VOffsetT = 12;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TextPrimitive { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TextPrimitiveArgs<'args>
+  ) -> flatbuffers::WIPOffset<TextPrimitive<'bldr>> {
+    let mut builder = TextPrimitiveBuilder::new(_fbb);
+    builder.add_font_size(args.font_size);
+    if let Some(x) = args.text { builder.add_text(x); }
+    if let Some(x) = args.color { builder.add_color(x); }
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    builder.add_scale_invariant(args.scale_invariant);
+    builder.add_billboard(args.billboard);
+    builder.finish()
+  }
+
+
+  /// Position of the center of the text box and orientation of the text. Identity orientation means the text is oriented in the xy-plane and flows from -x to +x.
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(TextPrimitive::VT_POSE, None)}
+  }
+  /// Whether the text should respect `pose.orientation` (false) or always face the camera (true)
+  #[inline]
+  pub fn billboard(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TextPrimitive::VT_BILLBOARD, Some(false)).unwrap()}
+  }
+  /// Font size (height of one line of text)
+  #[inline]
+  pub fn font_size(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(TextPrimitive::VT_FONT_SIZE, Some(0.0)).unwrap()}
+  }
+  /// Indicates whether `font_size` is a fixed size in screen pixels (true), or specified in world coordinates and scales with distance from the camera (false)
+  #[inline]
+  pub fn scale_invariant(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TextPrimitive::VT_SCALE_INVARIANT, Some(false)).unwrap()}
+  }
+  /// Color of the text
+  #[inline]
+  pub fn color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(TextPrimitive::VT_COLOR, None)}
+  }
+  /// Text
+  #[inline]
+  pub fn text(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TextPrimitive::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TextPrimitive<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<bool>("billboard", Self::VT_BILLBOARD, false)?
+     .visit_field::<f64>("font_size", Self::VT_FONT_SIZE, false)?
+     .visit_field::<bool>("scale_invariant", Self::VT_SCALE_INVARIANT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("color", Self::VT_COLOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TextPrimitiveArgs<'a> {
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub billboard: bool,
+    pub font_size: f64,
+    pub scale_invariant: bool,
+    pub color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for TextPrimitiveArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TextPrimitiveArgs {
+      pose: None,
+      billboard: false,
+      font_size: 0.0,
+      scale_invariant: false,
+      color: None,
+      text: None,
+    }
+  }
+}
+
+pub struct TextPrimitiveBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TextPrimitiveBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(TextPrimitive::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_billboard(&mut self, billboard: bool) {
+    self.fbb_.push_slot::<bool>(TextPrimitive::VT_BILLBOARD, billboard, false);
+  }
+  #[inline]
+  pub fn add_font_size(&mut self, font_size: f64) {
+    self.fbb_.push_slot::<f64>(TextPrimitive::VT_FONT_SIZE, font_size, 0.0);
+  }
+  #[inline]
+  pub fn add_scale_invariant(&mut self, scale_invariant: bool) {
+    self.fbb_.push_slot::<bool>(TextPrimitive::VT_SCALE_INVARIANT, scale_invariant, false);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(TextPrimitive::VT_COLOR, color);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TextPrimitive::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TextPrimitiveBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TextPrimitiveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TextPrimitive<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TextPrimitive<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TextPrimitive");
+      ds.field("pose", &self.pose());
+      ds.field("billboard", &self.billboard());
+      ds.field("font_size", &self.font_size());
+      ds.field("scale_invariant", &self.scale_invariant());
+      ds.field("color", &self.color());
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `TextPrimitive`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_text_primitive_unchecked`.
+pub fn root_as_text_primitive(buf: &[u8]) -> Result<TextPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<TextPrimitive>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `TextPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_text_primitive_unchecked`.
+pub fn size_prefixed_root_as_text_primitive(buf: &[u8]) -> Result<TextPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<TextPrimitive>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `TextPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_text_primitive_unchecked`.
+pub fn root_as_text_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TextPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<TextPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `TextPrimitive` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_text_primitive_unchecked`.
+pub fn size_prefixed_root_as_text_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TextPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<TextPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a TextPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `TextPrimitive`.
+pub unsafe fn root_as_text_primitive_unchecked(buf: &[u8]) -> TextPrimitive {
+  flatbuffers::root_unchecked::<TextPrimitive>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed TextPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `TextPrimitive`.
+pub unsafe fn size_prefixed_root_as_text_primitive_unchecked(buf: &[u8]) -> TextPrimitive {
+  flatbuffers::size_prefixed_root_unchecked::<TextPrimitive>(buf)
+}
+#[inline]
+pub fn finish_text_primitive_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<TextPrimitive<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_text_primitive_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<TextPrimitive<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
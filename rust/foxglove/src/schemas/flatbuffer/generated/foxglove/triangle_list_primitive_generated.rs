@@ -0,0 +1,255 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum TriangleListPrimitiveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A primitive representing a set of triangles or a surface tiled by triangles
+pub struct TriangleListPrimitive<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TriangleListPrimitive<'a> {
+  type Inner = TriangleListPrimitive<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TriangleListPrimitive<'a> {
+  pub const VT_POSE: flatbuffers::VOffsetT = 4;
+  pub const VT_POINTS: flatbuffers::VOffsetT = 6;
+  pub const VT_COLOR: flatbuffers::VOffsetT = 8;
+  pub const VT_COLORS: flatbuffers::VOffsetT = 10;
+  pub const VT_INDICES: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TriangleListPrimitive { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TriangleListPrimitiveArgs<'args>
+  ) -> flatbuffers::WIPOffset<TriangleListPrimitive<'bldr>> {
+    let mut builder = TriangleListPrimitiveBuilder::new(_fbb);
+    if let Some(x) = args.indices { builder.add_indices(x); }
+    if let Some(x) = args.colors { builder.add_colors(x); }
+    if let Some(x) = args.color { builder.add_color(x); }
+    if let Some(x) = args.points { builder.add_points(x); }
+    if let Some(x) = args.pose { builder.add_pose(x); }
+    builder.finish()
+  }
+
+
+  /// Origin of triangles relative to reference frame
+  #[inline]
+  pub fn pose(&self) -> Option<Pose<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Pose>>(TriangleListPrimitive::VT_POSE, None)}
+  }
+  /// Vertices to use for triangles, interpreted as a list of triples (0-1-2, 3-4-5, ...)
+  #[inline]
+  pub fn points(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Point3<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Point3>>>>(TriangleListPrimitive::VT_POINTS, None)}
+  }
+  /// Solid color to use for the whole shape. One of `color` or `colors` must be provided.
+  #[inline]
+  pub fn color(&self) -> Option<Color<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Color>>(TriangleListPrimitive::VT_COLOR, None)}
+  }
+  /// Per-vertex colors (if specified, must have the same length as `points`). One of `color` or `colors` must be provided.
+  #[inline]
+  pub fn colors(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Color<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Color>>>>(TriangleListPrimitive::VT_COLORS, None)}
+  }
+  /// Indices into the `points` and `colors` attribute arrays, which can be used to avoid duplicating attribute data.
+  ///
+  /// If omitted or empty, indexing will not be used. This default behavior is equivalent to specifying [0, 1, ..., N-1] for the indices (where N is the number of `points` provided).
+  #[inline]
+  pub fn indices(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(TriangleListPrimitive::VT_INDICES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TriangleListPrimitive<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Pose>>("pose", Self::VT_POSE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Point3>>>>("points", Self::VT_POINTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Color>>("color", Self::VT_COLOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Color>>>>("colors", Self::VT_COLORS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("indices", Self::VT_INDICES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TriangleListPrimitiveArgs<'a> {
+    pub pose: Option<flatbuffers::WIPOffset<Pose<'a>>>,
+    pub points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Point3<'a>>>>>,
+    pub color: Option<flatbuffers::WIPOffset<Color<'a>>>,
+    pub colors: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Color<'a>>>>>,
+    pub indices: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for TriangleListPrimitiveArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TriangleListPrimitiveArgs {
+      pose: None,
+      points: None,
+      color: None,
+      colors: None,
+      indices: None,
+    }
+  }
+}
+
+pub struct TriangleListPrimitiveBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TriangleListPrimitiveBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_pose(&mut self, pose: flatbuffers::WIPOffset<Pose<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Pose>>(TriangleListPrimitive::VT_POSE, pose);
+  }
+  #[inline]
+  pub fn add_points(&mut self, points: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Point3<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TriangleListPrimitive::VT_POINTS, points);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: flatbuffers::WIPOffset<Color<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Color>>(TriangleListPrimitive::VT_COLOR, color);
+  }
+  #[inline]
+  pub fn add_colors(&mut self, colors: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Color<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TriangleListPrimitive::VT_COLORS, colors);
+  }
+  #[inline]
+  pub fn add_indices(&mut self, indices: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TriangleListPrimitive::VT_INDICES, indices);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TriangleListPrimitiveBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TriangleListPrimitiveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TriangleListPrimitive<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TriangleListPrimitive<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TriangleListPrimitive");
+      ds.field("pose", &self.pose());
+      ds.field("points", &self.points());
+      ds.field("color", &self.color());
+      ds.field("colors", &self.colors());
+      ds.field("indices", &self.indices());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `TriangleListPrimitive`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_triangle_list_primitive_unchecked`.
+pub fn root_as_triangle_list_primitive(buf: &[u8]) -> Result<TriangleListPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<TriangleListPrimitive>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `TriangleListPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_triangle_list_primitive_unchecked`.
+pub fn size_prefixed_root_as_triangle_list_primitive(buf: &[u8]) -> Result<TriangleListPrimitive, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<TriangleListPrimitive>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `TriangleListPrimitive` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_triangle_list_primitive_unchecked`.
+pub fn root_as_triangle_list_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TriangleListPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<TriangleListPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `TriangleListPrimitive` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_triangle_list_primitive_unchecked`.
+pub fn size_prefixed_root_as_triangle_list_primitive_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TriangleListPrimitive<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<TriangleListPrimitive<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a TriangleListPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `TriangleListPrimitive`.
+pub unsafe fn root_as_triangle_list_primitive_unchecked(buf: &[u8]) -> TriangleListPrimitive {
+  flatbuffers::root_unchecked::<TriangleListPrimitive>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed TriangleListPrimitive and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `TriangleListPrimitive`.
+pub unsafe fn size_prefixed_root_as_triangle_list_primitive_unchecked(buf: &[u8]) -> TriangleListPrimitive {
+  flatbuffers::size_prefixed_root_unchecked::<TriangleListPrimitive>(buf)
+}
+#[inline]
+pub fn finish_triangle_list_primitive_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<TriangleListPrimitive<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_triangle_list_primitive_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<TriangleListPrimitive<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
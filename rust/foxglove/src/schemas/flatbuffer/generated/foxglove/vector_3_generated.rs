@@ -0,0 +1,217 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+extern crate alloc;
+extern crate flatbuffers;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::mem;
+use core::cmp::Ordering;
+use self::flatbuffers::{EndianScalar, Follow};
+use super::*;
+pub enum Vector3Offset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A vector in 3D space that represents a direction only
+pub struct Vector3<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Vector3<'a> {
+  type Inner = Vector3<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Vector3<'a> {
+  pub const VT_X: flatbuffers::VOffsetT = 4;
+  pub const VT_Y: flatbuffers::VOffsetT = 6;
+  pub const VT_Z: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Vector3 { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args Vector3Args
+  ) -> flatbuffers::WIPOffset<Vector3<'bldr>> {
+    let mut builder = Vector3Builder::new(_fbb);
+    builder.add_z(args.z);
+    builder.add_y(args.y);
+    builder.add_x(args.x);
+    builder.finish()
+  }
+
+
+  /// x coordinate length
+  #[inline]
+  pub fn x(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Vector3::VT_X, Some(1.0)).unwrap()}
+  }
+  /// y coordinate length
+  #[inline]
+  pub fn y(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Vector3::VT_Y, Some(1.0)).unwrap()}
+  }
+  /// z coordinate length
+  #[inline]
+  pub fn z(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Vector3::VT_Z, Some(1.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Vector3<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f64>("x", Self::VT_X, false)?
+     .visit_field::<f64>("y", Self::VT_Y, false)?
+     .visit_field::<f64>("z", Self::VT_Z, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct Vector3Args {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+impl<'a> Default for Vector3Args {
+  #[inline]
+  fn default() -> Self {
+    Vector3Args {
+      x: 1.0,
+      y: 1.0,
+      z: 1.0,
+    }
+  }
+}
+
+pub struct Vector3Builder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> Vector3Builder<'a, 'b> {
+  #[inline]
+  pub fn add_x(&mut self, x: f64) {
+    self.fbb_.push_slot::<f64>(Vector3::VT_X, x, 1.0);
+  }
+  #[inline]
+  pub fn add_y(&mut self, y: f64) {
+    self.fbb_.push_slot::<f64>(Vector3::VT_Y, y, 1.0);
+  }
+  #[inline]
+  pub fn add_z(&mut self, z: f64) {
+    self.fbb_.push_slot::<f64>(Vector3::VT_Z, z, 1.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Vector3Builder<'a, 'b> {
+    let start = _fbb.start_table();
+    Vector3Builder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Vector3<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Vector3<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Vector3");
+      ds.field("x", &self.x());
+      ds.field("y", &self.y());
+      ds.field("z", &self.z());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `Vector3`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_vector_3_unchecked`.
+pub fn root_as_vector_3(buf: &[u8]) -> Result<Vector3, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<Vector3>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `Vector3` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_vector_3_unchecked`.
+pub fn size_prefixed_root_as_vector_3(buf: &[u8]) -> Result<Vector3, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<Vector3>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `Vector3` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_vector_3_unchecked`.
+pub fn root_as_vector_3_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Vector3<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<Vector3<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `Vector3` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_vector_3_unchecked`.
+pub fn size_prefixed_root_as_vector_3_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Vector3<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<Vector3<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a Vector3 and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `Vector3`.
+pub unsafe fn root_as_vector_3_unchecked(buf: &[u8]) -> Vector3 {
+  flatbuffers::root_unchecked::<Vector3>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed Vector3 and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `Vector3`.
+pub unsafe fn size_prefixed_root_as_vector_3_unchecked(buf: &[u8]) -> Vector3 {
+  flatbuffers::size_prefixed_root_unchecked::<Vector3>(buf)
+}
+#[inline]
+pub fn finish_vector_3_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Vector3<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_vector_3_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Vector3<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,99 @@
+// Automatically generated by the Flatbuffers compiler. Do not modify.
+// @generated
+pub mod foxglove {
+  use super::*;
+  mod arrow_primitive_generated;
+  pub use self::arrow_primitive_generated::*;
+  mod byte_vector_generated;
+  pub use self::byte_vector_generated::*;
+  mod camera_calibration_generated;
+  pub use self::camera_calibration_generated::*;
+  mod circle_annotation_generated;
+  pub use self::circle_annotation_generated::*;
+  mod color_generated;
+  pub use self::color_generated::*;
+  mod compressed_image_generated;
+  pub use self::compressed_image_generated::*;
+  mod compressed_video_generated;
+  pub use self::compressed_video_generated::*;
+  mod cube_primitive_generated;
+  pub use self::cube_primitive_generated::*;
+  mod cylinder_primitive_generated;
+  pub use self::cylinder_primitive_generated::*;
+  mod duration_generated;
+  pub use self::duration_generated::*;
+  mod frame_transform_generated;
+  pub use self::frame_transform_generated::*;
+  mod frame_transforms_generated;
+  pub use self::frame_transforms_generated::*;
+  mod geo_json_generated;
+  pub use self::geo_json_generated::*;
+  mod grid_generated;
+  pub use self::grid_generated::*;
+  mod image_annotations_generated;
+  pub use self::image_annotations_generated::*;
+  mod key_value_pair_generated;
+  pub use self::key_value_pair_generated::*;
+  mod laser_scan_generated;
+  pub use self::laser_scan_generated::*;
+  mod line_primitive_generated;
+  pub use self::line_primitive_generated::*;
+  mod line_type_generated;
+  pub use self::line_type_generated::*;
+  mod location_fix_generated;
+  pub use self::location_fix_generated::*;
+  mod log_generated;
+  pub use self::log_generated::*;
+  mod log_level_generated;
+  pub use self::log_level_generated::*;
+  mod model_primitive_generated;
+  pub use self::model_primitive_generated::*;
+  mod numeric_type_generated;
+  pub use self::numeric_type_generated::*;
+  mod packed_element_field_generated;
+  pub use self::packed_element_field_generated::*;
+  mod point_2_generated;
+  pub use self::point_2_generated::*;
+  mod point_3_generated;
+  pub use self::point_3_generated::*;
+  mod point_cloud_generated;
+  pub use self::point_cloud_generated::*;
+  mod points_annotation_generated;
+  pub use self::points_annotation_generated::*;
+  mod points_annotation_type_generated;
+  pub use self::points_annotation_type_generated::*;
+  mod pose_generated;
+  pub use self::pose_generated::*;
+  mod pose_in_frame_generated;
+  pub use self::pose_in_frame_generated::*;
+  mod poses_in_frame_generated;
+  pub use self::poses_in_frame_generated::*;
+  mod position_covariance_type_generated;
+  pub use self::position_covariance_type_generated::*;
+  mod quaternion_generated;
+  pub use self::quaternion_generated::*;
+  mod raw_image_generated;
+  pub use self::raw_image_generated::*;
+  mod scene_entity_deletion_generated;
+  pub use self::scene_entity_deletion_generated::*;
+  mod scene_entity_deletion_type_generated;
+  pub use self::scene_entity_deletion_type_generated::*;
+  mod scene_entity_generated;
+  pub use self::scene_entity_generated::*;
+  mod scene_update_generated;
+  pub use self::scene_update_generated::*;
+  mod sphere_primitive_generated;
+  pub use self::sphere_primitive_generated::*;
+  mod text_annotation_generated;
+  pub use self::text_annotation_generated::*;
+  mod text_primitive_generated;
+  pub use self::text_primitive_generated::*;
+  mod time_generated;
+  pub use self::time_generated::*;
+  mod triangle_list_primitive_generated;
+  pub use self::triangle_list_primitive_generated::*;
+  mod vector_2_generated;
+  pub use self::vector_2_generated::*;
+  mod vector_3_generated;
+  pub use self::vector_3_generated::*;
+} // foxglove
@@ -0,0 +1,148 @@
+//! Conversions between geometry schemas and [`glam`](::glam) types.
+//!
+//! Both the `f64` (`DVec3`, `DQuat`) and `f32` (`Vec3`, `Quat`) types are supported. Converting
+//! to an `f32` type loses precision. Quaternions are normalized when converted to a `DQuat` or
+//! `Quat`, and a zero quaternion becomes the identity rotation.
+//!
+//! A [`Pose`] converts to and from a `DAffine3`. A missing position or orientation is treated as a
+//! zero position or the identity orientation, and any scale in the affine transform is discarded.
+
+use ::glam::{DAffine3, DQuat, DVec3, DVec4, Quat, Vec3};
+
+use super::{Point3, Pose, Quaternion, Vector3};
+
+impl From<DVec3> for Vector3 {
+    fn from(v: DVec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for DVec3 {
+    fn from(v: Vector3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3> for Vector3 {
+    fn from(v: Vec3) -> Self {
+        v.as_dvec3().into()
+    }
+}
+
+impl From<Vector3> for Vec3 {
+    fn from(v: Vector3) -> Self {
+        DVec3::from(v).as_vec3()
+    }
+}
+
+impl From<DVec3> for Point3 {
+    fn from(p: DVec3) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Point3> for DVec3 {
+    fn from(p: Point3) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Vec3> for Point3 {
+    fn from(p: Vec3) -> Self {
+        p.as_dvec3().into()
+    }
+}
+
+impl From<Point3> for Vec3 {
+    fn from(p: Point3) -> Self {
+        DVec3::from(p).as_vec3()
+    }
+}
+
+impl From<DQuat> for Quaternion {
+    fn from(q: DQuat) -> Self {
+        Self::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<Quaternion> for DQuat {
+    fn from(q: Quaternion) -> Self {
+        DVec4::new(q.x, q.y, q.z, q.w)
+            .try_normalize()
+            .map_or(Self::IDENTITY, Self::from_vec4)
+    }
+}
+
+impl From<Quat> for Quaternion {
+    fn from(q: Quat) -> Self {
+        q.as_dquat().into()
+    }
+}
+
+impl From<Quaternion> for Quat {
+    fn from(q: Quaternion) -> Self {
+        DQuat::from(q).as_quat()
+    }
+}
+
+impl From<Pose> for DAffine3 {
+    fn from(pose: Pose) -> Self {
+        let position = pose.position.unwrap_or_default();
+        let orientation = pose.orientation.unwrap_or_else(Quaternion::identity);
+        Self::from_rotation_translation(orientation.into(), position.into())
+    }
+}
+
+impl From<DAffine3> for Pose {
+    fn from(affine: DAffine3) -> Self {
+        let (_, rotation, translation) = affine.to_scale_rotation_translation();
+        Self::new(translation.into(), rotation.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glam_conversions() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(DVec3::from(v), DVec3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vec3::from(v), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(Vec3::new(1.0, 2.0, 3.0)), v);
+        let p = Point3::new(4.0, 5.0, 6.0);
+        assert_eq!(Point3::from(DVec3::from(p)), p);
+
+        let q = Quaternion::from_euler(0.1, 0.2, 0.3);
+        let expected = DQuat::from_euler(::glam::EulerRot::ZYX, 0.3, 0.2, 0.1);
+        assert!(DQuat::from(q).angle_between(expected) < 1e-6);
+        assert_eq!(Quaternion::from(DQuat::from(q)), q);
+
+        let affine = DAffine3::from(Pose::new(v, q));
+        assert!(affine
+            .transform_point3(DVec3::ZERO)
+            .abs_diff_eq(DVec3::new(1.0, 2.0, 3.0), 1e-12));
+        assert_eq!(DAffine3::from(Pose::default()), DAffine3::IDENTITY);
+
+        let pose = Pose::from(DAffine3::from_scale_rotation_translation(
+            DVec3::splat(2.0),
+            DQuat::from(q),
+            DVec3::from(v),
+        ));
+        assert!(DVec3::from(pose.position.unwrap()).abs_diff_eq(DVec3::from(v), 1e-12));
+        assert!(DQuat::from(pose.orientation.unwrap()).angle_between(DQuat::from(q)) < 1e-6);
+    }
+
+    #[test]
+    fn test_glam_normalizes_quaternions() {
+        let q = Quaternion::new(0.0, 0.0, 0.0, 2.0);
+        assert_eq!(DQuat::from(q), DQuat::IDENTITY);
+        assert_eq!(Quat::from(q), Quat::IDENTITY);
+
+        let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+        assert_eq!(DQuat::from(zero), DQuat::IDENTITY);
+        assert_eq!(Quat::from(zero), Quat::IDENTITY);
+        let affine = DAffine3::from(Pose::new(Vector3::default(), zero));
+        assert_eq!(affine, DAffine3::IDENTITY);
+    }
+}
@@ -1,6 +1,7 @@
 // This file is @generated by foxglove-proto-gen
 use crate::schemas::{descriptors, foxglove::*};
-use crate::{Schema, Encode};
+use crate::protobuf::{add_file_descriptors, FileDescriptorProto, ProtobufMessage};
+use crate::{Schema, Encode, Decode};
 use bytes::BufMut;
 
 impl Encode for CameraCalibration {
@@ -25,6 +26,36 @@ impl Encode for CameraCalibration {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for CameraCalibration {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for CameraCalibration {
+    fn type_name() -> String {
+        "foxglove.CameraCalibration".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/CameraCalibration.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::CAMERA_CALIBRATION);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for CircleAnnotation {
     type Error = ::prost::EncodeError;
 
@@ -47,6 +78,36 @@ impl Encode for CircleAnnotation {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for CircleAnnotation {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for CircleAnnotation {
+    fn type_name() -> String {
+        "foxglove.CircleAnnotation".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/CircleAnnotation.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::CIRCLE_ANNOTATION);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for Color {
     type Error = ::prost::EncodeError;
 
@@ -69,6 +130,36 @@ impl Encode for Color {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for Color {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for Color {
+    fn type_name() -> String {
+        "foxglove.Color".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/Color.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::COLOR);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for CompressedImage {
     type Error = ::prost::EncodeError;
 
@@ -91,6 +182,36 @@ impl Encode for CompressedImage {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for CompressedImage {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for CompressedImage {
+    fn type_name() -> String {
+        "foxglove.CompressedImage".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/CompressedImage.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::COMPRESSED_IMAGE);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for CompressedVideo {
     type Error = ::prost::EncodeError;
 
@@ -113,6 +234,36 @@ impl Encode for CompressedVideo {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for CompressedVideo {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for CompressedVideo {
+    fn type_name() -> String {
+        "foxglove.CompressedVideo".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/CompressedVideo.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::COMPRESSED_VIDEO);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for FrameTransform {
     type Error = ::prost::EncodeError;
 
@@ -135,6 +286,36 @@ impl Encode for FrameTransform {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for FrameTransform {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for FrameTransform {
+    fn type_name() -> String {
+        "foxglove.FrameTransform".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/FrameTransform.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::FRAME_TRANSFORM);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for FrameTransforms {
     type Error = ::prost::EncodeError;
 
@@ -157,6 +338,36 @@ impl Encode for FrameTransforms {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for FrameTransforms {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for FrameTransforms {
+    fn type_name() -> String {
+        "foxglove.FrameTransforms".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/FrameTransforms.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::FRAME_TRANSFORMS);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for GeoJson {
     type Error = ::prost::EncodeError;
 
@@ -179,6 +390,36 @@ impl Encode for GeoJson {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for GeoJson {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for GeoJson {
+    fn type_name() -> String {
+        "foxglove.GeoJSON".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/GeoJSON.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::GEO_JSON);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for Grid {
     type Error = ::prost::EncodeError;
 
@@ -201,6 +442,36 @@ impl Encode for Grid {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for Grid {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for Grid {
+    fn type_name() -> String {
+        "foxglove.Grid".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/Grid.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::GRID);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for ImageAnnotations {
     type Error = ::prost::EncodeError;
 
@@ -223,6 +494,36 @@ impl Encode for ImageAnnotations {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for ImageAnnotations {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for ImageAnnotations {
+    fn type_name() -> String {
+        "foxglove.ImageAnnotations".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/ImageAnnotations.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::IMAGE_ANNOTATIONS);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for KeyValuePair {
     type Error = ::prost::EncodeError;
 
@@ -245,6 +546,36 @@ impl Encode for KeyValuePair {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for KeyValuePair {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for KeyValuePair {
+    fn type_name() -> String {
+        "foxglove.KeyValuePair".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/KeyValuePair.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::KEY_VALUE_PAIR);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for LaserScan {
     type Error = ::prost::EncodeError;
 
@@ -267,6 +598,36 @@ impl Encode for LaserScan {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for LaserScan {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for LaserScan {
+    fn type_name() -> String {
+        "foxglove.LaserScan".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/LaserScan.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::LASER_SCAN);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for LocationFix {
     type Error = ::prost::EncodeError;
 
@@ -289,6 +650,36 @@ impl Encode for LocationFix {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for LocationFix {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for LocationFix {
+    fn type_name() -> String {
+        "foxglove.LocationFix".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/LocationFix.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::LOCATION_FIX);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for Log {
     type Error = ::prost::EncodeError;
 
@@ -311,6 +702,36 @@ impl Encode for Log {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for Log {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for Log {
+    fn type_name() -> String {
+        "foxglove.Log".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/Log.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::LOG);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for PackedElementField {
     type Error = ::prost::EncodeError;
 
@@ -333,6 +754,36 @@ impl Encode for PackedElementField {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for PackedElementField {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for PackedElementField {
+    fn type_name() -> String {
+        "foxglove.PackedElementField".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/PackedElementField.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::PACKED_ELEMENT_FIELD);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for Point2 {
     type Error = ::prost::EncodeError;
 
@@ -355,6 +806,36 @@ impl Encode for Point2 {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for Point2 {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for Point2 {
+    fn type_name() -> String {
+        "foxglove.Point2".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/Point2.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::POINT2);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for Point3 {
     type Error = ::prost::EncodeError;
 
@@ -377,6 +858,36 @@ impl Encode for Point3 {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for Point3 {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for Point3 {
+    fn type_name() -> String {
+        "foxglove.Point3".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/Point3.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::POINT3);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for PointCloud {
     type Error = ::prost::EncodeError;
 
@@ -399,6 +910,36 @@ impl Encode for PointCloud {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for PointCloud {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for PointCloud {
+    fn type_name() -> String {
+        "foxglove.PointCloud".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/PointCloud.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::POINT_CLOUD);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for PointsAnnotation {
     type Error = ::prost::EncodeError;
 
@@ -421,6 +962,36 @@ impl Encode for PointsAnnotation {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for PointsAnnotation {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for PointsAnnotation {
+    fn type_name() -> String {
+        "foxglove.PointsAnnotation".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/PointsAnnotation.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::POINTS_ANNOTATION);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for Pose {
     type Error = ::prost::EncodeError;
 
@@ -443,6 +1014,36 @@ impl Encode for Pose {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for Pose {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for Pose {
+    fn type_name() -> String {
+        "foxglove.Pose".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/Pose.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::POSE);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for PoseInFrame {
     type Error = ::prost::EncodeError;
 
@@ -465,6 +1066,36 @@ impl Encode for PoseInFrame {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for PoseInFrame {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for PoseInFrame {
+    fn type_name() -> String {
+        "foxglove.PoseInFrame".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/PoseInFrame.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::POSE_IN_FRAME);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for PosesInFrame {
     type Error = ::prost::EncodeError;
 
@@ -487,6 +1118,36 @@ impl Encode for PosesInFrame {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for PosesInFrame {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for PosesInFrame {
+    fn type_name() -> String {
+        "foxglove.PosesInFrame".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/PosesInFrame.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::POSES_IN_FRAME);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for Quaternion {
     type Error = ::prost::EncodeError;
 
@@ -509,6 +1170,36 @@ impl Encode for Quaternion {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for Quaternion {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for Quaternion {
+    fn type_name() -> String {
+        "foxglove.Quaternion".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/Quaternion.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::QUATERNION);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for RawImage {
     type Error = ::prost::EncodeError;
 
@@ -531,6 +1222,36 @@ impl Encode for RawImage {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for RawImage {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for RawImage {
+    fn type_name() -> String {
+        "foxglove.RawImage".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/RawImage.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::RAW_IMAGE);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for SceneEntity {
     type Error = ::prost::EncodeError;
 
@@ -553,6 +1274,36 @@ impl Encode for SceneEntity {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for SceneEntity {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for SceneEntity {
+    fn type_name() -> String {
+        "foxglove.SceneEntity".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/SceneEntity.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::SCENE_ENTITY);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for SceneEntityDeletion {
     type Error = ::prost::EncodeError;
 
@@ -575,6 +1326,36 @@ impl Encode for SceneEntityDeletion {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for SceneEntityDeletion {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for SceneEntityDeletion {
+    fn type_name() -> String {
+        "foxglove.SceneEntityDeletion".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/SceneEntityDeletion.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::SCENE_ENTITY_DELETION);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for SceneUpdate {
     type Error = ::prost::EncodeError;
 
@@ -597,6 +1378,36 @@ impl Encode for SceneUpdate {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for SceneUpdate {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for SceneUpdate {
+    fn type_name() -> String {
+        "foxglove.SceneUpdate".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/SceneUpdate.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::SCENE_UPDATE);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for TextAnnotation {
     type Error = ::prost::EncodeError;
 
@@ -619,6 +1430,36 @@ impl Encode for TextAnnotation {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for TextAnnotation {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for TextAnnotation {
+    fn type_name() -> String {
+        "foxglove.TextAnnotation".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/TextAnnotation.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::TEXT_ANNOTATION);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for Vector2 {
     type Error = ::prost::EncodeError;
 
@@ -641,6 +1482,36 @@ impl Encode for Vector2 {
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
 
+impl Decode for Vector2 {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for Vector2 {
+    fn type_name() -> String {
+        "foxglove.Vector2".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/Vector2.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::VECTOR2);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
+
 impl Encode for Vector3 {
     type Error = ::prost::EncodeError;
 
@@ -662,3 +1533,33 @@ impl Encode for Vector3 {
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
 }
+
+impl Decode for Vector3 {
+    type Error = ::prost::DecodeError;
+
+    fn decode(buf: &[u8]) -> Result<Self, prost::DecodeError> {
+        ::prost::Message::decode(buf)
+    }
+}
+
+impl ProtobufMessage for Vector3 {
+    fn type_name() -> String {
+        "foxglove.Vector3".to_string()
+    }
+
+    fn file_name() -> String {
+        "foxglove/Vector3.proto".to_string()
+    }
+
+    fn file_descriptors(files: &mut Vec<FileDescriptorProto>) {
+        add_file_descriptors(files, descriptors::VECTOR3);
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
+}
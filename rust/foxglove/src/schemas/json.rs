@@ -0,0 +1,641 @@
+// This file is @generated by foxglove-proto-gen
+use crate::json::{serialize_optional, Base64, JsonDuration, JsonMessage, JsonTime};
+use crate::schemas::foxglove::*;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+impl JsonMessage for ArrowPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.ArrowPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/ArrowPrimitive.json");
+}
+
+impl Serialize for ArrowPrimitive {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.ArrowPrimitive", 6)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        s.serialize_field("shaft_length", &self.shaft_length)?;
+        s.serialize_field("shaft_diameter", &self.shaft_diameter)?;
+        s.serialize_field("head_length", &self.head_length)?;
+        s.serialize_field("head_diameter", &self.head_diameter)?;
+        serialize_optional(&mut s, "color", self.color.as_ref())?;
+        s.end()
+    }
+}
+
+impl JsonMessage for CameraCalibration {
+    const TYPE_NAME: &'static str = "foxglove.CameraCalibration";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/CameraCalibration.json");
+}
+
+impl Serialize for CameraCalibration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.CameraCalibration", 9)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        s.serialize_field("width", &self.width)?;
+        s.serialize_field("height", &self.height)?;
+        s.serialize_field("distortion_model", &self.distortion_model)?;
+        s.serialize_field("D", &self.d)?;
+        s.serialize_field("K", &self.k)?;
+        s.serialize_field("R", &self.r)?;
+        s.serialize_field("P", &self.p)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for CircleAnnotation {
+    const TYPE_NAME: &'static str = "foxglove.CircleAnnotation";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/CircleAnnotation.json");
+}
+
+impl Serialize for CircleAnnotation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.CircleAnnotation", 6)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        serialize_optional(&mut s, "position", self.position.as_ref())?;
+        s.serialize_field("diameter", &self.diameter)?;
+        s.serialize_field("thickness", &self.thickness)?;
+        serialize_optional(&mut s, "fill_color", self.fill_color.as_ref())?;
+        serialize_optional(&mut s, "outline_color", self.outline_color.as_ref())?;
+        s.end()
+    }
+}
+
+impl JsonMessage for Color {
+    const TYPE_NAME: &'static str = "foxglove.Color";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/Color.json");
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.Color", 4)?;
+        s.serialize_field("r", &self.r)?;
+        s.serialize_field("g", &self.g)?;
+        s.serialize_field("b", &self.b)?;
+        s.serialize_field("a", &self.a)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for CompressedImage {
+    const TYPE_NAME: &'static str = "foxglove.CompressedImage";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/CompressedImage.json");
+}
+
+impl Serialize for CompressedImage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.CompressedImage", 4)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        s.serialize_field("data", &Base64(&self.data))?;
+        s.serialize_field("format", &self.format)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for CompressedVideo {
+    const TYPE_NAME: &'static str = "foxglove.CompressedVideo";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/CompressedVideo.json");
+}
+
+impl Serialize for CompressedVideo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.CompressedVideo", 4)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        s.serialize_field("data", &Base64(&self.data))?;
+        s.serialize_field("format", &self.format)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for CubePrimitive {
+    const TYPE_NAME: &'static str = "foxglove.CubePrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/CubePrimitive.json");
+}
+
+impl Serialize for CubePrimitive {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.CubePrimitive", 3)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        serialize_optional(&mut s, "size", self.size.as_ref())?;
+        serialize_optional(&mut s, "color", self.color.as_ref())?;
+        s.end()
+    }
+}
+
+impl JsonMessage for CylinderPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.CylinderPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/CylinderPrimitive.json");
+}
+
+impl Serialize for CylinderPrimitive {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.CylinderPrimitive", 5)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        serialize_optional(&mut s, "size", self.size.as_ref())?;
+        s.serialize_field("bottom_scale", &self.bottom_scale)?;
+        s.serialize_field("top_scale", &self.top_scale)?;
+        serialize_optional(&mut s, "color", self.color.as_ref())?;
+        s.end()
+    }
+}
+
+impl JsonMessage for FrameTransform {
+    const TYPE_NAME: &'static str = "foxglove.FrameTransform";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/FrameTransform.json");
+}
+
+impl Serialize for FrameTransform {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.FrameTransform", 5)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("parent_frame_id", &self.parent_frame_id)?;
+        s.serialize_field("child_frame_id", &self.child_frame_id)?;
+        serialize_optional(&mut s, "translation", self.translation.as_ref())?;
+        serialize_optional(&mut s, "rotation", self.rotation.as_ref())?;
+        s.end()
+    }
+}
+
+impl JsonMessage for FrameTransforms {
+    const TYPE_NAME: &'static str = "foxglove.FrameTransforms";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/FrameTransforms.json");
+}
+
+impl Serialize for FrameTransforms {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.FrameTransforms", 1)?;
+        s.serialize_field("transforms", &self.transforms)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for GeoJson {
+    const TYPE_NAME: &'static str = "foxglove.GeoJSON";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/GeoJSON.json");
+}
+
+impl Serialize for GeoJson {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.GeoJSON", 1)?;
+        s.serialize_field("geojson", &self.geojson)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for Grid {
+    const TYPE_NAME: &'static str = "foxglove.Grid";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/Grid.json");
+}
+
+impl Serialize for Grid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.Grid", 9)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        s.serialize_field("column_count", &self.column_count)?;
+        serialize_optional(&mut s, "cell_size", self.cell_size.as_ref())?;
+        s.serialize_field("row_stride", &self.row_stride)?;
+        s.serialize_field("cell_stride", &self.cell_stride)?;
+        s.serialize_field("fields", &self.fields)?;
+        s.serialize_field("data", &Base64(&self.data))?;
+        s.end()
+    }
+}
+
+impl JsonMessage for ImageAnnotations {
+    const TYPE_NAME: &'static str = "foxglove.ImageAnnotations";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/ImageAnnotations.json");
+}
+
+impl Serialize for ImageAnnotations {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.ImageAnnotations", 3)?;
+        s.serialize_field("circles", &self.circles)?;
+        s.serialize_field("points", &self.points)?;
+        s.serialize_field("texts", &self.texts)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for KeyValuePair {
+    const TYPE_NAME: &'static str = "foxglove.KeyValuePair";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/KeyValuePair.json");
+}
+
+impl Serialize for KeyValuePair {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.KeyValuePair", 2)?;
+        s.serialize_field("key", &self.key)?;
+        s.serialize_field("value", &self.value)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for LaserScan {
+    const TYPE_NAME: &'static str = "foxglove.LaserScan";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/LaserScan.json");
+}
+
+impl Serialize for LaserScan {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.LaserScan", 7)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        s.serialize_field("start_angle", &self.start_angle)?;
+        s.serialize_field("end_angle", &self.end_angle)?;
+        s.serialize_field("ranges", &self.ranges)?;
+        s.serialize_field("intensities", &self.intensities)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for LinePrimitive {
+    const TYPE_NAME: &'static str = "foxglove.LinePrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/LinePrimitive.json");
+}
+
+impl Serialize for LinePrimitive {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.LinePrimitive", 8)?;
+        s.serialize_field("type", &self.r#type)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        s.serialize_field("thickness", &self.thickness)?;
+        s.serialize_field("scale_invariant", &self.scale_invariant)?;
+        s.serialize_field("points", &self.points)?;
+        serialize_optional(&mut s, "color", self.color.as_ref())?;
+        s.serialize_field("colors", &self.colors)?;
+        s.serialize_field("indices", &self.indices)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for LocationFix {
+    const TYPE_NAME: &'static str = "foxglove.LocationFix";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/LocationFix.json");
+}
+
+impl Serialize for LocationFix {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.LocationFix", 7)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        s.serialize_field("latitude", &self.latitude)?;
+        s.serialize_field("longitude", &self.longitude)?;
+        s.serialize_field("altitude", &self.altitude)?;
+        s.serialize_field("position_covariance", &self.position_covariance)?;
+        s.serialize_field("position_covariance_type", &self.position_covariance_type)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for Log {
+    const TYPE_NAME: &'static str = "foxglove.Log";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/Log.json");
+}
+
+impl Serialize for Log {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.Log", 6)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("level", &self.level)?;
+        s.serialize_field("message", &self.message)?;
+        s.serialize_field("name", &self.name)?;
+        s.serialize_field("file", &self.file)?;
+        s.serialize_field("line", &self.line)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for ModelPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.ModelPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/ModelPrimitive.json");
+}
+
+impl Serialize for ModelPrimitive {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.ModelPrimitive", 7)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        serialize_optional(&mut s, "scale", self.scale.as_ref())?;
+        serialize_optional(&mut s, "color", self.color.as_ref())?;
+        s.serialize_field("override_color", &self.override_color)?;
+        s.serialize_field("url", &self.url)?;
+        s.serialize_field("media_type", &self.media_type)?;
+        s.serialize_field("data", &Base64(&self.data))?;
+        s.end()
+    }
+}
+
+impl JsonMessage for PackedElementField {
+    const TYPE_NAME: &'static str = "foxglove.PackedElementField";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/PackedElementField.json");
+}
+
+impl Serialize for PackedElementField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.PackedElementField", 3)?;
+        s.serialize_field("name", &self.name)?;
+        s.serialize_field("offset", &self.offset)?;
+        s.serialize_field("type", &self.r#type)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for Point2 {
+    const TYPE_NAME: &'static str = "foxglove.Point2";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/Point2.json");
+}
+
+impl Serialize for Point2 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.Point2", 2)?;
+        s.serialize_field("x", &self.x)?;
+        s.serialize_field("y", &self.y)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for Point3 {
+    const TYPE_NAME: &'static str = "foxglove.Point3";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/Point3.json");
+}
+
+impl Serialize for Point3 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.Point3", 3)?;
+        s.serialize_field("x", &self.x)?;
+        s.serialize_field("y", &self.y)?;
+        s.serialize_field("z", &self.z)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for PointCloud {
+    const TYPE_NAME: &'static str = "foxglove.PointCloud";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/PointCloud.json");
+}
+
+impl Serialize for PointCloud {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.PointCloud", 6)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        s.serialize_field("point_stride", &self.point_stride)?;
+        s.serialize_field("fields", &self.fields)?;
+        s.serialize_field("data", &Base64(&self.data))?;
+        s.end()
+    }
+}
+
+impl JsonMessage for PointsAnnotation {
+    const TYPE_NAME: &'static str = "foxglove.PointsAnnotation";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/PointsAnnotation.json");
+}
+
+impl Serialize for PointsAnnotation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.PointsAnnotation", 7)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("type", &self.r#type)?;
+        s.serialize_field("points", &self.points)?;
+        serialize_optional(&mut s, "outline_color", self.outline_color.as_ref())?;
+        s.serialize_field("outline_colors", &self.outline_colors)?;
+        serialize_optional(&mut s, "fill_color", self.fill_color.as_ref())?;
+        s.serialize_field("thickness", &self.thickness)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for Pose {
+    const TYPE_NAME: &'static str = "foxglove.Pose";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/Pose.json");
+}
+
+impl Serialize for Pose {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.Pose", 2)?;
+        serialize_optional(&mut s, "position", self.position.as_ref())?;
+        serialize_optional(&mut s, "orientation", self.orientation.as_ref())?;
+        s.end()
+    }
+}
+
+impl JsonMessage for PoseInFrame {
+    const TYPE_NAME: &'static str = "foxglove.PoseInFrame";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/PoseInFrame.json");
+}
+
+impl Serialize for PoseInFrame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.PoseInFrame", 3)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        s.end()
+    }
+}
+
+impl JsonMessage for PosesInFrame {
+    const TYPE_NAME: &'static str = "foxglove.PosesInFrame";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/PosesInFrame.json");
+}
+
+impl Serialize for PosesInFrame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.PosesInFrame", 3)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        s.serialize_field("poses", &self.poses)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for Quaternion {
+    const TYPE_NAME: &'static str = "foxglove.Quaternion";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/Quaternion.json");
+}
+
+impl Serialize for Quaternion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.Quaternion", 4)?;
+        s.serialize_field("x", &self.x)?;
+        s.serialize_field("y", &self.y)?;
+        s.serialize_field("z", &self.z)?;
+        s.serialize_field("w", &self.w)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for RawImage {
+    const TYPE_NAME: &'static str = "foxglove.RawImage";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/RawImage.json");
+}
+
+impl Serialize for RawImage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.RawImage", 7)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        s.serialize_field("width", &self.width)?;
+        s.serialize_field("height", &self.height)?;
+        s.serialize_field("encoding", &self.encoding)?;
+        s.serialize_field("step", &self.step)?;
+        s.serialize_field("data", &Base64(&self.data))?;
+        s.end()
+    }
+}
+
+impl JsonMessage for SceneEntity {
+    const TYPE_NAME: &'static str = "foxglove.SceneEntity";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/SceneEntity.json");
+}
+
+impl Serialize for SceneEntity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.SceneEntity", 14)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("frame_id", &self.frame_id)?;
+        s.serialize_field("id", &self.id)?;
+        serialize_optional(&mut s, "lifetime", self.lifetime.as_ref().map(JsonDuration))?;
+        s.serialize_field("frame_locked", &self.frame_locked)?;
+        s.serialize_field("metadata", &self.metadata)?;
+        s.serialize_field("arrows", &self.arrows)?;
+        s.serialize_field("cubes", &self.cubes)?;
+        s.serialize_field("spheres", &self.spheres)?;
+        s.serialize_field("cylinders", &self.cylinders)?;
+        s.serialize_field("lines", &self.lines)?;
+        s.serialize_field("triangles", &self.triangles)?;
+        s.serialize_field("texts", &self.texts)?;
+        s.serialize_field("models", &self.models)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for SceneEntityDeletion {
+    const TYPE_NAME: &'static str = "foxglove.SceneEntityDeletion";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/SceneEntityDeletion.json");
+}
+
+impl Serialize for SceneEntityDeletion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.SceneEntityDeletion", 3)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        s.serialize_field("type", &self.r#type)?;
+        s.serialize_field("id", &self.id)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for SceneUpdate {
+    const TYPE_NAME: &'static str = "foxglove.SceneUpdate";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/SceneUpdate.json");
+}
+
+impl Serialize for SceneUpdate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.SceneUpdate", 2)?;
+        s.serialize_field("deletions", &self.deletions)?;
+        s.serialize_field("entities", &self.entities)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for SpherePrimitive {
+    const TYPE_NAME: &'static str = "foxglove.SpherePrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/SpherePrimitive.json");
+}
+
+impl Serialize for SpherePrimitive {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.SpherePrimitive", 3)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        serialize_optional(&mut s, "size", self.size.as_ref())?;
+        serialize_optional(&mut s, "color", self.color.as_ref())?;
+        s.end()
+    }
+}
+
+impl JsonMessage for TextAnnotation {
+    const TYPE_NAME: &'static str = "foxglove.TextAnnotation";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/TextAnnotation.json");
+}
+
+impl Serialize for TextAnnotation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.TextAnnotation", 6)?;
+        serialize_optional(&mut s, "timestamp", self.timestamp.as_ref().map(JsonTime))?;
+        serialize_optional(&mut s, "position", self.position.as_ref())?;
+        s.serialize_field("text", &self.text)?;
+        s.serialize_field("font_size", &self.font_size)?;
+        serialize_optional(&mut s, "text_color", self.text_color.as_ref())?;
+        serialize_optional(&mut s, "background_color", self.background_color.as_ref())?;
+        s.end()
+    }
+}
+
+impl JsonMessage for TextPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.TextPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/TextPrimitive.json");
+}
+
+impl Serialize for TextPrimitive {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.TextPrimitive", 6)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        s.serialize_field("billboard", &self.billboard)?;
+        s.serialize_field("font_size", &self.font_size)?;
+        s.serialize_field("scale_invariant", &self.scale_invariant)?;
+        serialize_optional(&mut s, "color", self.color.as_ref())?;
+        s.serialize_field("text", &self.text)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for TriangleListPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.TriangleListPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/TriangleListPrimitive.json");
+}
+
+impl Serialize for TriangleListPrimitive {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.TriangleListPrimitive", 5)?;
+        serialize_optional(&mut s, "pose", self.pose.as_ref())?;
+        s.serialize_field("points", &self.points)?;
+        serialize_optional(&mut s, "color", self.color.as_ref())?;
+        s.serialize_field("colors", &self.colors)?;
+        s.serialize_field("indices", &self.indices)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for Vector2 {
+    const TYPE_NAME: &'static str = "foxglove.Vector2";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/Vector2.json");
+}
+
+impl Serialize for Vector2 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.Vector2", 2)?;
+        s.serialize_field("x", &self.x)?;
+        s.serialize_field("y", &self.y)?;
+        s.end()
+    }
+}
+
+impl JsonMessage for Vector3 {
+    const TYPE_NAME: &'static str = "foxglove.Vector3";
+    const JSON_SCHEMA: &'static str = include_str!("jsonschema/Vector3.json");
+}
+
+impl Serialize for Vector3 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("foxglove.Vector3", 3)?;
+        s.serialize_field("x", &self.x)?;
+        s.serialize_field("y", &self.y)?;
+        s.serialize_field("z", &self.z)?;
+        s.end()
+    }
+}
@@ -0,0 +1,107 @@
+//! Conversions between geometry schemas and [`nalgebra`](::nalgebra) types.
+//!
+//! Quaternions are normalized when converted to a `UnitQuaternion`, and a zero quaternion becomes
+//! the identity rotation. A [`Pose`] without a position or orientation is treated as having a zero
+//! position or the identity orientation.
+
+use ::nalgebra as na;
+
+use super::{Point3, Pose, Quaternion, Vector3};
+
+impl From<na::Vector3<f64>> for Vector3 {
+    fn from(v: na::Vector3<f64>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for na::Vector3<f64> {
+    fn from(v: Vector3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<na::Point3<f64>> for Point3 {
+    fn from(p: na::Point3<f64>) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Point3> for na::Point3<f64> {
+    fn from(p: Point3) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<na::Quaternion<f64>> for Quaternion {
+    fn from(q: na::Quaternion<f64>) -> Self {
+        Self::new(q.i, q.j, q.k, q.w)
+    }
+}
+
+impl From<Quaternion> for na::Quaternion<f64> {
+    fn from(q: Quaternion) -> Self {
+        Self::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+impl From<na::UnitQuaternion<f64>> for Quaternion {
+    fn from(q: na::UnitQuaternion<f64>) -> Self {
+        q.into_inner().into()
+    }
+}
+
+impl From<Quaternion> for na::UnitQuaternion<f64> {
+    fn from(q: Quaternion) -> Self {
+        Self::try_new(q.into(), f64::EPSILON).unwrap_or_else(Self::identity)
+    }
+}
+
+impl From<na::Isometry3<f64>> for Pose {
+    fn from(iso: na::Isometry3<f64>) -> Self {
+        Self::new(iso.translation.vector.into(), iso.rotation.into())
+    }
+}
+
+impl From<Pose> for na::Isometry3<f64> {
+    fn from(pose: Pose) -> Self {
+        let position = pose.position.unwrap_or_default();
+        let orientation = pose.orientation.unwrap_or_else(Quaternion::identity);
+        Self::from_parts(
+            na::Translation3::new(position.x, position.y, position.z),
+            orientation.into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nalgebra_conversions() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(na::Vector3::from(v), na::Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(na::Vector3::from(v)), v);
+        let p = Point3::new(4.0, 5.0, 6.0);
+        assert_eq!(Point3::from(na::Point3::from(p)), p);
+
+        let q = Quaternion::from_euler(0.1, 0.2, 0.3);
+        let unit = na::UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+        let converted = na::UnitQuaternion::from(q);
+        assert!(converted.angle_to(&unit) < 1e-12);
+        assert_eq!(Quaternion::from(unit).w, unit.w);
+        assert_eq!(
+            na::UnitQuaternion::from(Quaternion::new(0.0, 0.0, 0.0, 0.0)),
+            na::UnitQuaternion::identity()
+        );
+
+        let pose = Pose::new(v, q);
+        let iso = na::Isometry3::from(pose);
+        assert_eq!(iso.translation.vector, na::Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Pose::from(iso).position, Some(v));
+        assert_eq!(
+            na::Isometry3::from(Pose::default()),
+            na::Isometry3::identity()
+        );
+    }
+}
@@ -0,0 +1,602 @@
+// This file is @generated by foxglove-proto-gen
+use crate::omgidl::{OmgIdlMessage, Xcdr2Error, Xcdr2Serialize, Xcdr2Writer};
+use crate::schemas::foxglove::*;
+
+impl OmgIdlMessage for ArrowPrimitive {
+    const TYPE_NAME: &'static str = "foxglove::ArrowPrimitive";
+    const DEFINITION: &'static str = include_str!("omgidl/ArrowPrimitive.idl");
+}
+
+impl Xcdr2Serialize for ArrowPrimitive {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct(self.pose.as_ref())?;
+        w.write_f64(self.shaft_length);
+        w.write_f64(self.shaft_diameter);
+        w.write_f64(self.head_length);
+        w.write_f64(self.head_diameter);
+        w.write_struct(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for CameraCalibration {
+    const TYPE_NAME: &'static str = "foxglove::CameraCalibration";
+    const DEFINITION: &'static str = include_str!("omgidl/CameraCalibration.idl");
+}
+
+impl Xcdr2Serialize for CameraCalibration {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.distortion_model)?;
+        w.write_sequence(&self.d)?;
+        w.write_array(&self.k, 9)?;
+        w.write_array(&self.r, 9)?;
+        w.write_array(&self.p, 12)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for CircleAnnotation {
+    const TYPE_NAME: &'static str = "foxglove::CircleAnnotation";
+    const DEFINITION: &'static str = include_str!("omgidl/CircleAnnotation.idl");
+}
+
+impl Xcdr2Serialize for CircleAnnotation {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_struct(self.position.as_ref())?;
+        w.write_f64(self.diameter);
+        w.write_f64(self.thickness);
+        w.write_struct(self.fill_color.as_ref())?;
+        w.write_struct(self.outline_color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for Color {
+    const TYPE_NAME: &'static str = "foxglove::Color";
+    const DEFINITION: &'static str = include_str!("omgidl/Color.idl");
+}
+
+impl Xcdr2Serialize for Color {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_f64(self.r);
+        w.write_f64(self.g);
+        w.write_f64(self.b);
+        w.write_f64(self.a);
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for CompressedImage {
+    const TYPE_NAME: &'static str = "foxglove::CompressedImage";
+    const DEFINITION: &'static str = include_str!("omgidl/CompressedImage.idl");
+}
+
+impl Xcdr2Serialize for CompressedImage {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for CompressedVideo {
+    const TYPE_NAME: &'static str = "foxglove::CompressedVideo";
+    const DEFINITION: &'static str = include_str!("omgidl/CompressedVideo.idl");
+}
+
+impl Xcdr2Serialize for CompressedVideo {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for CubePrimitive {
+    const TYPE_NAME: &'static str = "foxglove::CubePrimitive";
+    const DEFINITION: &'static str = include_str!("omgidl/CubePrimitive.idl");
+}
+
+impl Xcdr2Serialize for CubePrimitive {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct(self.pose.as_ref())?;
+        w.write_struct(self.size.as_ref())?;
+        w.write_struct(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for CylinderPrimitive {
+    const TYPE_NAME: &'static str = "foxglove::CylinderPrimitive";
+    const DEFINITION: &'static str = include_str!("omgidl/CylinderPrimitive.idl");
+}
+
+impl Xcdr2Serialize for CylinderPrimitive {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct(self.pose.as_ref())?;
+        w.write_struct(self.size.as_ref())?;
+        w.write_f64(self.bottom_scale);
+        w.write_f64(self.top_scale);
+        w.write_struct(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for FrameTransform {
+    const TYPE_NAME: &'static str = "foxglove::FrameTransform";
+    const DEFINITION: &'static str = include_str!("omgidl/FrameTransform.idl");
+}
+
+impl Xcdr2Serialize for FrameTransform {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.parent_frame_id)?;
+        w.write_string(&self.child_frame_id)?;
+        w.write_struct(self.translation.as_ref())?;
+        w.write_struct(self.rotation.as_ref())?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for FrameTransforms {
+    const TYPE_NAME: &'static str = "foxglove::FrameTransforms";
+    const DEFINITION: &'static str = include_str!("omgidl/FrameTransforms.idl");
+}
+
+impl Xcdr2Serialize for FrameTransforms {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct_sequence(&self.transforms)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for GeoJson {
+    const TYPE_NAME: &'static str = "foxglove::GeoJSON";
+    const DEFINITION: &'static str = include_str!("omgidl/GeoJSON.idl");
+}
+
+impl Xcdr2Serialize for GeoJson {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_string(&self.geojson)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for Grid {
+    const TYPE_NAME: &'static str = "foxglove::Grid";
+    const DEFINITION: &'static str = include_str!("omgidl/Grid.idl");
+}
+
+impl Xcdr2Serialize for Grid {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_struct(self.pose.as_ref())?;
+        w.write_u32(self.column_count);
+        w.write_struct(self.cell_size.as_ref())?;
+        w.write_u32(self.row_stride);
+        w.write_u32(self.cell_stride);
+        w.write_struct_sequence(&self.fields)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for ImageAnnotations {
+    const TYPE_NAME: &'static str = "foxglove::ImageAnnotations";
+    const DEFINITION: &'static str = include_str!("omgidl/ImageAnnotations.idl");
+}
+
+impl Xcdr2Serialize for ImageAnnotations {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct_sequence(&self.circles)?;
+        w.write_struct_sequence(&self.points)?;
+        w.write_struct_sequence(&self.texts)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for KeyValuePair {
+    const TYPE_NAME: &'static str = "foxglove::KeyValuePair";
+    const DEFINITION: &'static str = include_str!("omgidl/KeyValuePair.idl");
+}
+
+impl Xcdr2Serialize for KeyValuePair {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_string(&self.key)?;
+        w.write_string(&self.value)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for LaserScan {
+    const TYPE_NAME: &'static str = "foxglove::LaserScan";
+    const DEFINITION: &'static str = include_str!("omgidl/LaserScan.idl");
+}
+
+impl Xcdr2Serialize for LaserScan {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_struct(self.pose.as_ref())?;
+        w.write_f64(self.start_angle);
+        w.write_f64(self.end_angle);
+        w.write_sequence(&self.ranges)?;
+        w.write_sequence(&self.intensities)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for LinePrimitive {
+    const TYPE_NAME: &'static str = "foxglove::LinePrimitive";
+    const DEFINITION: &'static str = include_str!("omgidl/LinePrimitive.idl");
+}
+
+impl Xcdr2Serialize for LinePrimitive {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_enum(self.r#type);
+        w.write_struct(self.pose.as_ref())?;
+        w.write_f64(self.thickness);
+        w.write_bool(self.scale_invariant);
+        w.write_struct_sequence(&self.points)?;
+        w.write_struct(self.color.as_ref())?;
+        w.write_struct_sequence(&self.colors)?;
+        w.write_sequence(&self.indices)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for LocationFix {
+    const TYPE_NAME: &'static str = "foxglove::LocationFix";
+    const DEFINITION: &'static str = include_str!("omgidl/LocationFix.idl");
+}
+
+impl Xcdr2Serialize for LocationFix {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_f64(self.latitude);
+        w.write_f64(self.longitude);
+        w.write_f64(self.altitude);
+        w.write_array(&self.position_covariance, 9)?;
+        w.write_enum(self.position_covariance_type);
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for Log {
+    const TYPE_NAME: &'static str = "foxglove::Log";
+    const DEFINITION: &'static str = include_str!("omgidl/Log.idl");
+}
+
+impl Xcdr2Serialize for Log {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_enum(self.level);
+        w.write_string(&self.message)?;
+        w.write_string(&self.name)?;
+        w.write_string(&self.file)?;
+        w.write_u32(self.line);
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for ModelPrimitive {
+    const TYPE_NAME: &'static str = "foxglove::ModelPrimitive";
+    const DEFINITION: &'static str = include_str!("omgidl/ModelPrimitive.idl");
+}
+
+impl Xcdr2Serialize for ModelPrimitive {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct(self.pose.as_ref())?;
+        w.write_struct(self.scale.as_ref())?;
+        w.write_struct(self.color.as_ref())?;
+        w.write_bool(self.override_color);
+        w.write_string(&self.url)?;
+        w.write_string(&self.media_type)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for PackedElementField {
+    const TYPE_NAME: &'static str = "foxglove::PackedElementField";
+    const DEFINITION: &'static str = include_str!("omgidl/PackedElementField.idl");
+}
+
+impl Xcdr2Serialize for PackedElementField {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_string(&self.name)?;
+        w.write_u32(self.offset);
+        w.write_enum(self.r#type);
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for Point2 {
+    const TYPE_NAME: &'static str = "foxglove::Point2";
+    const DEFINITION: &'static str = include_str!("omgidl/Point2.idl");
+}
+
+impl Xcdr2Serialize for Point2 {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for Point3 {
+    const TYPE_NAME: &'static str = "foxglove::Point3";
+    const DEFINITION: &'static str = include_str!("omgidl/Point3.idl");
+}
+
+impl Xcdr2Serialize for Point3 {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for PointCloud {
+    const TYPE_NAME: &'static str = "foxglove::PointCloud";
+    const DEFINITION: &'static str = include_str!("omgidl/PointCloud.idl");
+}
+
+impl Xcdr2Serialize for PointCloud {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_struct(self.pose.as_ref())?;
+        w.write_u32(self.point_stride);
+        w.write_struct_sequence(&self.fields)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for PointsAnnotation {
+    const TYPE_NAME: &'static str = "foxglove::PointsAnnotation";
+    const DEFINITION: &'static str = include_str!("omgidl/PointsAnnotation.idl");
+}
+
+impl Xcdr2Serialize for PointsAnnotation {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_enum(self.r#type);
+        w.write_struct_sequence(&self.points)?;
+        w.write_struct(self.outline_color.as_ref())?;
+        w.write_struct_sequence(&self.outline_colors)?;
+        w.write_struct(self.fill_color.as_ref())?;
+        w.write_f64(self.thickness);
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for Pose {
+    const TYPE_NAME: &'static str = "foxglove::Pose";
+    const DEFINITION: &'static str = include_str!("omgidl/Pose.idl");
+}
+
+impl Xcdr2Serialize for Pose {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct(self.position.as_ref())?;
+        w.write_struct(self.orientation.as_ref())?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for PoseInFrame {
+    const TYPE_NAME: &'static str = "foxglove::PoseInFrame";
+    const DEFINITION: &'static str = include_str!("omgidl/PoseInFrame.idl");
+}
+
+impl Xcdr2Serialize for PoseInFrame {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_struct(self.pose.as_ref())?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for PosesInFrame {
+    const TYPE_NAME: &'static str = "foxglove::PosesInFrame";
+    const DEFINITION: &'static str = include_str!("omgidl/PosesInFrame.idl");
+}
+
+impl Xcdr2Serialize for PosesInFrame {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_struct_sequence(&self.poses)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for Quaternion {
+    const TYPE_NAME: &'static str = "foxglove::Quaternion";
+    const DEFINITION: &'static str = include_str!("omgidl/Quaternion.idl");
+}
+
+impl Xcdr2Serialize for Quaternion {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        w.write_f64(self.w);
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for RawImage {
+    const TYPE_NAME: &'static str = "foxglove::RawImage";
+    const DEFINITION: &'static str = include_str!("omgidl/RawImage.idl");
+}
+
+impl Xcdr2Serialize for RawImage {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.encoding)?;
+        w.write_u32(self.step);
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for SceneEntity {
+    const TYPE_NAME: &'static str = "foxglove::SceneEntity";
+    const DEFINITION: &'static str = include_str!("omgidl/SceneEntity.idl");
+}
+
+impl Xcdr2Serialize for SceneEntity {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_string(&self.id)?;
+        w.write_duration(self.lifetime.as_ref())?;
+        w.write_bool(self.frame_locked);
+        w.write_struct_sequence(&self.metadata)?;
+        w.write_struct_sequence(&self.arrows)?;
+        w.write_struct_sequence(&self.cubes)?;
+        w.write_struct_sequence(&self.spheres)?;
+        w.write_struct_sequence(&self.cylinders)?;
+        w.write_struct_sequence(&self.lines)?;
+        w.write_struct_sequence(&self.triangles)?;
+        w.write_struct_sequence(&self.texts)?;
+        w.write_struct_sequence(&self.models)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for SceneEntityDeletion {
+    const TYPE_NAME: &'static str = "foxglove::SceneEntityDeletion";
+    const DEFINITION: &'static str = include_str!("omgidl/SceneEntityDeletion.idl");
+}
+
+impl Xcdr2Serialize for SceneEntityDeletion {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_enum(self.r#type);
+        w.write_string(&self.id)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for SceneUpdate {
+    const TYPE_NAME: &'static str = "foxglove::SceneUpdate";
+    const DEFINITION: &'static str = include_str!("omgidl/SceneUpdate.idl");
+}
+
+impl Xcdr2Serialize for SceneUpdate {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct_sequence(&self.deletions)?;
+        w.write_struct_sequence(&self.entities)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for SpherePrimitive {
+    const TYPE_NAME: &'static str = "foxglove::SpherePrimitive";
+    const DEFINITION: &'static str = include_str!("omgidl/SpherePrimitive.idl");
+}
+
+impl Xcdr2Serialize for SpherePrimitive {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct(self.pose.as_ref())?;
+        w.write_struct(self.size.as_ref())?;
+        w.write_struct(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for TextAnnotation {
+    const TYPE_NAME: &'static str = "foxglove::TextAnnotation";
+    const DEFINITION: &'static str = include_str!("omgidl/TextAnnotation.idl");
+}
+
+impl Xcdr2Serialize for TextAnnotation {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_struct(self.position.as_ref())?;
+        w.write_string(&self.text)?;
+        w.write_f64(self.font_size);
+        w.write_struct(self.text_color.as_ref())?;
+        w.write_struct(self.background_color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for TextPrimitive {
+    const TYPE_NAME: &'static str = "foxglove::TextPrimitive";
+    const DEFINITION: &'static str = include_str!("omgidl/TextPrimitive.idl");
+}
+
+impl Xcdr2Serialize for TextPrimitive {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct(self.pose.as_ref())?;
+        w.write_bool(self.billboard);
+        w.write_f64(self.font_size);
+        w.write_bool(self.scale_invariant);
+        w.write_struct(self.color.as_ref())?;
+        w.write_string(&self.text)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for TriangleListPrimitive {
+    const TYPE_NAME: &'static str = "foxglove::TriangleListPrimitive";
+    const DEFINITION: &'static str = include_str!("omgidl/TriangleListPrimitive.idl");
+}
+
+impl Xcdr2Serialize for TriangleListPrimitive {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_struct(self.pose.as_ref())?;
+        w.write_struct_sequence(&self.points)?;
+        w.write_struct(self.color.as_ref())?;
+        w.write_struct_sequence(&self.colors)?;
+        w.write_sequence(&self.indices)?;
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for Vector2 {
+    const TYPE_NAME: &'static str = "foxglove::Vector2";
+    const DEFINITION: &'static str = include_str!("omgidl/Vector2.idl");
+}
+
+impl Xcdr2Serialize for Vector2 {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+}
+
+impl OmgIdlMessage for Vector3 {
+    const TYPE_NAME: &'static str = "foxglove::Vector3";
+    const DEFINITION: &'static str = include_str!("omgidl/Vector3.idl");
+}
+
+impl Xcdr2Serialize for Vector3 {
+    fn serialize(&self, w: &mut Xcdr2Writer) -> Result<(), Xcdr2Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+}
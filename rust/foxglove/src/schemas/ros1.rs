@@ -0,0 +1,602 @@
+// This file is @generated by foxglove-proto-gen
+use crate::ros1::{Ros1Error, Ros1Message, Ros1Serialize, Ros1Writer};
+use crate::schemas::foxglove::*;
+
+impl Ros1Message for ArrowPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/ArrowPrimitive";
+    const DEFINITION: &'static str = include_str!("ros1/ArrowPrimitive.msg");
+}
+
+impl Ros1Serialize for ArrowPrimitive {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.shaft_length);
+        w.write_f64(self.shaft_diameter);
+        w.write_f64(self.head_length);
+        w.write_f64(self.head_diameter);
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for CameraCalibration {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CameraCalibration";
+    const DEFINITION: &'static str = include_str!("ros1/CameraCalibration.msg");
+}
+
+impl Ros1Serialize for CameraCalibration {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.distortion_model)?;
+        w.write_sequence(&self.d)?;
+        w.write_array(&self.k, 9)?;
+        w.write_array(&self.r, 9)?;
+        w.write_array(&self.p, 12)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for CircleAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CircleAnnotation";
+    const DEFINITION: &'static str = include_str!("ros1/CircleAnnotation.msg");
+}
+
+impl Ros1Serialize for CircleAnnotation {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_message(self.position.as_ref())?;
+        w.write_f64(self.diameter);
+        w.write_f64(self.thickness);
+        w.write_message(self.fill_color.as_ref())?;
+        w.write_message(self.outline_color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for Color {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Color";
+    const DEFINITION: &'static str = include_str!("ros1/Color.msg");
+}
+
+impl Ros1Serialize for Color {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_f64(self.r);
+        w.write_f64(self.g);
+        w.write_f64(self.b);
+        w.write_f64(self.a);
+        Ok(())
+    }
+}
+
+impl Ros1Message for CompressedImage {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CompressedImage";
+    const DEFINITION: &'static str = include_str!("ros1/CompressedImage.msg");
+}
+
+impl Ros1Serialize for CompressedImage {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for CompressedVideo {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CompressedVideo";
+    const DEFINITION: &'static str = include_str!("ros1/CompressedVideo.msg");
+}
+
+impl Ros1Serialize for CompressedVideo {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for CubePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CubePrimitive";
+    const DEFINITION: &'static str = include_str!("ros1/CubePrimitive.msg");
+}
+
+impl Ros1Serialize for CubePrimitive {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for CylinderPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CylinderPrimitive";
+    const DEFINITION: &'static str = include_str!("ros1/CylinderPrimitive.msg");
+}
+
+impl Ros1Serialize for CylinderPrimitive {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_f64(self.bottom_scale);
+        w.write_f64(self.top_scale);
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for FrameTransform {
+    const TYPE_NAME: &'static str = "foxglove_msgs/FrameTransform";
+    const DEFINITION: &'static str = include_str!("ros1/FrameTransform.msg");
+}
+
+impl Ros1Serialize for FrameTransform {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.parent_frame_id)?;
+        w.write_string(&self.child_frame_id)?;
+        w.write_message(self.translation.as_ref())?;
+        w.write_message(self.rotation.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for FrameTransforms {
+    const TYPE_NAME: &'static str = "foxglove_msgs/FrameTransforms";
+    const DEFINITION: &'static str = include_str!("ros1/FrameTransforms.msg");
+}
+
+impl Ros1Serialize for FrameTransforms {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_sequence(&self.transforms)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for GeoJson {
+    const TYPE_NAME: &'static str = "foxglove_msgs/GeoJSON";
+    const DEFINITION: &'static str = include_str!("ros1/GeoJson.msg");
+}
+
+impl Ros1Serialize for GeoJson {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_string(&self.geojson)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for Grid {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Grid";
+    const DEFINITION: &'static str = include_str!("ros1/Grid.msg");
+}
+
+impl Ros1Serialize for Grid {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_u32(self.column_count);
+        w.write_message(self.cell_size.as_ref())?;
+        w.write_u32(self.row_stride);
+        w.write_u32(self.cell_stride);
+        w.write_sequence(&self.fields)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for ImageAnnotations {
+    const TYPE_NAME: &'static str = "foxglove_msgs/ImageAnnotations";
+    const DEFINITION: &'static str = include_str!("ros1/ImageAnnotations.msg");
+}
+
+impl Ros1Serialize for ImageAnnotations {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_sequence(&self.circles)?;
+        w.write_sequence(&self.points)?;
+        w.write_sequence(&self.texts)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for KeyValuePair {
+    const TYPE_NAME: &'static str = "foxglove_msgs/KeyValuePair";
+    const DEFINITION: &'static str = include_str!("ros1/KeyValuePair.msg");
+}
+
+impl Ros1Serialize for KeyValuePair {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_string(&self.key)?;
+        w.write_string(&self.value)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for LaserScan {
+    const TYPE_NAME: &'static str = "foxglove_msgs/LaserScan";
+    const DEFINITION: &'static str = include_str!("ros1/LaserScan.msg");
+}
+
+impl Ros1Serialize for LaserScan {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.start_angle);
+        w.write_f64(self.end_angle);
+        w.write_sequence(&self.ranges)?;
+        w.write_sequence(&self.intensities)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for LinePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/LinePrimitive";
+    const DEFINITION: &'static str = include_str!("ros1/LinePrimitive.msg");
+}
+
+impl Ros1Serialize for LinePrimitive {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_u8(self.r#type as u8);
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.thickness);
+        w.write_bool(self.scale_invariant);
+        w.write_sequence(&self.points)?;
+        w.write_message(self.color.as_ref())?;
+        w.write_sequence(&self.colors)?;
+        w.write_sequence(&self.indices)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for LocationFix {
+    const TYPE_NAME: &'static str = "foxglove_msgs/LocationFix";
+    const DEFINITION: &'static str = include_str!("ros1/LocationFix.msg");
+}
+
+impl Ros1Serialize for LocationFix {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_f64(self.latitude);
+        w.write_f64(self.longitude);
+        w.write_f64(self.altitude);
+        w.write_array(&self.position_covariance, 9)?;
+        w.write_u8(self.position_covariance_type as u8);
+        Ok(())
+    }
+}
+
+impl Ros1Message for Log {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Log";
+    const DEFINITION: &'static str = include_str!("ros1/Log.msg");
+}
+
+impl Ros1Serialize for Log {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_u8(self.level as u8);
+        w.write_string(&self.message)?;
+        w.write_string(&self.name)?;
+        w.write_string(&self.file)?;
+        w.write_u32(self.line);
+        Ok(())
+    }
+}
+
+impl Ros1Message for ModelPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/ModelPrimitive";
+    const DEFINITION: &'static str = include_str!("ros1/ModelPrimitive.msg");
+}
+
+impl Ros1Serialize for ModelPrimitive {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.scale.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        w.write_bool(self.override_color);
+        w.write_string(&self.url)?;
+        w.write_string(&self.media_type)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for PackedElementField {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PackedElementField";
+    const DEFINITION: &'static str = include_str!("ros1/PackedElementField.msg");
+}
+
+impl Ros1Serialize for PackedElementField {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_string(&self.name)?;
+        w.write_u32(self.offset);
+        w.write_u8(self.r#type as u8);
+        Ok(())
+    }
+}
+
+impl Ros1Message for Point2 {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Point2";
+    const DEFINITION: &'static str = include_str!("ros1/Point2.msg");
+}
+
+impl Ros1Serialize for Point2 {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+}
+
+impl Ros1Message for PointCloud {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PointCloud";
+    const DEFINITION: &'static str = include_str!("ros1/PointCloud.msg");
+}
+
+impl Ros1Serialize for PointCloud {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_u32(self.point_stride);
+        w.write_sequence(&self.fields)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for PointsAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PointsAnnotation";
+    const DEFINITION: &'static str = include_str!("ros1/PointsAnnotation.msg");
+}
+
+impl Ros1Serialize for PointsAnnotation {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_u8(self.r#type as u8);
+        w.write_sequence(&self.points)?;
+        w.write_message(self.outline_color.as_ref())?;
+        w.write_sequence(&self.outline_colors)?;
+        w.write_message(self.fill_color.as_ref())?;
+        w.write_f64(self.thickness);
+        Ok(())
+    }
+}
+
+impl Ros1Message for PoseInFrame {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PoseInFrame";
+    const DEFINITION: &'static str = include_str!("ros1/PoseInFrame.msg");
+}
+
+impl Ros1Serialize for PoseInFrame {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for PosesInFrame {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PosesInFrame";
+    const DEFINITION: &'static str = include_str!("ros1/PosesInFrame.msg");
+}
+
+impl Ros1Serialize for PosesInFrame {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_sequence(&self.poses)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for RawImage {
+    const TYPE_NAME: &'static str = "foxglove_msgs/RawImage";
+    const DEFINITION: &'static str = include_str!("ros1/RawImage.msg");
+}
+
+impl Ros1Serialize for RawImage {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.encoding)?;
+        w.write_u32(self.step);
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for SceneEntity {
+    const TYPE_NAME: &'static str = "foxglove_msgs/SceneEntity";
+    const DEFINITION: &'static str = include_str!("ros1/SceneEntity.msg");
+}
+
+impl Ros1Serialize for SceneEntity {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_string(&self.id)?;
+        w.write_duration(self.lifetime.as_ref())?;
+        w.write_bool(self.frame_locked);
+        w.write_sequence(&self.metadata)?;
+        w.write_sequence(&self.arrows)?;
+        w.write_sequence(&self.cubes)?;
+        w.write_sequence(&self.spheres)?;
+        w.write_sequence(&self.cylinders)?;
+        w.write_sequence(&self.lines)?;
+        w.write_sequence(&self.triangles)?;
+        w.write_sequence(&self.texts)?;
+        w.write_sequence(&self.models)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for SceneEntityDeletion {
+    const TYPE_NAME: &'static str = "foxglove_msgs/SceneEntityDeletion";
+    const DEFINITION: &'static str = include_str!("ros1/SceneEntityDeletion.msg");
+}
+
+impl Ros1Serialize for SceneEntityDeletion {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_u8(self.r#type as u8);
+        w.write_string(&self.id)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for SceneUpdate {
+    const TYPE_NAME: &'static str = "foxglove_msgs/SceneUpdate";
+    const DEFINITION: &'static str = include_str!("ros1/SceneUpdate.msg");
+}
+
+impl Ros1Serialize for SceneUpdate {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_sequence(&self.deletions)?;
+        w.write_sequence(&self.entities)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for SpherePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/SpherePrimitive";
+    const DEFINITION: &'static str = include_str!("ros1/SpherePrimitive.msg");
+}
+
+impl Ros1Serialize for SpherePrimitive {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for TextAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/TextAnnotation";
+    const DEFINITION: &'static str = include_str!("ros1/TextAnnotation.msg");
+}
+
+impl Ros1Serialize for TextAnnotation {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_message(self.position.as_ref())?;
+        w.write_string(&self.text)?;
+        w.write_f64(self.font_size);
+        w.write_message(self.text_color.as_ref())?;
+        w.write_message(self.background_color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for TextPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/TextPrimitive";
+    const DEFINITION: &'static str = include_str!("ros1/TextPrimitive.msg");
+}
+
+impl Ros1Serialize for TextPrimitive {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_bool(self.billboard);
+        w.write_f64(self.font_size);
+        w.write_bool(self.scale_invariant);
+        w.write_message(self.color.as_ref())?;
+        w.write_string(&self.text)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for TriangleListPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/TriangleListPrimitive";
+    const DEFINITION: &'static str = include_str!("ros1/TriangleListPrimitive.msg");
+}
+
+impl Ros1Serialize for TriangleListPrimitive {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_sequence(&self.points)?;
+        w.write_message(self.color.as_ref())?;
+        w.write_sequence(&self.colors)?;
+        w.write_sequence(&self.indices)?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for Vector2 {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Vector2";
+    const DEFINITION: &'static str = include_str!("ros1/Vector2.msg");
+}
+
+impl Ros1Serialize for Vector2 {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+}
+
+impl Ros1Message for Point3 {
+    const TYPE_NAME: &'static str = "geometry_msgs/Point";
+    const DEFINITION: &'static str = include_str!("ros1/Point3.msg");
+}
+
+impl Ros1Serialize for Point3 {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+}
+
+impl Ros1Message for Pose {
+    const TYPE_NAME: &'static str = "geometry_msgs/Pose";
+    const DEFINITION: &'static str = include_str!("ros1/Pose.msg");
+}
+
+impl Ros1Serialize for Pose {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_message(self.position.as_ref())?;
+        w.write_message(self.orientation.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros1Message for Quaternion {
+    const TYPE_NAME: &'static str = "geometry_msgs/Quaternion";
+    const DEFINITION: &'static str = include_str!("ros1/Quaternion.msg");
+}
+
+impl Ros1Serialize for Quaternion {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        w.write_f64(self.w);
+        Ok(())
+    }
+}
+
+impl Ros1Message for Vector3 {
+    const TYPE_NAME: &'static str = "geometry_msgs/Vector3";
+    const DEFINITION: &'static str = include_str!("ros1/Vector3.msg");
+}
+
+impl Ros1Serialize for Vector3 {
+    fn serialize(&self, w: &mut Ros1Writer) -> Result<(), Ros1Error> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+}
@@ -0,0 +1,602 @@
+// This file is @generated by foxglove-proto-gen
+use crate::ros2::{CdrError, CdrSerialize, CdrWriter, Ros2Message};
+use crate::schemas::foxglove::*;
+
+impl Ros2Message for ArrowPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/ArrowPrimitive";
+    const DEFINITION: &'static str = include_str!("ros2/ArrowPrimitive.msg");
+}
+
+impl CdrSerialize for ArrowPrimitive {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.shaft_length);
+        w.write_f64(self.shaft_diameter);
+        w.write_f64(self.head_length);
+        w.write_f64(self.head_diameter);
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for CameraCalibration {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CameraCalibration";
+    const DEFINITION: &'static str = include_str!("ros2/CameraCalibration.msg");
+}
+
+impl CdrSerialize for CameraCalibration {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.distortion_model)?;
+        w.write_sequence(&self.d)?;
+        w.write_array(&self.k, 9)?;
+        w.write_array(&self.r, 9)?;
+        w.write_array(&self.p, 12)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for CircleAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CircleAnnotation";
+    const DEFINITION: &'static str = include_str!("ros2/CircleAnnotation.msg");
+}
+
+impl CdrSerialize for CircleAnnotation {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_message(self.position.as_ref())?;
+        w.write_f64(self.diameter);
+        w.write_f64(self.thickness);
+        w.write_message(self.fill_color.as_ref())?;
+        w.write_message(self.outline_color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for Color {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Color";
+    const DEFINITION: &'static str = include_str!("ros2/Color.msg");
+}
+
+impl CdrSerialize for Color {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_f64(self.r);
+        w.write_f64(self.g);
+        w.write_f64(self.b);
+        w.write_f64(self.a);
+        Ok(())
+    }
+}
+
+impl Ros2Message for CompressedImage {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CompressedImage";
+    const DEFINITION: &'static str = include_str!("ros2/CompressedImage.msg");
+}
+
+impl CdrSerialize for CompressedImage {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for CompressedVideo {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CompressedVideo";
+    const DEFINITION: &'static str = include_str!("ros2/CompressedVideo.msg");
+}
+
+impl CdrSerialize for CompressedVideo {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for CubePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CubePrimitive";
+    const DEFINITION: &'static str = include_str!("ros2/CubePrimitive.msg");
+}
+
+impl CdrSerialize for CubePrimitive {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for CylinderPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CylinderPrimitive";
+    const DEFINITION: &'static str = include_str!("ros2/CylinderPrimitive.msg");
+}
+
+impl CdrSerialize for CylinderPrimitive {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_f64(self.bottom_scale);
+        w.write_f64(self.top_scale);
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for FrameTransform {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/FrameTransform";
+    const DEFINITION: &'static str = include_str!("ros2/FrameTransform.msg");
+}
+
+impl CdrSerialize for FrameTransform {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.parent_frame_id)?;
+        w.write_string(&self.child_frame_id)?;
+        w.write_message(self.translation.as_ref())?;
+        w.write_message(self.rotation.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for FrameTransforms {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/FrameTransforms";
+    const DEFINITION: &'static str = include_str!("ros2/FrameTransforms.msg");
+}
+
+impl CdrSerialize for FrameTransforms {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_sequence(&self.transforms)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for GeoJson {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/GeoJSON";
+    const DEFINITION: &'static str = include_str!("ros2/GeoJson.msg");
+}
+
+impl CdrSerialize for GeoJson {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_string(&self.geojson)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for Grid {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Grid";
+    const DEFINITION: &'static str = include_str!("ros2/Grid.msg");
+}
+
+impl CdrSerialize for Grid {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_u32(self.column_count);
+        w.write_message(self.cell_size.as_ref())?;
+        w.write_u32(self.row_stride);
+        w.write_u32(self.cell_stride);
+        w.write_sequence(&self.fields)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for ImageAnnotations {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/ImageAnnotations";
+    const DEFINITION: &'static str = include_str!("ros2/ImageAnnotations.msg");
+}
+
+impl CdrSerialize for ImageAnnotations {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_sequence(&self.circles)?;
+        w.write_sequence(&self.points)?;
+        w.write_sequence(&self.texts)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for KeyValuePair {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/KeyValuePair";
+    const DEFINITION: &'static str = include_str!("ros2/KeyValuePair.msg");
+}
+
+impl CdrSerialize for KeyValuePair {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_string(&self.key)?;
+        w.write_string(&self.value)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for LaserScan {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/LaserScan";
+    const DEFINITION: &'static str = include_str!("ros2/LaserScan.msg");
+}
+
+impl CdrSerialize for LaserScan {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.start_angle);
+        w.write_f64(self.end_angle);
+        w.write_sequence(&self.ranges)?;
+        w.write_sequence(&self.intensities)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for LinePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/LinePrimitive";
+    const DEFINITION: &'static str = include_str!("ros2/LinePrimitive.msg");
+}
+
+impl CdrSerialize for LinePrimitive {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_u8(self.r#type as u8);
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.thickness);
+        w.write_bool(self.scale_invariant);
+        w.write_sequence(&self.points)?;
+        w.write_message(self.color.as_ref())?;
+        w.write_sequence(&self.colors)?;
+        w.write_sequence(&self.indices)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for LocationFix {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/LocationFix";
+    const DEFINITION: &'static str = include_str!("ros2/LocationFix.msg");
+}
+
+impl CdrSerialize for LocationFix {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_f64(self.latitude);
+        w.write_f64(self.longitude);
+        w.write_f64(self.altitude);
+        w.write_array(&self.position_covariance, 9)?;
+        w.write_u8(self.position_covariance_type as u8);
+        Ok(())
+    }
+}
+
+impl Ros2Message for Log {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Log";
+    const DEFINITION: &'static str = include_str!("ros2/Log.msg");
+}
+
+impl CdrSerialize for Log {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_u8(self.level as u8);
+        w.write_string(&self.message)?;
+        w.write_string(&self.name)?;
+        w.write_string(&self.file)?;
+        w.write_u32(self.line);
+        Ok(())
+    }
+}
+
+impl Ros2Message for ModelPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/ModelPrimitive";
+    const DEFINITION: &'static str = include_str!("ros2/ModelPrimitive.msg");
+}
+
+impl CdrSerialize for ModelPrimitive {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.scale.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        w.write_bool(self.override_color);
+        w.write_string(&self.url)?;
+        w.write_string(&self.media_type)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for PackedElementField {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PackedElementField";
+    const DEFINITION: &'static str = include_str!("ros2/PackedElementField.msg");
+}
+
+impl CdrSerialize for PackedElementField {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_string(&self.name)?;
+        w.write_u32(self.offset);
+        w.write_u8(self.r#type as u8);
+        Ok(())
+    }
+}
+
+impl Ros2Message for Point2 {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Point2";
+    const DEFINITION: &'static str = include_str!("ros2/Point2.msg");
+}
+
+impl CdrSerialize for Point2 {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+}
+
+impl Ros2Message for PointCloud {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PointCloud";
+    const DEFINITION: &'static str = include_str!("ros2/PointCloud.msg");
+}
+
+impl CdrSerialize for PointCloud {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_u32(self.point_stride);
+        w.write_sequence(&self.fields)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for PointsAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PointsAnnotation";
+    const DEFINITION: &'static str = include_str!("ros2/PointsAnnotation.msg");
+}
+
+impl CdrSerialize for PointsAnnotation {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_u8(self.r#type as u8);
+        w.write_sequence(&self.points)?;
+        w.write_message(self.outline_color.as_ref())?;
+        w.write_sequence(&self.outline_colors)?;
+        w.write_message(self.fill_color.as_ref())?;
+        w.write_f64(self.thickness);
+        Ok(())
+    }
+}
+
+impl Ros2Message for PoseInFrame {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PoseInFrame";
+    const DEFINITION: &'static str = include_str!("ros2/PoseInFrame.msg");
+}
+
+impl CdrSerialize for PoseInFrame {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for PosesInFrame {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PosesInFrame";
+    const DEFINITION: &'static str = include_str!("ros2/PosesInFrame.msg");
+}
+
+impl CdrSerialize for PosesInFrame {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_sequence(&self.poses)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for RawImage {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/RawImage";
+    const DEFINITION: &'static str = include_str!("ros2/RawImage.msg");
+}
+
+impl CdrSerialize for RawImage {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.encoding)?;
+        w.write_u32(self.step);
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for SceneEntity {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/SceneEntity";
+    const DEFINITION: &'static str = include_str!("ros2/SceneEntity.msg");
+}
+
+impl CdrSerialize for SceneEntity {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_string(&self.id)?;
+        w.write_duration(self.lifetime.as_ref())?;
+        w.write_bool(self.frame_locked);
+        w.write_sequence(&self.metadata)?;
+        w.write_sequence(&self.arrows)?;
+        w.write_sequence(&self.cubes)?;
+        w.write_sequence(&self.spheres)?;
+        w.write_sequence(&self.cylinders)?;
+        w.write_sequence(&self.lines)?;
+        w.write_sequence(&self.triangles)?;
+        w.write_sequence(&self.texts)?;
+        w.write_sequence(&self.models)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for SceneEntityDeletion {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/SceneEntityDeletion";
+    const DEFINITION: &'static str = include_str!("ros2/SceneEntityDeletion.msg");
+}
+
+impl CdrSerialize for SceneEntityDeletion {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_u8(self.r#type as u8);
+        w.write_string(&self.id)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for SceneUpdate {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/SceneUpdate";
+    const DEFINITION: &'static str = include_str!("ros2/SceneUpdate.msg");
+}
+
+impl CdrSerialize for SceneUpdate {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_sequence(&self.deletions)?;
+        w.write_sequence(&self.entities)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for SpherePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/SpherePrimitive";
+    const DEFINITION: &'static str = include_str!("ros2/SpherePrimitive.msg");
+}
+
+impl CdrSerialize for SpherePrimitive {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for TextAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/TextAnnotation";
+    const DEFINITION: &'static str = include_str!("ros2/TextAnnotation.msg");
+}
+
+impl CdrSerialize for TextAnnotation {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_time(self.timestamp.as_ref())?;
+        w.write_message(self.position.as_ref())?;
+        w.write_string(&self.text)?;
+        w.write_f64(self.font_size);
+        w.write_message(self.text_color.as_ref())?;
+        w.write_message(self.background_color.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for TextPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/TextPrimitive";
+    const DEFINITION: &'static str = include_str!("ros2/TextPrimitive.msg");
+}
+
+impl CdrSerialize for TextPrimitive {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_bool(self.billboard);
+        w.write_f64(self.font_size);
+        w.write_bool(self.scale_invariant);
+        w.write_message(self.color.as_ref())?;
+        w.write_string(&self.text)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for TriangleListPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/TriangleListPrimitive";
+    const DEFINITION: &'static str = include_str!("ros2/TriangleListPrimitive.msg");
+}
+
+impl CdrSerialize for TriangleListPrimitive {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_sequence(&self.points)?;
+        w.write_message(self.color.as_ref())?;
+        w.write_sequence(&self.colors)?;
+        w.write_sequence(&self.indices)?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for Vector2 {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Vector2";
+    const DEFINITION: &'static str = include_str!("ros2/Vector2.msg");
+}
+
+impl CdrSerialize for Vector2 {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+}
+
+impl Ros2Message for Point3 {
+    const TYPE_NAME: &'static str = "geometry_msgs/msg/Point";
+    const DEFINITION: &'static str = include_str!("ros2/Point3.msg");
+}
+
+impl CdrSerialize for Point3 {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+}
+
+impl Ros2Message for Pose {
+    const TYPE_NAME: &'static str = "geometry_msgs/msg/Pose";
+    const DEFINITION: &'static str = include_str!("ros2/Pose.msg");
+}
+
+impl CdrSerialize for Pose {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_message(self.position.as_ref())?;
+        w.write_message(self.orientation.as_ref())?;
+        Ok(())
+    }
+}
+
+impl Ros2Message for Quaternion {
+    const TYPE_NAME: &'static str = "geometry_msgs/msg/Quaternion";
+    const DEFINITION: &'static str = include_str!("ros2/Quaternion.msg");
+}
+
+impl CdrSerialize for Quaternion {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        w.write_f64(self.w);
+        Ok(())
+    }
+}
+
+impl Ros2Message for Vector3 {
+    const TYPE_NAME: &'static str = "geometry_msgs/msg/Vector3";
+    const DEFINITION: &'static str = include_str!("ros2/Vector3.msg");
+}
+
+impl CdrSerialize for Vector3 {
+    fn serialize(&self, w: &mut CdrWriter) -> Result<(), CdrError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+}
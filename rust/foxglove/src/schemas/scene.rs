@@ -0,0 +1,632 @@
+//! Builders and constructors for scene messages.
+//!
+//! Scene entities are deeply nested, and a primitive without a pose orientation has an invalid
+//! all-zero quaternion. The constructors here always set a valid orientation, and the builders
+//! fill in the nesting:
+//!
+//! ```
+//! use foxglove::schemas::{Color, Pose, SceneEntity, SceneUpdate, Vector3};
+//!
+//! let update = SceneUpdate::builder()
+//!     .entity(
+//!         SceneEntity::builder("box")
+//!             .frame("map")
+//!             .cube(
+//!                 Pose::from_position(Vector3::new(1.0, 0.0, 0.0)),
+//!                 Vector3::new(1.0, 1.0, 1.0),
+//!                 Color::rgb(1.0, 0.0, 0.0),
+//!             )
+//!             .build(),
+//!     )
+//!     .build();
+//! assert_eq!(update.entities[0].cubes.len(), 1);
+//! ```
+
+use super::line_primitive::Type as LineType;
+use super::scene_entity_deletion::Type as DeletionType;
+use super::{
+    ArrowPrimitive, Color, CubePrimitive, CylinderPrimitive, Duration, KeyValuePair, LinePrimitive,
+    ModelPrimitive, Point3, Pose, Quaternion, SceneEntity, SceneEntityDeletion, SceneUpdate,
+    SpherePrimitive, TextPrimitive, Timestamp, TriangleListPrimitive, Vector3,
+};
+
+impl Vector3 {
+    /// Creates a new vector.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Point3 {
+    /// Creates a new point.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Quaternion {
+    /// Creates a new quaternion.
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Returns the identity rotation.
+    pub fn identity() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Creates a rotation from Euler angles in radians.
+    ///
+    /// The rotation is about the fixed X, Y, and Z axes, in that order; equivalently, it is a yaw
+    /// about Z, followed by a pitch about the new Y axis, followed by a roll about the new X axis.
+    /// This is the roll-pitch-yaw convention used by ROS.
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Self {
+        let (sr, cr) = (roll / 2.0).sin_cos();
+        let (sp, cp) = (pitch / 2.0).sin_cos();
+        let (sy, cy) = (yaw / 2.0).sin_cos();
+        Self::new(
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+            cr * cp * cy + sr * sp * sy,
+        )
+    }
+
+    /// Returns the Euler angles of the rotation in radians, as `(roll, pitch, yaw)`.
+    ///
+    /// This is the inverse of [`Quaternion::from_euler`]. The pitch is in `[-π/2, π/2]`.
+    pub fn to_euler(&self) -> (f64, f64, f64) {
+        let Self { x, y, z, w } = *self;
+        let roll = (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y));
+        let pitch = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0).asin();
+        let yaw = (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z));
+        (roll, pitch, yaw)
+    }
+
+    /// Creates a rotation of `angle` radians about `axis`.
+    ///
+    /// The axis does not need to be normalized. If it has zero length, returns the identity.
+    pub fn from_axis_angle(axis: Vector3, angle: f64) -> Self {
+        let norm = (axis.x * axis.x + axis.y * axis.y + axis.z * axis.z).sqrt();
+        if norm == 0.0 {
+            return Self::identity();
+        }
+        let (s, c) = (angle / 2.0).sin_cos();
+        let s = s / norm;
+        Self::new(axis.x * s, axis.y * s, axis.z * s, c)
+    }
+}
+
+impl Color {
+    /// Creates an opaque color from red, green, and blue components in `[0, 1]`.
+    pub fn rgb(r: f64, g: f64, b: f64) -> Self {
+        Self::rgba(r, g, b, 1.0)
+    }
+
+    /// Creates a color from red, green, blue, and alpha components in `[0, 1]`.
+    pub fn rgba(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl Pose {
+    /// Creates a new pose.
+    pub fn new(position: Vector3, orientation: Quaternion) -> Self {
+        Self {
+            position: Some(position),
+            orientation: Some(orientation),
+        }
+    }
+
+    /// Returns a pose at the origin, with the identity orientation.
+    pub fn identity() -> Self {
+        Self::from_position(Vector3::default())
+    }
+
+    /// Returns a pose at the given position, with the identity orientation.
+    pub fn from_position(position: Vector3) -> Self {
+        Self::new(position, Quaternion::identity())
+    }
+}
+
+impl ArrowPrimitive {
+    /// Creates an arrow pointing along the pose's +x axis, with a head sized relative to the
+    /// shaft.
+    pub fn new(pose: Pose, shaft_length: f64, shaft_diameter: f64, color: Color) -> Self {
+        Self {
+            pose: Some(pose),
+            shaft_length,
+            shaft_diameter,
+            head_length: shaft_diameter * 2.0,
+            head_diameter: shaft_diameter * 2.0,
+            color: Some(color),
+        }
+    }
+
+    /// Sets the length and diameter of the arrow head.
+    pub fn with_head(mut self, head_length: f64, head_diameter: f64) -> Self {
+        self.head_length = head_length;
+        self.head_diameter = head_diameter;
+        self
+    }
+}
+
+impl CubePrimitive {
+    /// Creates a cube.
+    pub fn new(pose: Pose, size: Vector3, color: Color) -> Self {
+        Self {
+            pose: Some(pose),
+            size: Some(size),
+            color: Some(color),
+        }
+    }
+}
+
+impl SpherePrimitive {
+    /// Creates a sphere or ellipsoid.
+    pub fn new(pose: Pose, size: Vector3, color: Color) -> Self {
+        Self {
+            pose: Some(pose),
+            size: Some(size),
+            color: Some(color),
+        }
+    }
+}
+
+impl CylinderPrimitive {
+    /// Creates a cylinder.
+    pub fn new(pose: Pose, size: Vector3, color: Color) -> Self {
+        Self {
+            pose: Some(pose),
+            size: Some(size),
+            bottom_scale: 1.0,
+            top_scale: 1.0,
+            color: Some(color),
+        }
+    }
+
+    /// Creates a cone, with its point at the top.
+    pub fn cone(pose: Pose, size: Vector3, color: Color) -> Self {
+        Self::new(pose, size, color).with_scale(1.0, 0.0)
+    }
+
+    /// Sets the scale of the bottom and top faces, relative to the size.
+    pub fn with_scale(mut self, bottom_scale: f64, top_scale: f64) -> Self {
+        self.bottom_scale = bottom_scale;
+        self.top_scale = top_scale;
+        self
+    }
+}
+
+impl LinePrimitive {
+    /// Creates a line of the given type through the points, with the identity pose.
+    pub fn new(
+        r#type: LineType,
+        points: impl IntoIterator<Item = Point3>,
+        thickness: f64,
+        color: Color,
+    ) -> Self {
+        Self {
+            r#type: r#type.into(),
+            pose: Some(Pose::identity()),
+            thickness,
+            scale_invariant: false,
+            points: points.into_iter().collect(),
+            color: Some(color),
+            colors: vec![],
+            indices: vec![],
+        }
+    }
+
+    /// Sets the pose of the line's points.
+    pub fn with_pose(mut self, pose: Pose) -> Self {
+        self.pose = Some(pose);
+        self
+    }
+
+    /// Sets whether the thickness is in pixels, rather than meters.
+    pub fn with_scale_invariant(mut self, scale_invariant: bool) -> Self {
+        self.scale_invariant = scale_invariant;
+        self
+    }
+
+    /// Sets per-point colors, which override the line's color.
+    pub fn with_colors(mut self, colors: impl IntoIterator<Item = Color>) -> Self {
+        self.colors = colors.into_iter().collect();
+        self
+    }
+
+    /// Sets the indices of the points to draw, in place of all points in order.
+    pub fn with_indices(mut self, indices: impl IntoIterator<Item = u32>) -> Self {
+        self.indices = indices.into_iter().collect();
+        self
+    }
+}
+
+impl TriangleListPrimitive {
+    /// Creates a list of triangles from consecutive triples of points, with the identity pose.
+    pub fn new(points: impl IntoIterator<Item = Point3>, color: Color) -> Self {
+        Self {
+            pose: Some(Pose::identity()),
+            points: points.into_iter().collect(),
+            color: Some(color),
+            colors: vec![],
+            indices: vec![],
+        }
+    }
+
+    /// Sets the pose of the triangles' points.
+    pub fn with_pose(mut self, pose: Pose) -> Self {
+        self.pose = Some(pose);
+        self
+    }
+
+    /// Sets per-point colors, which override the triangles' color.
+    pub fn with_colors(mut self, colors: impl IntoIterator<Item = Color>) -> Self {
+        self.colors = colors.into_iter().collect();
+        self
+    }
+
+    /// Sets the indices of the points to draw, in place of all points in order.
+    pub fn with_indices(mut self, indices: impl IntoIterator<Item = u32>) -> Self {
+        self.indices = indices.into_iter().collect();
+        self
+    }
+}
+
+impl TextPrimitive {
+    /// Creates a billboard text label, which always faces the camera.
+    pub fn new(pose: Pose, text: impl Into<String>, font_size: f64, color: Color) -> Self {
+        Self {
+            pose: Some(pose),
+            billboard: true,
+            font_size,
+            scale_invariant: false,
+            color: Some(color),
+            text: text.into(),
+        }
+    }
+
+    /// Sets whether the text always faces the camera.
+    pub fn with_billboard(mut self, billboard: bool) -> Self {
+        self.billboard = billboard;
+        self
+    }
+
+    /// Sets whether the font size is in pixels, rather than meters.
+    pub fn with_scale_invariant(mut self, scale_invariant: bool) -> Self {
+        self.scale_invariant = scale_invariant;
+        self
+    }
+}
+
+impl ModelPrimitive {
+    /// Creates a model loaded from a URL, rendered with its own colors.
+    pub fn from_url(pose: Pose, url: impl Into<String>) -> Self {
+        Self {
+            pose: Some(pose),
+            scale: Some(Vector3::new(1.0, 1.0, 1.0)),
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a model from embedded data with the given media type, e.g. `model/gltf-binary`.
+    pub fn from_data(pose: Pose, media_type: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            pose: Some(pose),
+            scale: Some(Vector3::new(1.0, 1.0, 1.0)),
+            media_type: media_type.into(),
+            data: data.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the scale factor along each axis.
+    pub fn with_scale(mut self, scale: Vector3) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Renders the model with a single color, in place of its own colors.
+    pub fn with_override_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self.override_color = true;
+        self
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive which can be added to a [`SceneEntity`].
+///
+/// This trait is implemented for each of the primitive types, and cannot be implemented outside
+/// of this crate.
+pub trait ScenePrimitive: sealed::Sealed {
+    /// Adds the primitive to the entity.
+    fn add_to(self, entity: &mut SceneEntity);
+}
+
+macro_rules! impl_scene_primitive {
+    ($($ty:ty => $field:ident),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl ScenePrimitive for $ty {
+                fn add_to(self, entity: &mut SceneEntity) {
+                    entity.$field.push(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_scene_primitive! {
+    ArrowPrimitive => arrows,
+    CubePrimitive => cubes,
+    SpherePrimitive => spheres,
+    CylinderPrimitive => cylinders,
+    LinePrimitive => lines,
+    TriangleListPrimitive => triangles,
+    TextPrimitive => texts,
+    ModelPrimitive => models,
+}
+
+impl SceneEntity {
+    /// Returns a builder for an entity with the given ID.
+    pub fn builder(id: impl Into<String>) -> SceneEntityBuilder {
+        SceneEntityBuilder(SceneEntity {
+            id: id.into(),
+            ..Default::default()
+        })
+    }
+}
+
+/// A builder for a [`SceneEntity`].
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct SceneEntityBuilder(SceneEntity);
+
+impl SceneEntityBuilder {
+    /// Sets the timestamp of the entity.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.0.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the frame of reference of the entity.
+    pub fn frame(mut self, frame_id: impl Into<String>) -> Self {
+        self.0.frame_id = frame_id.into();
+        self
+    }
+
+    /// Sets the lifetime of the entity, after which it is removed.
+    pub fn lifetime(mut self, lifetime: Duration) -> Self {
+        self.0.lifetime = Some(lifetime);
+        self
+    }
+
+    /// Sets whether the entity follows its frame as it moves relative to the fixed frame.
+    pub fn frame_locked(mut self, frame_locked: bool) -> Self {
+        self.0.frame_locked = frame_locked;
+        self
+    }
+
+    /// Adds a metadata entry.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.metadata.push(KeyValuePair {
+            key: key.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Adds a primitive.
+    pub fn primitive(mut self, primitive: impl ScenePrimitive) -> Self {
+        primitive.add_to(&mut self.0);
+        self
+    }
+
+    /// Adds a cube.
+    pub fn cube(self, pose: Pose, size: Vector3, color: Color) -> Self {
+        self.primitive(CubePrimitive::new(pose, size, color))
+    }
+
+    /// Adds a sphere or ellipsoid.
+    pub fn sphere(self, pose: Pose, size: Vector3, color: Color) -> Self {
+        self.primitive(SpherePrimitive::new(pose, size, color))
+    }
+
+    /// Adds a cylinder.
+    pub fn cylinder(self, pose: Pose, size: Vector3, color: Color) -> Self {
+        self.primitive(CylinderPrimitive::new(pose, size, color))
+    }
+
+    /// Adds an arrow.
+    pub fn arrow(self, pose: Pose, shaft_length: f64, shaft_diameter: f64, color: Color) -> Self {
+        self.primitive(ArrowPrimitive::new(
+            pose,
+            shaft_length,
+            shaft_diameter,
+            color,
+        ))
+    }
+
+    /// Adds a line strip through the points.
+    pub fn line_strip(
+        self,
+        points: impl IntoIterator<Item = Point3>,
+        thickness: f64,
+        color: Color,
+    ) -> Self {
+        self.primitive(LinePrimitive::new(
+            LineType::LineStrip,
+            points,
+            thickness,
+            color,
+        ))
+    }
+
+    /// Adds a billboard text label.
+    pub fn text(self, pose: Pose, text: impl Into<String>, font_size: f64, color: Color) -> Self {
+        self.primitive(TextPrimitive::new(pose, text, font_size, color))
+    }
+
+    /// Returns the entity.
+    pub fn build(self) -> SceneEntity {
+        self.0
+    }
+}
+
+impl SceneUpdate {
+    /// Returns a builder for a scene update.
+    pub fn builder() -> SceneUpdateBuilder {
+        SceneUpdateBuilder(SceneUpdate::default())
+    }
+}
+
+/// A builder for a [`SceneUpdate`].
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct SceneUpdateBuilder(SceneUpdate);
+
+impl SceneUpdateBuilder {
+    /// Adds or replaces an entity.
+    pub fn entity(mut self, entity: impl Into<SceneEntity>) -> Self {
+        self.0.entities.push(entity.into());
+        self
+    }
+
+    /// Deletes the entity with the given ID, if it is older than the timestamp.
+    pub fn delete(mut self, id: impl Into<String>, timestamp: Timestamp) -> Self {
+        self.0.deletions.push(SceneEntityDeletion {
+            timestamp: Some(timestamp),
+            r#type: DeletionType::MatchingId.into(),
+            id: id.into(),
+        });
+        self
+    }
+
+    /// Deletes all entities older than the timestamp.
+    pub fn delete_all(mut self, timestamp: Timestamp) -> Self {
+        self.0.deletions.push(SceneEntityDeletion {
+            timestamp: Some(timestamp),
+            r#type: DeletionType::All.into(),
+            id: String::new(),
+        });
+        self
+    }
+
+    /// Returns the scene update.
+    pub fn build(self) -> SceneUpdate {
+        self.0
+    }
+}
+
+impl From<SceneEntityBuilder> for SceneEntity {
+    fn from(builder: SceneEntityBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<SceneUpdateBuilder> for SceneUpdate {
+    fn from(builder: SceneUpdateBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entity_builder() {
+        let timestamp = Timestamp {
+            seconds: 1,
+            nanos: 2,
+        };
+        let pose = Pose::from_position(Vector3::new(1.0, 2.0, 3.0));
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let entity = SceneEntity::builder("robot")
+            .frame("map")
+            .timestamp(timestamp)
+            .frame_locked(true)
+            .metadata("kind", "robot")
+            .cube(pose, Vector3::new(1.0, 1.0, 1.0), red)
+            .line_strip(
+                [Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)],
+                0.1,
+                red,
+            )
+            .text(pose, "hello", 12.0, red)
+            .primitive(CylinderPrimitive::cone(
+                pose,
+                Vector3::new(1.0, 1.0, 2.0),
+                red,
+            ))
+            .build();
+
+        assert_eq!(entity.id, "robot");
+        assert_eq!(entity.frame_id, "map");
+        assert_eq!(entity.timestamp, Some(timestamp));
+        assert!(entity.frame_locked);
+        assert_eq!(entity.metadata[0].key, "kind");
+        assert_eq!(
+            entity.cubes,
+            [CubePrimitive::new(pose, Vector3::new(1.0, 1.0, 1.0), red)]
+        );
+        assert_eq!(entity.lines[0].r#type(), LineType::LineStrip);
+        assert_eq!(entity.lines[0].pose, Some(Pose::identity()));
+        assert_eq!(entity.lines[0].points.len(), 2);
+        assert_eq!(entity.texts[0].text, "hello");
+        assert!(entity.texts[0].billboard);
+        assert_eq!(entity.cylinders[0].top_scale, 0.0);
+        assert!(entity.arrows.is_empty());
+
+        // Every pose has a valid orientation.
+        assert_eq!(pose.orientation, Some(Quaternion::new(0.0, 0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_update_builder() {
+        let timestamp = Timestamp::default();
+        let update = SceneUpdate::builder()
+            .delete("old", timestamp)
+            .delete_all(timestamp)
+            .entity(SceneEntity::builder("new"))
+            .build();
+        assert_eq!(update.deletions.len(), 2);
+        assert_eq!(update.deletions[0].r#type(), DeletionType::MatchingId);
+        assert_eq!(update.deletions[0].id, "old");
+        assert_eq!(update.deletions[1].r#type(), DeletionType::All);
+        assert_eq!(update.entities[0].id, "new");
+    }
+
+    #[test]
+    fn test_quaternion_euler() {
+        use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2};
+
+        let approx_eq = |a: Quaternion, b: Quaternion| {
+            let d = [a.x - b.x, a.y - b.y, a.z - b.z, a.w - b.w];
+            d.iter().all(|d| d.abs() < 1e-12)
+        };
+        let s = FRAC_1_SQRT_2;
+        let yaw = Quaternion::from_euler(0.0, 0.0, FRAC_PI_2);
+        assert!(approx_eq(yaw, Quaternion::new(0.0, 0.0, s, s)));
+        assert!(approx_eq(
+            yaw,
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 2.0), FRAC_PI_2)
+        ));
+        assert_eq!(
+            Quaternion::from_axis_angle(Vector3::default(), 1.0),
+            Quaternion::identity()
+        );
+
+        let (roll, pitch, yaw) = Quaternion::from_euler(0.1, -0.2, 0.3).to_euler();
+        assert!((roll - 0.1).abs() < 1e-12);
+        assert!((pitch + 0.2).abs() < 1e-12);
+        assert!((yaw - 0.3).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,237 @@
+//! In-process subscriptions to logged messages.
+//!
+//! A subscription observes the messages logged on the channels of a [`LogContext`], without
+//! implementing a [`LogSink`]. This is useful for monitoring, for testing, and for publishing
+//! topics derived from other topics:
+//!
+//! ```no_run
+//! use foxglove::{LogContext, TopicFilter};
+//! use futures_util::StreamExt;
+//!
+//! # async fn func() {
+//! let mut stream = LogContext::global().subscribe_stream(TopicFilter::prefix("/camera/"));
+//! while let Some(msg) = stream.next().await {
+//!     println!("{}: {} bytes", msg.channel.topic(), msg.data.len());
+//! }
+//! # }
+//! ```
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures_util::Stream;
+
+use crate::{Channel, FoxgloveError, LogContext, LogSink, Metadata};
+
+/// The number of messages a [`MessageStream`] buffers before dropping new messages.
+pub const DEFAULT_STREAM_CAPACITY: usize = 1024;
+
+/// Selects the topics a subscription receives messages from.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub enum TopicFilter {
+    /// Messages on all topics.
+    #[default]
+    All,
+    /// Messages on any of the given topics.
+    Topics(HashSet<String>),
+    /// Messages on topics starting with the given prefix.
+    Prefix(String),
+}
+
+impl TopicFilter {
+    /// Returns a filter for messages on any of the given topics.
+    pub fn topics(topics: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Topics(topics.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns a filter for messages on topics starting with the given prefix.
+    pub fn prefix(prefix: impl Into<String>) -> Self {
+        Self::Prefix(prefix.into())
+    }
+
+    /// Returns true if the filter matches the topic.
+    pub fn matches(&self, topic: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Topics(topics) => topics.contains(topic),
+            Self::Prefix(prefix) => topic.starts_with(prefix.as_str()),
+        }
+    }
+}
+
+impl From<&str> for TopicFilter {
+    fn from(topic: &str) -> Self {
+        Self::topics([topic])
+    }
+}
+
+/// A message received by a subscription.
+#[derive(Debug, Clone)]
+pub struct SubscribedMessage {
+    /// The channel the message was logged on.
+    pub channel: Arc<Channel>,
+    /// The encoded message.
+    pub data: Bytes,
+    /// The message metadata.
+    pub metadata: Metadata,
+}
+
+type Callback = dyn Fn(&Arc<Channel>, &[u8], &Metadata) + Send + Sync;
+
+/// A sink which passes messages on matching topics to a callback.
+struct SubscriptionSink {
+    filter: TopicFilter,
+    callback: Box<Callback>,
+}
+
+impl LogSink for SubscriptionSink {
+    fn log(
+        &self,
+        channel: &Arc<Channel>,
+        msg: &[u8],
+        metadata: &Metadata,
+    ) -> Result<(), FoxgloveError> {
+        if self.filter.matches(&channel.topic) {
+            (self.callback)(channel, msg, metadata);
+        }
+        Ok(())
+    }
+}
+
+/// A subscription to messages logged in a [`LogContext`].
+///
+/// Created by [`LogContext::subscribe`]. The subscription ends when it is dropped.
+#[must_use = "the subscription ends when it is dropped"]
+pub struct Subscription<'a> {
+    context: &'a LogContext,
+    sink: Arc<dyn LogSink>,
+}
+
+impl<'a> Subscription<'a> {
+    pub(crate) fn new(
+        context: &'a LogContext,
+        filter: TopicFilter,
+        callback: impl Fn(&Arc<Channel>, &[u8], &Metadata) + Send + Sync + 'static,
+    ) -> Self {
+        let sink: Arc<dyn LogSink> = Arc::new(SubscriptionSink {
+            filter,
+            callback: Box::new(callback),
+        });
+        context.add_sink(sink.clone());
+        Self { context, sink }
+    }
+}
+
+impl Drop for Subscription<'_> {
+    fn drop(&mut self) {
+        self.context.remove_sink(&self.sink);
+    }
+}
+
+/// A stream of messages logged in a [`LogContext`].
+///
+/// Created by [`LogContext::subscribe_stream`]. The stream buffers up to
+/// [`DEFAULT_STREAM_CAPACITY`] messages; if it is not polled quickly enough, new messages are
+/// dropped rather than blocking the logging thread. The subscription ends when the stream is
+/// dropped.
+#[must_use = "streams do nothing unless polled"]
+pub struct MessageStream<'a> {
+    stream: flume::r#async::RecvStream<'static, SubscribedMessage>,
+    _subscription: Subscription<'a>,
+}
+
+impl<'a> MessageStream<'a> {
+    pub(crate) fn new(context: &'a LogContext, filter: TopicFilter) -> Self {
+        let (tx, rx) = flume::bounded(DEFAULT_STREAM_CAPACITY);
+        let subscription = Subscription::new(context, filter, move |channel, msg, metadata| {
+            _ = tx.try_send(SubscribedMessage {
+                channel: channel.clone(),
+                data: Bytes::copy_from_slice(msg),
+                metadata: *metadata,
+            });
+        });
+        Self {
+            stream: rx.into_stream(),
+            _subscription: subscription,
+        }
+    }
+}
+
+impl Stream for MessageStream<'_> {
+    type Item = SubscribedMessage;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.stream).poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+    use parking_lot::Mutex;
+
+    use super::*;
+    use crate::ChannelBuilder;
+
+    fn channel(ctx: &LogContext, topic: &str) -> Arc<Channel> {
+        ChannelBuilder::new(topic)
+            .message_encoding("json")
+            .with_context(ctx)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_subscribe() {
+        let ctx = LogContext::new();
+        let a = channel(&ctx, "/a");
+        let received = Arc::new(Mutex::new(vec![]));
+        let subscription = ctx.subscribe(TopicFilter::topics(["/a", "/b"]), {
+            let received = received.clone();
+            move |channel, msg, _| received.lock().push((channel.topic.clone(), msg.to_vec()))
+        });
+
+        // Channels created after subscribing are also observed.
+        let b = channel(&ctx, "/b");
+        let c = channel(&ctx, "/c");
+        a.log(b"1");
+        b.log(b"2");
+        c.log(b"3");
+        drop(subscription);
+        a.log(b"4");
+
+        assert_eq!(
+            *received.lock(),
+            [
+                ("/a".to_string(), b"1".to_vec()),
+                ("/b".to_string(), b"2".to_vec())
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_stream() {
+        let ctx = LogContext::new();
+        let mut stream = ctx.subscribe_stream(TopicFilter::prefix("/camera/"));
+        let image = channel(&ctx, "/camera/image");
+        let other = channel(&ctx, "/imu");
+        other.log(b"imu");
+        image.log(b"image");
+
+        let msg = stream.next().await.unwrap();
+        assert_eq!(msg.channel.topic(), "/camera/image");
+        assert_eq!(msg.data, Bytes::from_static(b"image"));
+        assert_eq!(msg.metadata.sequence, 1);
+    }
+
+    #[test]
+    fn test_topic_filter() {
+        assert!(TopicFilter::All.matches("/a"));
+        assert!(TopicFilter::from("/a").matches("/a"));
+        assert!(!TopicFilter::from("/a").matches("/ab"));
+        assert!(TopicFilter::prefix("/a").matches("/ab"));
+    }
+}
@@ -1,5 +1,39 @@
+//! Conversions for message timestamps and durations.
+//!
+//! Messages carry [`Timestamp`] and [`Duration`] fields, while log times in
+//! [`PartialMetadata`](crate::PartialMetadata) are nanoseconds since the Unix epoch. The
+//! [`TimestampExt`] and [`DurationExt`] traits convert between the two. Conversions to and from
+//! [`SystemTime`] and [`std::time::Duration`] are provided by `From` and `TryFrom` implementations
+//! on the types themselves.
+//!
+//! To keep a message's timestamp consistent with the publish time recorded by sinks, derive the
+//! metadata from the timestamp with [`PartialMetadata::published_at`](crate::PartialMetadata::published_at):
+//!
+//! ```no_run
+//! use foxglove::schemas::{Log, Timestamp};
+//! use foxglove::time::TimestampExt;
+//! use foxglove::{LogContext, PartialMetadata, TypedChannel};
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = TypedChannel::<Log>::new("/log")?;
+//! let timestamp = Timestamp::from_nanos(LogContext::global().now());
+//! let msg = Log {
+//!     timestamp: Some(timestamp),
+//!     message: "hello".to_string(),
+//!     ..Default::default()
+//! };
+//! channel.log_with_meta(&msg, PartialMetadata::published_at(&timestamp));
+//! # Ok(()) }
+//! ```
+//!
+//! With the `chrono` feature, timestamps and durations also convert to and from `chrono` types.
+
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::schemas::{Duration, Timestamp};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
 /// nanoseconds_since_epoch returns the current time in nanoseconds since the Unix epoch.
 /// This is useful for setting timestamps in log messages.
 pub(crate) fn nanoseconds_since_epoch() -> u64 {
@@ -9,3 +43,199 @@ pub(crate) fn nanoseconds_since_epoch() -> u64 {
     }
     0
 }
+
+/// Normalizes seconds and nanoseconds, such that nanoseconds are in the range [0, 1e9).
+///
+/// Used by encoders whose time and duration representations have non-negative nanoseconds.
+pub(crate) fn normalize(seconds: i64, nanos: i32) -> (i128, u32) {
+    let total = i128::from(seconds) * NANOS_PER_SECOND + i128::from(nanos);
+    (
+        total.div_euclid(NANOS_PER_SECOND),
+        total.rem_euclid(NANOS_PER_SECOND) as u32,
+    )
+}
+
+/// Splits a number of nanoseconds into seconds and nanoseconds with the same sign.
+///
+/// The number of seconds must fit in an `i64`, which holds for any 64-bit count of nanoseconds.
+fn split_nanos(nanos: i128) -> (i64, i32) {
+    (
+        (nanos / NANOS_PER_SECOND) as i64,
+        (nanos % NANOS_PER_SECOND) as i32,
+    )
+}
+
+/// Conversions for [`Timestamp`].
+pub trait TimestampExt: sealed::Sealed {
+    /// Creates a timestamp from nanoseconds since the Unix epoch.
+    fn from_nanos(nanos: u64) -> Self;
+
+    /// Returns the timestamp in nanoseconds since the Unix epoch.
+    ///
+    /// Returns `None` if the timestamp is before the epoch, or too far in the future.
+    fn to_nanos(&self) -> Option<u64>;
+
+    /// Creates a timestamp from a `chrono` date and time.
+    #[cfg(feature = "chrono")]
+    fn from_datetime(datetime: chrono::DateTime<chrono::Utc>) -> Self;
+
+    /// Returns the timestamp as a `chrono` date and time.
+    ///
+    /// Returns `None` if the timestamp is out of range.
+    #[cfg(feature = "chrono")]
+    fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>>;
+}
+
+impl TimestampExt for Timestamp {
+    fn from_nanos(nanos: u64) -> Self {
+        let (seconds, nanos) = split_nanos(nanos.into());
+        Self { seconds, nanos }
+    }
+
+    fn to_nanos(&self) -> Option<u64> {
+        let nanos = i128::from(self.seconds) * NANOS_PER_SECOND + i128::from(self.nanos);
+        u64::try_from(nanos).ok()
+    }
+
+    #[cfg(feature = "chrono")]
+    fn from_datetime(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        Self {
+            seconds: datetime.timestamp(),
+            nanos: datetime.timestamp_subsec_nanos() as i32,
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let mut ts = *self;
+        ts.normalize();
+        chrono::DateTime::from_timestamp(ts.seconds, ts.nanos as u32)
+    }
+}
+
+/// Conversions for [`Duration`].
+pub trait DurationExt: sealed::Sealed {
+    /// Creates a duration from a signed number of nanoseconds.
+    fn from_nanos(nanos: i64) -> Self;
+
+    /// Returns the duration in nanoseconds.
+    ///
+    /// Returns `None` if the duration does not fit in an `i64`.
+    fn to_nanos(&self) -> Option<i64>;
+
+    /// Creates a duration from a [`std::time::Duration`], saturating if it is out of range.
+    fn from_std(duration: std::time::Duration) -> Self;
+
+    /// Creates a duration from a `chrono` time delta.
+    #[cfg(feature = "chrono")]
+    fn from_time_delta(delta: chrono::TimeDelta) -> Self;
+
+    /// Returns the duration as a `chrono` time delta.
+    ///
+    /// Returns `None` if the duration is out of range.
+    #[cfg(feature = "chrono")]
+    fn to_time_delta(&self) -> Option<chrono::TimeDelta>;
+}
+
+impl DurationExt for Duration {
+    fn from_nanos(nanos: i64) -> Self {
+        let (seconds, nanos) = split_nanos(nanos.into());
+        Self { seconds, nanos }
+    }
+
+    fn to_nanos(&self) -> Option<i64> {
+        let nanos = i128::from(self.seconds) * NANOS_PER_SECOND + i128::from(self.nanos);
+        i64::try_from(nanos).ok()
+    }
+
+    fn from_std(duration: std::time::Duration) -> Self {
+        Self {
+            seconds: i64::try_from(duration.as_secs()).unwrap_or(i64::MAX),
+            nanos: duration.subsec_nanos() as i32,
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    fn from_time_delta(delta: chrono::TimeDelta) -> Self {
+        // A negative delta has a negative number of seconds, and positive subsecond nanoseconds.
+        let nanos =
+            i128::from(delta.num_seconds()) * NANOS_PER_SECOND + i128::from(delta.subsec_nanos());
+        let (seconds, nanos) = split_nanos(nanos);
+        Self { seconds, nanos }
+    }
+
+    #[cfg(feature = "chrono")]
+    fn to_time_delta(&self) -> Option<chrono::TimeDelta> {
+        let mut duration = *self;
+        duration.normalize();
+        let seconds = chrono::TimeDelta::try_seconds(duration.seconds)?;
+        seconds.checked_add(&chrono::TimeDelta::nanoseconds(duration.nanos.into()))
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Timestamp {}
+    impl Sealed for super::Duration {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_nanos() {
+        let ts = Timestamp::from_nanos(1_500_000_002);
+        assert_eq!((ts.seconds, ts.nanos), (1, 500_000_002));
+        assert_eq!(ts.to_nanos(), Some(1_500_000_002));
+        assert_eq!(Timestamp::from_nanos(u64::MAX).to_nanos(), Some(u64::MAX));
+
+        let before_epoch = Timestamp {
+            seconds: -1,
+            nanos: 0,
+        };
+        assert_eq!(before_epoch.to_nanos(), None);
+        // Unnormalized timestamps are still converted.
+        let unnormalized = Timestamp {
+            seconds: 2,
+            nanos: -1,
+        };
+        assert_eq!(unnormalized.to_nanos(), Some(1_999_999_999));
+
+        // Agrees with the conversion from `SystemTime`.
+        let time = UNIX_EPOCH + std::time::Duration::from_nanos(1_500_000_002);
+        assert_eq!(Timestamp::from(time), ts);
+
+        let meta = crate::PartialMetadata::published_at(&ts);
+        assert_eq!(meta.publish_time, Some(1_500_000_002));
+        assert_eq!(meta.log_time, None);
+        let meta = crate::PartialMetadata::published_at(&before_epoch);
+        assert_eq!(meta.publish_time, None);
+    }
+
+    #[test]
+    fn test_duration_nanos() {
+        let d = Duration::from_nanos(-1_500_000_000);
+        assert_eq!((d.seconds, d.nanos), (-1, -500_000_000));
+        assert_eq!(d.to_nanos(), Some(-1_500_000_000));
+        let d = Duration::from_std(std::time::Duration::from_millis(2_250));
+        assert_eq!((d.seconds, d.nanos), (2, 250_000_000));
+        let d = Duration::from_std(std::time::Duration::MAX);
+        assert_eq!(d.seconds, i64::MAX);
+        assert_eq!(d.to_nanos(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        let datetime = chrono::DateTime::from_timestamp(1_700_000_000, 5).unwrap();
+        let ts = Timestamp::from_datetime(datetime);
+        assert_eq!((ts.seconds, ts.nanos), (1_700_000_000, 5));
+        assert_eq!(ts.to_datetime(), Some(datetime));
+
+        let delta = chrono::TimeDelta::milliseconds(-1_250);
+        let d = Duration::from_time_delta(delta);
+        assert_eq!((d.seconds, d.nanos), (-1, -250_000_000));
+        assert_eq!(d.to_time_delta(), Some(delta));
+    }
+}
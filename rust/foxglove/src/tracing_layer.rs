@@ -0,0 +1,265 @@
+//! A [`tracing`] layer which logs events to a Foxglove [`Log`] channel.
+//!
+//! With the `tracing-subscriber` feature, a [`LogLayer`] can be added to a `tracing_subscriber`
+//! registry alongside other layers. Each event becomes a [`Log`] message, so application logs
+//! appear in the Log panel and in MCAP recordings next to the data they describe:
+//!
+//! ```no_run
+//! use foxglove::tracing_layer::LogLayer;
+//! use tracing_subscriber::layer::SubscriberExt;
+//! use tracing_subscriber::util::SubscriberInitExt;
+//!
+//! # fn func() -> Result<(), Box<dyn std::error::Error>> {
+//! tracing_subscriber::registry()
+//!     .with(LogLayer::new()?)
+//!     .init();
+//!
+//! tracing::info!(speed = 1.5, "Robot started");
+//! # Ok(()) }
+//! ```
+//!
+//! The event's `message` field becomes the message text, followed by any other fields as
+//! `key=value` pairs. The event's target becomes the `name`, and its source location the `file`
+//! and `line`. Use the filtering provided by `tracing_subscriber` to choose which events are
+//! logged.
+
+use std::cell::Cell;
+use std::fmt::{self, Write};
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::schemas::log::Level as LogLevel;
+use crate::schemas::{Log, Timestamp};
+use crate::time::TimestampExt;
+use crate::{FoxgloveError, PartialMetadata, TypedChannel};
+
+/// The default topic for log messages.
+pub const DEFAULT_TOPIC: &str = "/log";
+
+thread_local! {
+    // Set while logging an event, so that events emitted by sinks are not logged recursively.
+    static IN_EVENT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A `tracing_subscriber` [`Layer`] which logs events as [`Log`] messages.
+///
+/// Event levels are mapped to log levels, with `TRACE` mapped to [`LogLevel::Debug`]. Events
+/// emitted while logging an event, e.g. by a sink, are dropped.
+pub struct LogLayer {
+    channel: TypedChannel<Log>,
+}
+
+impl LogLayer {
+    /// Creates a layer which logs to the [`DEFAULT_TOPIC`].
+    ///
+    /// Returns an error if a channel already exists for the topic.
+    pub fn new() -> Result<Self, FoxgloveError> {
+        Self::with_topic(DEFAULT_TOPIC)
+    }
+
+    /// Creates a layer which logs to the given topic.
+    ///
+    /// Returns an error if a channel already exists for the topic.
+    pub fn with_topic(topic: impl Into<String>) -> Result<Self, FoxgloveError> {
+        TypedChannel::new(topic).map(Self::with_channel)
+    }
+
+    /// Creates a layer which logs to an existing channel.
+    pub fn with_channel(channel: TypedChannel<Log>) -> Self {
+        Self { channel }
+    }
+
+    /// Converts an event to a log message.
+    fn message(&self, event: &Event<'_>) -> Log {
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        Log {
+            timestamp: Some(Timestamp::from_nanos(self.channel.inner().clock.now())),
+            level: log_level(*metadata.level()).into(),
+            message: visitor.finish(),
+            name: metadata.target().to_string(),
+            file: metadata.file().unwrap_or_default().to_string(),
+            line: metadata.line().unwrap_or_default(),
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if IN_EVENT.replace(true) {
+            return;
+        }
+        let _guard = InEventGuard;
+        let msg = self.message(event);
+        let timestamp = msg.timestamp.unwrap_or_default();
+        let metadata = PartialMetadata {
+            log_time: timestamp.to_nanos(),
+            ..PartialMetadata::published_at(&timestamp)
+        };
+        self.channel.log_with_meta(&msg, metadata);
+    }
+}
+
+/// Clears [`IN_EVENT`] when dropped, including when a sink panics while logging an event.
+struct InEventGuard;
+
+impl Drop for InEventGuard {
+    fn drop(&mut self) {
+        IN_EVENT.set(false);
+    }
+}
+
+fn log_level(level: Level) -> LogLevel {
+    match level {
+        Level::TRACE | Level::DEBUG => LogLevel::Debug,
+        Level::INFO => LogLevel::Info,
+        Level::WARN => LogLevel::Warning,
+        Level::ERROR => LogLevel::Error,
+    }
+}
+
+/// Formats the fields of an event as a message.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            _ = write!(self.message, "{value:?}");
+        } else {
+            if !self.fields.is_empty() {
+                self.fields.push(' ');
+            }
+            _ = write!(self.fields, "{}={value:?}", field.name());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use prost::Message;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::testutil::RecordingSink;
+    use crate::{Channel, ChannelBuilder, FoxgloveError, LogContext, LogSink, Metadata};
+
+    #[test]
+    fn test_log_layer() {
+        let ctx = LogContext::new();
+        ctx.set_clock(ManualClock::new(1_000_000_042));
+        let sink = Arc::new(RecordingSink::new());
+        assert!(ctx.add_sink(sink.clone()));
+        let channel = ChannelBuilder::new("/log")
+            .with_context(&ctx)
+            .build_typed::<Log>()
+            .unwrap();
+        let subscriber = tracing_subscriber::registry().with(LogLayer::with_channel(channel));
+
+        let line = tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(target: "robot", speed = 1.5, name = "wall-e", "Robot {} started", 1);
+            tracing::trace!(done = true);
+            line!() - 2
+        });
+
+        let recorded = sink.recorded.lock();
+        let logs: Vec<Log> = recorded
+            .iter()
+            .map(|r| Log::decode(r.msg.as_slice()).unwrap())
+            .collect();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].level(), LogLevel::Warning);
+        assert_eq!(
+            logs[0].message,
+            r#"Robot 1 started speed=1.5 name="wall-e""#
+        );
+        assert_eq!(logs[0].name, "robot");
+        assert_eq!(logs[0].file, file!());
+        assert_eq!(logs[0].line, line);
+        assert_eq!(
+            logs[0].timestamp,
+            Some(Timestamp {
+                seconds: 1,
+                nanos: 42
+            })
+        );
+        assert_eq!(recorded[0].metadata.log_time, 1_000_000_042);
+        assert_eq!(recorded[0].metadata.publish_time, 1_000_000_042);
+
+        assert_eq!(logs[1].level(), LogLevel::Debug);
+        assert_eq!(logs[1].message, "done=true");
+        assert_eq!(logs[1].name, module_path!());
+    }
+
+    /// A sink which panics the first time it logs a message.
+    #[derive(Default)]
+    struct PanicOnceSink {
+        panicked: AtomicBool,
+    }
+
+    impl LogSink for PanicOnceSink {
+        fn log(
+            &self,
+            _channel: &Arc<Channel>,
+            _msg: &[u8],
+            _metadata: &Metadata,
+        ) -> Result<(), FoxgloveError> {
+            if !self.panicked.swap(true, Ordering::Relaxed) {
+                panic!("sink panicked");
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_log_layer_recovers_from_panic() {
+        let ctx = LogContext::new();
+        assert!(ctx.add_sink(Arc::new(PanicOnceSink::default())));
+        let sink = Arc::new(RecordingSink::new());
+        assert!(ctx.add_sink(sink.clone()));
+        let channel = ChannelBuilder::new("/log")
+            .with_context(&ctx)
+            .build_typed::<Log>()
+            .unwrap();
+        let subscriber = tracing_subscriber::registry().with(LogLayer::with_channel(channel));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let result = std::panic::catch_unwind(|| tracing::info!("first"));
+            assert!(result.is_err());
+            tracing::info!("second");
+        });
+
+        let recorded = sink.recorded.lock();
+        assert_eq!(recorded.len(), 1);
+        let log = Log::decode(recorded[0].msg.as_slice()).unwrap();
+        assert_eq!(log.message, "second");
+    }
+}
@@ -0,0 +1,785 @@
+//! Frame transform management.
+//!
+//! The 3D panel positions everything it renders using a tree of coordinate frames, built from
+//! [`FrameTransform`] messages. This module provides the pieces that most robots need to maintain
+//! and publish that tree:
+//!
+//! - [`TransformBuffer`] keeps a time-limited history of transforms between frames, and looks up
+//!   the transform between any two connected frames, interpolating between samples.
+//! - [`TransformPublisher`] records transforms in a buffer, and publishes them in
+//!   [`FrameTransforms`] batches on a channel. Static transforms are latched, and republished with
+//!   every batch so that clients which connect later still receive them.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! use foxglove::schemas::{FrameTransform, Vector3};
+//! use foxglove::transforms::TransformPublisher;
+//!
+//! # fn func() -> Result<(), Box<dyn std::error::Error>> {
+//! let publisher = Arc::new(TransformPublisher::new("/tf")?);
+//! let _task = publisher.publish_periodically(Duration::from_millis(100));
+//!
+//! publisher.add_static(FrameTransform {
+//!     parent_frame_id: "base_link".to_string(),
+//!     child_frame_id: "camera".to_string(),
+//!     translation: Some(Vector3 { x: 0.1, y: 0.0, z: 0.5 }),
+//!     ..Default::default()
+//! })?;
+//!
+//! let camera_in_base = publisher.lookup("base_link", "camera", 0)?;
+//! # Ok(()) }
+//! ```
+//!
+//! Times are in nanoseconds since the Unix epoch.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use thiserror::Error;
+use tokio::task::JoinHandle;
+
+use crate::schemas::{FrameTransform, FrameTransforms, Quaternion, Timestamp, Vector3};
+use crate::time::TimestampExt;
+use crate::{get_runtime_handle, FoxgloveError, TypedChannel};
+
+/// The default duration for which a [`TransformBuffer`] keeps transforms.
+pub const DEFAULT_CACHE_DURATION: Duration = Duration::from_secs(10);
+
+/// An error encountered while adding or looking up transforms.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TransformError {
+    /// The transform has no timestamp, or a timestamp before the Unix epoch.
+    #[error("Transform for frame {0} has a missing or invalid timestamp")]
+    InvalidTimestamp(String),
+    /// The transform would make a frame its own ancestor.
+    #[error("Transform from {parent} to {child} would create a cycle")]
+    Cycle {
+        /// The parent frame of the transform.
+        parent: String,
+        /// The child frame of the transform.
+        child: String,
+    },
+    /// The frame is not in the buffer.
+    #[error("Unknown frame {0}")]
+    UnknownFrame(String),
+    /// The frames are in the buffer, but not in the same tree.
+    #[error("Frames {parent} and {child} are not connected")]
+    NotConnected {
+        /// The requested parent frame.
+        parent: String,
+        /// The requested child frame.
+        child: String,
+    },
+    /// The buffer has no transform for the frame at the requested time.
+    #[error("No transform for frame {frame} at time {time}")]
+    Extrapolation {
+        /// The frame whose transform to its parent is not available.
+        frame: String,
+        /// The requested time.
+        time: u64,
+    },
+}
+
+/// A rigid transform, as a translation and a unit rotation quaternion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Transform {
+    translation: [f64; 3],
+    /// The rotation, as `[x, y, z, w]`.
+    rotation: [f64; 4],
+}
+
+impl Transform {
+    const IDENTITY: Self = Self {
+        translation: [0.0; 3],
+        rotation: [0.0, 0.0, 0.0, 1.0],
+    };
+
+    fn from_message(msg: &FrameTransform) -> Self {
+        let translation = msg
+            .translation
+            .as_ref()
+            .map_or([0.0; 3], |t| [t.x, t.y, t.z]);
+        let rotation = msg
+            .rotation
+            .as_ref()
+            .map_or([0.0, 0.0, 0.0, 1.0], |r| normalize([r.x, r.y, r.z, r.w]));
+        Self {
+            translation,
+            rotation,
+        }
+    }
+
+    /// Returns `self * other`, i.e. `other` applied first, then `self`.
+    fn then(&self, other: &Self) -> Self {
+        let rotated = rotate(self.rotation, other.translation);
+        Self {
+            translation: [
+                self.translation[0] + rotated[0],
+                self.translation[1] + rotated[1],
+                self.translation[2] + rotated[2],
+            ],
+            rotation: multiply(self.rotation, other.rotation),
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        let [x, y, z, w] = self.rotation;
+        let rotation = [-x, -y, -z, w];
+        let t = rotate(rotation, self.translation);
+        Self {
+            translation: [-t[0], -t[1], -t[2]],
+            rotation,
+        }
+    }
+
+    /// Interpolates between two transforms, with `t` in `[0, 1]`.
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        Self {
+            translation: [
+                lerp(self.translation[0], other.translation[0]),
+                lerp(self.translation[1], other.translation[1]),
+                lerp(self.translation[2], other.translation[2]),
+            ],
+            rotation: slerp(self.rotation, other.rotation, t),
+        }
+    }
+
+    fn to_message(self, parent: &str, child: &str, time: u64) -> FrameTransform {
+        let [x, y, z] = self.translation;
+        let [qx, qy, qz, qw] = self.rotation;
+        FrameTransform {
+            timestamp: Some(Timestamp::from_nanos(time)),
+            parent_frame_id: parent.to_string(),
+            child_frame_id: child.to_string(),
+            translation: Some(Vector3 { x, y, z }),
+            rotation: Some(Quaternion {
+                x: qx,
+                y: qy,
+                z: qz,
+                w: qw,
+            }),
+        }
+    }
+}
+
+fn normalize(q: [f64; 4]) -> [f64; 4] {
+    let norm = q.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if norm == 0.0 || !norm.is_finite() {
+        return [0.0, 0.0, 0.0, 1.0];
+    }
+    q.map(|v| v / norm)
+}
+
+fn multiply(a: [f64; 4], b: [f64; 4]) -> [f64; 4] {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn rotate(q: [f64; 4], v: [f64; 3]) -> [f64; 3] {
+    let u = [q[0], q[1], q[2]];
+    let w = q[3];
+    let uv = cross(u, v);
+    let uuv = cross(u, uv);
+    [
+        v[0] + 2.0 * (w * uv[0] + uuv[0]),
+        v[1] + 2.0 * (w * uv[1] + uuv[1]),
+        v[2] + 2.0 * (w * uv[2] + uuv[2]),
+    ]
+}
+
+fn slerp(a: [f64; 4], b: [f64; 4], t: f64) -> [f64; 4] {
+    let mut dot: f64 = a.iter().zip(&b).map(|(a, b)| a * b).sum();
+    // Take the shortest path.
+    let b = if dot < 0.0 {
+        dot = -dot;
+        b.map(|v| -v)
+    } else {
+        b
+    };
+    let (wa, wb) = if dot > 0.9995 {
+        // The quaternions are nearly parallel; fall back to linear interpolation.
+        (1.0 - t, t)
+    } else {
+        let theta = dot.acos();
+        let sin = theta.sin();
+        (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+    };
+    normalize([
+        wa * a[0] + wb * b[0],
+        wa * a[1] + wb * b[1],
+        wa * a[2] + wb * b[2],
+        wa * a[3] + wb * b[3],
+    ])
+}
+
+/// The transforms from a frame to its parent.
+#[derive(Debug)]
+struct Frame {
+    parent: String,
+    /// The static transform, if the frame is static.
+    fixed: Option<Transform>,
+    /// The history of transforms, by time.
+    history: BTreeMap<u64, Transform>,
+}
+
+impl Frame {
+    fn transform_at(&self, frame: &str, time: u64) -> Result<Transform, TransformError> {
+        if let Some(fixed) = self.fixed {
+            return Ok(fixed);
+        }
+        let before = self.history.range(..=time).next_back();
+        let after = self
+            .history
+            .range((Bound::Excluded(time), Bound::Unbounded))
+            .next();
+        match (before, after) {
+            (Some((&t, transform)), _) if t == time => Ok(*transform),
+            (Some((&t0, before)), Some((&t1, after))) => {
+                let t = (time - t0) as f64 / (t1 - t0) as f64;
+                Ok(before.interpolate(after, t))
+            }
+            _ => Err(TransformError::Extrapolation {
+                frame: frame.to_string(),
+                time,
+            }),
+        }
+    }
+}
+
+/// A buffer of transforms between coordinate frames.
+///
+/// Frames form a forest: each frame has at most one parent, set by the most recent transform
+/// whose child is that frame. Dynamic transforms are kept for a limited duration, relative to the
+/// most recent transform for the same frame, and are interpolated when looking up a transform
+/// between samples. Static transforms apply at all times.
+#[derive(Debug)]
+pub struct TransformBuffer {
+    frames: HashMap<String, Frame>,
+    cache_duration: u64,
+}
+
+impl Default for TransformBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransformBuffer {
+    /// Creates an empty buffer, which keeps transforms for [`DEFAULT_CACHE_DURATION`].
+    pub fn new() -> Self {
+        Self::with_cache_duration(DEFAULT_CACHE_DURATION)
+    }
+
+    /// Creates an empty buffer, which keeps transforms for the given duration.
+    pub fn with_cache_duration(duration: Duration) -> Self {
+        Self {
+            frames: HashMap::new(),
+            cache_duration: u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX),
+        }
+    }
+
+    /// Returns true if the buffer has a transform for the frame to its parent.
+    pub fn has_frame(&self, frame: &str) -> bool {
+        self.frames.contains_key(frame)
+    }
+
+    /// Returns the parent of the frame, if it has one.
+    pub fn parent(&self, frame: &str) -> Option<&str> {
+        self.frames.get(frame).map(|f| f.parent.as_str())
+    }
+
+    /// Adds a dynamic transform, which is valid at its timestamp.
+    pub fn insert(&mut self, transform: &FrameTransform) -> Result<(), TransformError> {
+        let time = transform
+            .timestamp
+            .as_ref()
+            .and_then(Timestamp::to_nanos)
+            .ok_or_else(|| TransformError::InvalidTimestamp(transform.child_frame_id.clone()))?;
+        let cache_duration = self.cache_duration;
+        let frame = self.frame_mut(transform, false)?;
+        frame
+            .history
+            .insert(time, Transform::from_message(transform));
+        let latest = *frame.history.keys().next_back().expect("not empty");
+        let oldest = latest.saturating_sub(cache_duration);
+        frame.history = frame.history.split_off(&oldest);
+        Ok(())
+    }
+
+    /// Adds a static transform, which is valid at all times. The timestamp is ignored.
+    pub fn insert_static(&mut self, transform: &FrameTransform) -> Result<(), TransformError> {
+        let frame = self.frame_mut(transform, true)?;
+        frame.fixed = Some(Transform::from_message(transform));
+        Ok(())
+    }
+
+    /// Returns the frame for the child of the transform, replacing it if its parent or kind
+    /// has changed.
+    fn frame_mut(
+        &mut self,
+        transform: &FrameTransform,
+        fixed: bool,
+    ) -> Result<&mut Frame, TransformError> {
+        let parent = &transform.parent_frame_id;
+        let child = &transform.child_frame_id;
+        if self.ancestors(parent).any(|f| f == child) {
+            return Err(TransformError::Cycle {
+                parent: parent.clone(),
+                child: child.clone(),
+            });
+        }
+        let new_frame = || Frame {
+            parent: parent.clone(),
+            fixed: None,
+            history: BTreeMap::new(),
+        };
+        let frame = match self.frames.entry(child.clone()) {
+            Entry::Occupied(entry) => {
+                let frame = entry.into_mut();
+                if frame.parent != *parent || frame.fixed.is_some() != fixed {
+                    *frame = new_frame();
+                }
+                frame
+            }
+            Entry::Vacant(entry) => entry.insert(new_frame()),
+        };
+        Ok(frame)
+    }
+
+    /// Returns the frame and its ancestors, starting with the frame itself.
+    fn ancestors<'a>(&'a self, frame: &'a str) -> impl Iterator<Item = &'a str> {
+        std::iter::successors(Some(frame), |f| self.parent(f))
+    }
+
+    /// Returns the transform from `ancestor` to `frame` at the given time.
+    fn transform_from(
+        &self,
+        ancestor: &str,
+        frame: &str,
+        time: u64,
+    ) -> Result<Transform, TransformError> {
+        let mut result = Transform::IDENTITY;
+        for f in self.ancestors(frame).take_while(|f| *f != ancestor) {
+            let transform = self.frames[f].transform_at(f, time)?;
+            result = transform.then(&result);
+        }
+        Ok(result)
+    }
+
+    /// Looks up the transform from the `parent` frame to the `child` frame at the given time.
+    ///
+    /// The result expresses the pose of the child frame in the parent frame. The frames need not
+    /// be directly related, but must share a common ancestor. The returned transform has the
+    /// requested parent and child frame IDs and timestamp.
+    pub fn lookup(
+        &self,
+        parent: &str,
+        child: &str,
+        time: u64,
+    ) -> Result<FrameTransform, TransformError> {
+        for frame in [parent, child] {
+            if !self.has_frame(frame) && !self.frames.values().any(|f| f.parent == frame) {
+                return Err(TransformError::UnknownFrame(frame.to_string()));
+            }
+        }
+        let parent_ancestors: Vec<_> = self.ancestors(parent).collect();
+        let Some(common) = self.ancestors(child).find(|f| parent_ancestors.contains(f)) else {
+            return Err(TransformError::NotConnected {
+                parent: parent.to_string(),
+                child: child.to_string(),
+            });
+        };
+        let common_to_parent = self.transform_from(common, parent, time)?;
+        let common_to_child = self.transform_from(common, child, time)?;
+        let transform = common_to_parent.inverse().then(&common_to_child);
+        Ok(transform.to_message(parent, child, time))
+    }
+}
+
+#[derive(Default)]
+struct PublisherState {
+    buffer: TransformBuffer,
+    /// Dynamic transforms added since the last batch was published.
+    pending: Vec<FrameTransform>,
+    /// Latched static transforms, by child frame.
+    statics: BTreeMap<String, FrameTransform>,
+}
+
+/// Records transforms, and publishes them in batches on a [`FrameTransforms`] channel.
+///
+/// Dynamic transforms are published once, in the next batch after they are added. Static
+/// transforms are latched: they are republished with every batch, stamped with the time of the
+/// batch, so that clients which connect later still receive them.
+///
+/// See the [module documentation](self) for an example.
+pub struct TransformPublisher {
+    channel: TypedChannel<FrameTransforms>,
+    state: Mutex<PublisherState>,
+}
+
+impl TransformPublisher {
+    /// Creates a publisher for a new channel on the given topic, with a default buffer.
+    pub fn new(topic: impl Into<String>) -> Result<Self, FoxgloveError> {
+        Ok(Self::with_channel(
+            TypedChannel::new(topic)?,
+            TransformBuffer::new(),
+        ))
+    }
+
+    /// Creates a publisher for an existing channel, with the given buffer.
+    pub fn with_channel(channel: TypedChannel<FrameTransforms>, buffer: TransformBuffer) -> Self {
+        Self {
+            channel,
+            state: Mutex::new(PublisherState {
+                buffer,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Adds a dynamic transform to the buffer, and queues it for publishing.
+    ///
+    /// If the child frame previously had a static transform, it is no longer latched.
+    pub fn add(&self, transform: FrameTransform) -> Result<(), TransformError> {
+        let mut state = self.state.lock();
+        state.buffer.insert(&transform)?;
+        state.statics.remove(&transform.child_frame_id);
+        state.pending.push(transform);
+        Ok(())
+    }
+
+    /// Adds a static transform to the buffer, and latches it for publishing.
+    pub fn add_static(&self, transform: FrameTransform) -> Result<(), TransformError> {
+        let mut state = self.state.lock();
+        state.buffer.insert_static(&transform)?;
+        state
+            .statics
+            .insert(transform.child_frame_id.clone(), transform);
+        Ok(())
+    }
+
+    /// Looks up a transform in the buffer. See [`TransformBuffer::lookup`].
+    pub fn lookup(
+        &self,
+        parent: &str,
+        child: &str,
+        time: u64,
+    ) -> Result<FrameTransform, TransformError> {
+        self.state.lock().buffer.lookup(parent, child, time)
+    }
+
+    /// Publishes queued dynamic transforms and latched static transforms as a single batch.
+    ///
+    /// Does nothing if there are no transforms to publish.
+    pub fn publish(&self) {
+        let batch = {
+            let mut state = self.state.lock();
+            if state.pending.is_empty() && state.statics.is_empty() {
+                return;
+            }
+            let now = Some(Timestamp::from_nanos(self.channel.inner().clock.now()));
+            let statics = state.statics.values().map(|transform| FrameTransform {
+                timestamp: now,
+                ..transform.clone()
+            });
+            let mut transforms: Vec<_> = statics.collect();
+            transforms.append(&mut state.pending);
+            FrameTransforms { transforms }
+        };
+        self.channel.log(&batch);
+    }
+
+    /// Spawns a task which calls [`publish`](Self::publish) at the given interval.
+    ///
+    /// The task stops when the publisher is dropped, or when the returned handle is aborted.
+    pub fn publish_periodically(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let publisher: Weak<Self> = Arc::downgrade(self);
+        get_runtime_handle().spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let Some(publisher) = publisher.upgrade() else {
+                    break;
+                };
+                publisher.publish();
+            }
+        })
+    }
+}
+
+impl std::fmt::Debug for TransformPublisher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransformPublisher")
+            .field("topic", &self.channel.topic())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use prost::Message;
+
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::testutil::RecordingSink;
+    use crate::{ChannelBuilder, LogContext};
+
+    fn transform(
+        parent: &str,
+        child: &str,
+        time: u64,
+        translation: [f64; 3],
+        rotation: [f64; 4],
+    ) -> FrameTransform {
+        Transform {
+            translation,
+            rotation,
+        }
+        .to_message(parent, child, time)
+    }
+
+    /// A rotation of 90 degrees about the z axis.
+    const YAW_90: [f64; 4] = [0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2];
+    const IDENTITY: [f64; 4] = [0.0, 0.0, 0.0, 1.0];
+
+    fn assert_transform(actual: &FrameTransform, translation: [f64; 3], rotation: [f64; 4]) {
+        let actual = Transform::from_message(actual);
+        for (a, e) in actual.translation.iter().zip(translation) {
+            assert!((a - e).abs() < 1e-9, "{actual:?} != {translation:?}");
+        }
+        // q and -q represent the same rotation.
+        let dot: f64 = actual
+            .rotation
+            .iter()
+            .zip(rotation)
+            .map(|(a, e)| a * e)
+            .sum();
+        assert!((dot.abs() - 1.0).abs() < 1e-9, "{actual:?} != {rotation:?}");
+    }
+
+    #[test]
+    fn test_lookup_chain() {
+        let mut buffer = TransformBuffer::new();
+        buffer
+            .insert_static(&transform("world", "base", 0, [1.0, 0.0, 0.0], YAW_90))
+            .unwrap();
+        buffer
+            .insert_static(&transform("base", "camera", 0, [1.0, 0.0, 0.0], IDENTITY))
+            .unwrap();
+        buffer
+            .insert_static(&transform("base", "lidar", 0, [0.0, 0.0, 1.0], IDENTITY))
+            .unwrap();
+
+        // The camera is 1m along the base's x axis, which points along the world's y axis.
+        let result = buffer.lookup("world", "camera", 5).unwrap();
+        assert_eq!(result.parent_frame_id, "world");
+        assert_eq!(result.child_frame_id, "camera");
+        assert_eq!(result.timestamp, Some(Timestamp::from_nanos(5)));
+        assert_transform(&result, [1.0, 1.0, 0.0], YAW_90);
+
+        // The inverse lookup.
+        let result = buffer.lookup("camera", "world", 5).unwrap();
+        assert_transform(
+            &result,
+            [-1.0, 1.0, 0.0],
+            [0.0, 0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+        );
+
+        // Siblings are related through their common parent.
+        let result = buffer.lookup("camera", "lidar", 5).unwrap();
+        assert_transform(&result, [-1.0, 0.0, 1.0], IDENTITY);
+
+        let result = buffer.lookup("world", "world", 5).unwrap();
+        assert_transform(&result, [0.0; 3], IDENTITY);
+    }
+
+    #[test]
+    fn test_lookup_interpolates() {
+        let mut buffer = TransformBuffer::new();
+        buffer
+            .insert(&transform("odom", "base", 100, [0.0; 3], IDENTITY))
+            .unwrap();
+        buffer
+            .insert(&transform("odom", "base", 200, [2.0, 4.0, 0.0], YAW_90))
+            .unwrap();
+
+        let result = buffer.lookup("odom", "base", 150).unwrap();
+        let half = (std::f64::consts::PI / 8.0).sin_cos();
+        assert_transform(&result, [1.0, 2.0, 0.0], [0.0, 0.0, half.0, half.1]);
+
+        let result = buffer.lookup("odom", "base", 200).unwrap();
+        assert_transform(&result, [2.0, 4.0, 0.0], YAW_90);
+
+        for time in [99, 201] {
+            assert_eq!(
+                buffer.lookup("odom", "base", time),
+                Err(TransformError::Extrapolation {
+                    frame: "base".to_string(),
+                    time
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_cache_duration() {
+        let mut buffer = TransformBuffer::with_cache_duration(Duration::from_nanos(100));
+        for time in [0, 50, 100, 150] {
+            buffer
+                .insert(&transform("odom", "base", time, [0.0; 3], IDENTITY))
+                .unwrap();
+        }
+        assert!(buffer.lookup("odom", "base", 49).is_err());
+        assert!(buffer.lookup("odom", "base", 50).is_ok());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut buffer = TransformBuffer::new();
+        buffer
+            .insert_static(&transform("a", "b", 0, [0.0; 3], IDENTITY))
+            .unwrap();
+        buffer
+            .insert_static(&transform("c", "d", 0, [0.0; 3], IDENTITY))
+            .unwrap();
+
+        assert_eq!(
+            buffer.insert_static(&transform("b", "a", 0, [0.0; 3], IDENTITY)),
+            Err(TransformError::Cycle {
+                parent: "b".to_string(),
+                child: "a".to_string()
+            })
+        );
+        assert!(buffer
+            .insert_static(&transform("a", "a", 0, [0.0; 3], IDENTITY))
+            .is_err());
+        assert_eq!(
+            buffer.insert(&FrameTransform {
+                child_frame_id: "e".to_string(),
+                ..Default::default()
+            }),
+            Err(TransformError::InvalidTimestamp("e".to_string()))
+        );
+        assert_eq!(
+            buffer.lookup("a", "x", 0),
+            Err(TransformError::UnknownFrame("x".to_string()))
+        );
+        assert_eq!(
+            buffer.lookup("a", "d", 0),
+            Err(TransformError::NotConnected {
+                parent: "a".to_string(),
+                child: "d".to_string()
+            })
+        );
+
+        // Reparenting a frame replaces its transforms.
+        buffer
+            .insert(&transform("c", "b", 10, [0.0; 3], IDENTITY))
+            .unwrap();
+        assert_eq!(buffer.parent("b"), Some("c"));
+        assert!(buffer.lookup("c", "b", 0).is_err());
+        assert!(buffer.lookup("d", "b", 10).is_ok());
+    }
+
+    #[test]
+    fn test_publish() {
+        let ctx = LogContext::new();
+        ctx.set_clock(ManualClock::new(1_000));
+        let sink = Arc::new(RecordingSink::new());
+        assert!(ctx.add_sink(sink.clone()));
+        let channel = ChannelBuilder::new("/tf")
+            .with_context(&ctx)
+            .build_typed()
+            .unwrap();
+        let publisher = TransformPublisher::with_channel(channel, TransformBuffer::new());
+
+        // Nothing to publish.
+        publisher.publish();
+
+        let fixed = transform("base", "camera", 0, [1.0, 0.0, 0.0], IDENTITY);
+        let moving = transform("odom", "base", 500, [0.0; 3], IDENTITY);
+        publisher.add_static(fixed.clone()).unwrap();
+        publisher.add(moving.clone()).unwrap();
+        assert!(publisher.lookup("odom", "camera", 500).is_ok());
+        publisher.publish();
+        publisher.publish();
+
+        let recorded = sink.recorded.lock();
+        let batches: Vec<_> = recorded
+            .iter()
+            .map(|r| FrameTransforms::decode(r.msg.as_slice()).unwrap())
+            .collect();
+        let latched = FrameTransform {
+            timestamp: Some(Timestamp::from_nanos(1_000)),
+            ..fixed
+        };
+        assert_eq!(
+            batches,
+            [
+                FrameTransforms {
+                    transforms: vec![latched.clone(), moving]
+                },
+                FrameTransforms {
+                    transforms: vec![latched]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_publish_static_to_dynamic() {
+        let ctx = LogContext::new();
+        ctx.set_clock(ManualClock::new(1_000));
+        let sink = Arc::new(RecordingSink::new());
+        assert!(ctx.add_sink(sink.clone()));
+        let channel = ChannelBuilder::new("/tf")
+            .with_context(&ctx)
+            .build_typed()
+            .unwrap();
+        let publisher = TransformPublisher::with_channel(channel, TransformBuffer::new());
+
+        let fixed = transform("base", "camera", 0, [1.0, 0.0, 0.0], IDENTITY);
+        let moving = transform("base", "camera", 500, [2.0, 0.0, 0.0], IDENTITY);
+        publisher.add_static(fixed).unwrap();
+        publisher.add(moving.clone()).unwrap();
+        publisher.publish();
+        // The static transform is no longer latched.
+        publisher.publish();
+
+        let recorded = sink.recorded.lock();
+        let batches: Vec<_> = recorded
+            .iter()
+            .map(|r| FrameTransforms::decode(r.msg.as_slice()).unwrap())
+            .collect();
+        assert_eq!(
+            batches,
+            [FrameTransforms {
+                transforms: vec![moving]
+            }]
+        );
+    }
+}
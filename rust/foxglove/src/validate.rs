@@ -0,0 +1,290 @@
+//! Validation of logged messages against their schemas.
+//!
+//! Messages logged on a [`Channel`] must be encoded according to the channel's schema, but this
+//! is not checked when logging. A [`ValidationSink`] decodes each message against its schema, and
+//! reports violations as warnings through `tracing`, so that producer bugs are caught before they
+//! end up in recordings. Since decoding every message is expensive, validation is intended for
+//! debug builds and tests.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use foxglove::validate::ValidationSink;
+//! use foxglove::LogContext;
+//!
+//! let validator = Arc::new(ValidationSink::new());
+//! if cfg!(debug_assertions) {
+//!     LogContext::global().add_sink(validator.clone());
+//! }
+//!
+//! // ... log messages ...
+//!
+//! assert_eq!(validator.violations(), 0);
+//! ```
+//!
+//! The following schema and message encodings are validated:
+//!
+//! | Schema encoding | Message encoding |
+//! |-----------------|------------------|
+//! | `jsonschema`    | `json`           |
+//! | `protobuf`      | `protobuf`       |
+//!
+//! Messages on channels with other encodings, or without a schema, are not validated. A schema
+//! which cannot be parsed is reported once, and counted as a single violation.
+//!
+//! This module requires the `validate` feature.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use thiserror::Error;
+
+use crate::channel::ChannelId;
+use crate::{Channel, FoxgloveError, LogSink, Metadata, Schema};
+
+/// A reason that a schema or message is invalid.
+#[derive(Error, Debug)]
+enum Violation {
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    JsonSchema(String),
+    #[error("Invalid protobuf: {0}")]
+    Protobuf(#[from] prost_reflect::DescriptorError),
+    #[error("Message {0} not found in protobuf schema")]
+    MessageNotFound(String),
+    #[error("Invalid protobuf message: {0}")]
+    ProtobufMessage(#[from] prost_reflect::prost::DecodeError),
+}
+
+/// Validates messages against a schema.
+enum Validator {
+    Json(jsonschema::Validator),
+    Protobuf(MessageDescriptor),
+}
+
+impl Validator {
+    /// Returns a validator for the channel, or `None` if its encoding is not supported.
+    fn new(channel: &Channel) -> Result<Option<Self>, Violation> {
+        let Some(schema) = channel.schema() else {
+            return Ok(None);
+        };
+        match (schema.encoding.as_str(), channel.message_encoding.as_str()) {
+            ("jsonschema", "json") => Self::json(schema).map(Some),
+            ("protobuf", "protobuf") => Self::protobuf(schema).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    fn json(schema: &Schema) -> Result<Self, Violation> {
+        let schema = serde_json::from_slice(&schema.data)?;
+        let validator = jsonschema::validator_for(&schema)
+            .map_err(|err| Violation::JsonSchema(format!("Invalid JSON schema: {err}")))?;
+        Ok(Self::Json(validator))
+    }
+
+    fn protobuf(schema: &Schema) -> Result<Self, Violation> {
+        let pool = DescriptorPool::decode(schema.data.as_ref())?;
+        let descriptor = pool
+            .get_message_by_name(&schema.name)
+            .ok_or_else(|| Violation::MessageNotFound(schema.name.clone()))?;
+        Ok(Self::Protobuf(descriptor))
+    }
+
+    fn validate(&self, msg: &[u8]) -> Result<(), Violation> {
+        match self {
+            Self::Json(validator) => {
+                let value = serde_json::from_slice(msg)?;
+                validator
+                    .validate(&value)
+                    .map_err(|err| Violation::JsonSchema(format!("{err} at {}", err.instance_path)))
+            }
+            Self::Protobuf(descriptor) => {
+                DynamicMessage::decode(descriptor.clone(), msg)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A sink which validates logged messages against their channel's schema.
+///
+/// Violations are reported as warnings through `tracing`, and counted. Messages are not modified
+/// or dropped, and other sinks receive them as usual.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Default)]
+pub struct ValidationSink {
+    /// Validators for each channel, or `None` if the channel is not validated.
+    validators: Mutex<HashMap<ChannelId, Option<Arc<Validator>>>>,
+    violations: AtomicU64,
+}
+
+impl ValidationSink {
+    /// Creates a new validation sink.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of violations reported so far.
+    pub fn violations(&self) -> u64 {
+        self.violations.load(Relaxed)
+    }
+
+    /// Returns the validator for the channel, creating it the first time the channel is seen.
+    fn validator(&self, channel: &Channel) -> Option<Arc<Validator>> {
+        let mut validators = self.validators.lock();
+        validators
+            .entry(channel.id())
+            .or_insert_with(|| match Validator::new(channel) {
+                Ok(validator) => validator.map(Arc::new),
+                Err(err) => {
+                    self.violations.fetch_add(1, Relaxed);
+                    tracing::warn!(topic = channel.topic(), "Invalid schema: {err}");
+                    None
+                }
+            })
+            .clone()
+    }
+}
+
+impl LogSink for ValidationSink {
+    fn log(
+        &self,
+        channel: &Arc<Channel>,
+        msg: &[u8],
+        metadata: &Metadata,
+    ) -> Result<(), FoxgloveError> {
+        let Some(validator) = self.validator(channel) else {
+            return Ok(());
+        };
+        if let Err(err) = validator.validate(msg) {
+            self.violations.fetch_add(1, Relaxed);
+            tracing::warn!(
+                topic = channel.topic(),
+                sequence = metadata.sequence,
+                "Message does not match schema: {err}"
+            );
+        }
+        Ok(())
+    }
+
+    fn remove_channel(&self, channel: &Channel) {
+        self.validators.lock().remove(&channel.id());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use serde::Serialize;
+    use tracing_test::traced_test;
+
+    use super::*;
+    use crate::schemas::Log;
+    use crate::{ChannelBuilder, Encode, LogContext};
+
+    fn context() -> (LogContext, Arc<ValidationSink>) {
+        let ctx = LogContext::new();
+        let sink = Arc::new(ValidationSink::new());
+        assert!(ctx.add_sink(sink.clone()));
+        (ctx, sink)
+    }
+
+    #[derive(Serialize, schemars::JsonSchema)]
+    struct Count {
+        count: u32,
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_validate_json() {
+        let (ctx, sink) = context();
+        let channel = ChannelBuilder::new("/count")
+            .with_context(&ctx)
+            .build_typed::<Count>()
+            .unwrap();
+        channel.log(&Count { count: 1 });
+        assert_eq!(sink.violations(), 0);
+
+        let channel = ctx.get_channel_by_topic("/count").unwrap();
+        channel.log(br#"{"count": -1}"#);
+        assert_eq!(sink.violations(), 1);
+        assert!(logs_contain("Message does not match schema"));
+        assert!(logs_contain("/count"));
+
+        channel.log(b"not json");
+        assert_eq!(sink.violations(), 2);
+        assert!(logs_contain("Invalid JSON"));
+    }
+
+    #[test]
+    fn test_validate_protobuf() {
+        let (ctx, sink) = context();
+        let channel = ChannelBuilder::new("/log")
+            .with_context(&ctx)
+            .build_typed::<Log>()
+            .unwrap();
+        channel.log(&Log {
+            message: "hello".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(sink.violations(), 0);
+
+        // The level field, with a length-delimited wire type instead of a varint.
+        let channel = ctx.get_channel_by_topic("/log").unwrap();
+        let msg = [0x12, 0x01, 0x00];
+        channel.log(&msg);
+        assert_eq!(sink.violations(), 1);
+        assert!(Log::decode(msg.as_slice()).is_err());
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_invalid_and_unsupported_schemas() {
+        let (ctx, sink) = context();
+        let channel = ChannelBuilder::new("/invalid")
+            .with_context(&ctx)
+            .message_encoding("protobuf")
+            .schema(Schema::new("foxglove.Log", "protobuf", &[0xff]))
+            .build()
+            .unwrap();
+        channel.log(b"");
+        channel.log(b"");
+        // The schema is only reported once.
+        assert_eq!(sink.violations(), 1);
+        assert!(logs_contain("Invalid schema"));
+
+        let channel = ChannelBuilder::new("/missing")
+            .with_context(&ctx)
+            .message_encoding("protobuf")
+            .schema(Schema {
+                name: "foxglove.Missing".to_string(),
+                ..Log::get_schema().unwrap()
+            })
+            .build()
+            .unwrap();
+        channel.log(b"");
+        assert_eq!(sink.violations(), 2);
+        assert!(logs_contain("Message foxglove.Missing not found"));
+
+        let channel = ChannelBuilder::new("/raw")
+            .with_context(&ctx)
+            .message_encoding("cdr")
+            .schema(Schema::new("raw", "ros2msg", b"uint8 data".as_slice()))
+            .build()
+            .unwrap();
+        channel.log(b"anything");
+        let channel = ChannelBuilder::new("/schemaless")
+            .with_context(&ctx)
+            .message_encoding("json")
+            .build()
+            .unwrap();
+        channel.log(b"anything");
+        assert_eq!(sink.violations(), 2);
+    }
+}
@@ -0,0 +1,311 @@
+//! Software video encoding for [`CompressedVideo`] channels.
+//!
+//! Streaming camera frames as video uses far less bandwidth than sending each frame as a JPEG.
+//! With the `h264` feature, an [`H264Encoder`] encodes [`RawImage`] frames on the CPU with
+//! [OpenH264](https://www.openh264.org/), and emits [`CompressedVideo`] messages which can be
+//! logged directly:
+//!
+//! ```no_run
+//! use foxglove::schemas::{CompressedVideo, RawImage};
+//! use foxglove::video::H264Encoder;
+//! use foxglove::TypedChannel;
+//!
+//! # fn func(frames: impl Iterator<Item = RawImage>) -> Result<(), Box<dyn std::error::Error>> {
+//! let channel = TypedChannel::<CompressedVideo>::new("/camera/video")?;
+//! let mut encoder = H264Encoder::new().keyframe_interval(30);
+//! for frame in frames {
+//!     channel.log(&encoder.encode(&frame)?);
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! Each message contains a single frame in Annex B format, with the timestamp and frame of
+//! reference of the [`RawImage`] it was encoded from. Frames are never reordered, skipped, or
+//! buffered, so each message is emitted as soon as its frame is encoded. Keyframes are emitted
+//! periodically, and include the sequence and picture parameter sets, so that clients can start
+//! decoding whenever they join the stream.
+//!
+//! OpenH264 is compiled from source, which requires a C++ compiler. It produces a Constrained
+//! Baseline profile stream, which every H.264 decoder supports.
+
+use openh264::encoder::{BitRate, Encoder, EncoderConfig, FrameRate};
+use openh264::formats::{RgbSliceU8, YUVBuffer};
+use openh264::OpenH264API;
+use thiserror::Error;
+
+use crate::image::ImageError;
+use crate::schemas::{CompressedVideo, RawImage};
+
+/// The default interval between keyframes, in frames.
+pub const DEFAULT_KEYFRAME_INTERVAL: u64 = 30;
+
+/// The default target bitrate, in bits per second.
+pub const DEFAULT_BITRATE: u32 = 2_000_000;
+
+/// An error encountered while encoding video.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum VideoError {
+    /// The frame could not be converted for encoding.
+    #[error(transparent)]
+    Image(#[from] ImageError),
+    /// The encoder failed to initialize or to encode a frame.
+    #[error(transparent)]
+    Encoder(#[from] openh264::Error),
+    /// The frame size differs from that of the first frame.
+    #[error("Frame size {actual:?} does not match the video size {expected:?}")]
+    FrameSize {
+        /// The width and height of the video.
+        expected: (u32, u32),
+        /// The width and height of the frame.
+        actual: (u32, u32),
+    },
+    /// The frame size cannot be encoded. Widths and heights must be even and nonzero.
+    #[error("Unsupported frame size {width}x{height}; dimensions must be even and nonzero")]
+    UnsupportedSize {
+        /// The width of the frame.
+        width: u32,
+        /// The height of the frame.
+        height: u32,
+    },
+}
+
+/// A software H.264 encoder, which encodes [`RawImage`] frames to [`CompressedVideo`] messages.
+///
+/// The video size is set by the first frame; all frames must have the same size until the encoder
+/// is [reset](Self::reset).
+pub struct H264Encoder {
+    keyframe_interval: u64,
+    bitrate: u32,
+    frame_rate: u32,
+    encoder: Option<(Encoder, (u32, u32))>,
+    frame_count: u64,
+}
+
+impl Default for H264Encoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl H264Encoder {
+    /// Creates an encoder with default settings.
+    pub fn new() -> Self {
+        Self {
+            keyframe_interval: DEFAULT_KEYFRAME_INTERVAL,
+            bitrate: DEFAULT_BITRATE,
+            frame_rate: 30,
+            encoder: None,
+            frame_count: 0,
+        }
+    }
+
+    /// Sets the interval between keyframes, in frames.
+    ///
+    /// Clients can only start decoding the stream at a keyframe. The default is
+    /// [`DEFAULT_KEYFRAME_INTERVAL`].
+    #[must_use]
+    pub fn keyframe_interval(mut self, frames: u64) -> Self {
+        self.keyframe_interval = frames.max(1);
+        self
+    }
+
+    /// Sets the target bitrate, in bits per second.
+    ///
+    /// The default is [`DEFAULT_BITRATE`]. Takes effect when the next stream starts.
+    #[must_use]
+    pub fn bitrate(mut self, bits_per_second: u32) -> Self {
+        self.bitrate = bits_per_second.max(1);
+        self
+    }
+
+    /// Sets the expected frame rate, in frames per second, which the encoder uses to divide the
+    /// bitrate between frames.
+    ///
+    /// The default is 30. Message timestamps are always taken from the frames.
+    #[must_use]
+    pub fn frame_rate(mut self, frame_rate: u32) -> Self {
+        self.frame_rate = frame_rate.max(1);
+        self
+    }
+
+    /// Encodes a frame.
+    pub fn encode(&mut self, frame: &RawImage) -> Result<CompressedVideo, VideoError> {
+        let size = (frame.width, frame.height);
+        if let Some((_, expected)) = &self.encoder {
+            if *expected != size {
+                return Err(VideoError::FrameSize {
+                    expected: *expected,
+                    actual: size,
+                });
+            }
+        } else if frame.width == 0 || frame.height == 0 || frame.width % 2 + frame.height % 2 > 0 {
+            return Err(VideoError::UnsupportedSize {
+                width: frame.width,
+                height: frame.height,
+            });
+        }
+        let image = frame.to_image()?.into_rgb8();
+
+        if self.encoder.is_none() {
+            let config = EncoderConfig::new()
+                .bitrate(BitRate::from_bps(self.bitrate))
+                .max_frame_rate(FrameRate::from_hz(self.frame_rate as f32))
+                .skip_frames(false);
+            let encoder = Encoder::with_api_config(OpenH264API::from_source(), config)?;
+            self.encoder = Some((encoder, size));
+        }
+        let (encoder, _) = self.encoder.as_mut().expect("encoder is initialized");
+        if self.frame_count.is_multiple_of(self.keyframe_interval) {
+            encoder.force_intra_frame();
+        }
+        let yuv = YUVBuffer::from_rgb_source(RgbSliceU8::new(
+            image.as_raw(),
+            (image.width() as usize, image.height() as usize),
+        ));
+        let data = encoder.encode(&yuv)?.to_vec();
+        self.frame_count += 1;
+        Ok(CompressedVideo {
+            timestamp: frame.timestamp,
+            frame_id: frame.frame_id.clone(),
+            data,
+            format: "h264".to_string(),
+        })
+    }
+
+    /// Resets the encoder, so that the next frame starts a new stream with a keyframe.
+    ///
+    /// The new stream may have a different frame size.
+    pub fn reset(&mut self) {
+        self.encoder = None;
+        self.frame_count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openh264::decoder::Decoder;
+    use openh264::formats::YUVSource;
+
+    use super::*;
+    use crate::schemas::Timestamp;
+
+    /// The NAL unit type of a sequence parameter set.
+    const NAL_SPS: u8 = 7;
+
+    /// Returns a frame of a textured background, with a bright square which moves by `(3, 2)`
+    /// pixels per frame.
+    fn frame(index: u32, width: u32, height: u32) -> RawImage {
+        let image = ::image::RgbImage::from_fn(width, height, |x, y| {
+            let (sx, sy) = (x.wrapping_sub(index * 3 + 5), y.wrapping_sub(index * 2 + 3));
+            if sx < 12 && sy < 12 {
+                ::image::Rgb([250, 240, 30])
+            } else {
+                let texture = ((x / 4 + y / 4) % 2 * 60 + x * 2) as u8;
+                ::image::Rgb([texture, (y * 5) as u8, 128])
+            }
+        });
+        RawImage {
+            timestamp: Some(Timestamp {
+                seconds: 100,
+                nanos: index as i32,
+            }),
+            frame_id: "camera".to_string(),
+            ..RawImage::from(&image)
+        }
+    }
+
+    /// Returns the NAL unit types of an Annex B stream.
+    fn nal_types(data: &[u8]) -> Vec<u8> {
+        data.windows(4)
+            .filter(|w| w[..3] == [0, 0, 1])
+            .map(|w| w[3] & 0x1f)
+            .collect()
+    }
+
+    /// Decodes a message with the OpenH264 decoder, and returns the decoded picture.
+    fn decode(decoder: &mut Decoder, data: &[u8]) -> ::image::RgbImage {
+        let mut picture = None;
+        for packet in openh264::nal_units(data) {
+            if let Some(yuv) = decoder.decode(packet).unwrap() {
+                let (width, height) = yuv.dimensions();
+                let mut rgb = vec![0; width * height * 3];
+                yuv.write_rgb8(&mut rgb);
+                picture = ::image::RgbImage::from_raw(width as u32, height as u32, rgb);
+            }
+        }
+        picture.expect("no picture decoded")
+    }
+
+    /// Returns the peak signal to noise ratio of a decoded picture.
+    fn psnr(source: &::image::RgbImage, decoded: &::image::RgbImage) -> f64 {
+        let error: f64 = source
+            .as_raw()
+            .iter()
+            .zip(decoded.as_raw())
+            .map(|(a, b)| (f64::from(*a) - f64::from(*b)).powi(2))
+            .sum();
+        let mse = error / source.as_raw().len() as f64;
+        10.0 * (255.0 * 255.0 / mse.max(1e-9)).log10()
+    }
+
+    #[test]
+    fn test_encode() {
+        let frames: Vec<_> = (0..6).map(|i| frame(i, 48, 34)).collect();
+        let mut encoder = H264Encoder::new().keyframe_interval(3);
+        let mut decoder = Decoder::new().unwrap();
+        for (i, frame) in frames.iter().enumerate() {
+            let msg = encoder.encode(frame).unwrap();
+            assert_eq!(msg.format, "h264");
+            assert_eq!(msg.frame_id, "camera");
+            assert_eq!(msg.timestamp.unwrap().nanos, i as i32);
+            let keyframe = nal_types(&msg.data).contains(&NAL_SPS);
+            assert_eq!(keyframe, i % 3 == 0, "frame {i}");
+
+            // Every frame decodes with a reference decoder.
+            let decoded = decode(&mut decoder, &msg.data);
+            assert_eq!(decoded.dimensions(), (48, 34));
+            let source = frame.to_image().unwrap().into_rgb8();
+            let psnr = psnr(&source, &decoded);
+            assert!(psnr > 30.0, "frame {i}: PSNR {psnr}");
+        }
+    }
+
+    #[test]
+    fn test_join_at_keyframe() {
+        let frames: Vec<_> = (0..4).map(|i| frame(i, 32, 32)).collect();
+        let mut encoder = H264Encoder::new().keyframe_interval(2);
+        let messages: Vec<_> = frames.iter().map(|f| encoder.encode(f).unwrap()).collect();
+        // A client which joins at the second keyframe can decode the rest of the stream.
+        let mut decoder = Decoder::new().unwrap();
+        for msg in &messages[2..] {
+            assert_eq!(decode(&mut decoder, &msg.data).dimensions(), (32, 32));
+        }
+    }
+
+    #[test]
+    fn test_frame_size() {
+        let mut encoder = H264Encoder::new();
+        encoder.encode(&frame(0, 48, 34)).unwrap();
+        let small = RawImage::from(&::image::RgbImage::new(16, 16));
+        assert!(matches!(
+            encoder.encode(&small),
+            Err(VideoError::FrameSize {
+                expected: (48, 34),
+                actual: (16, 16)
+            })
+        ));
+        encoder.reset();
+        let msg = encoder.encode(&small).unwrap();
+        assert!(nal_types(&msg.data).contains(&NAL_SPS));
+
+        let mut encoder = H264Encoder::new();
+        for (width, height) in [(33, 16), (16, 17), (0, 0)] {
+            let odd = RawImage::from(&::image::RgbImage::new(width, height));
+            assert!(matches!(
+                encoder.encode(&odd),
+                Err(VideoError::UnsupportedSize { .. })
+            ));
+        }
+    }
+}
@@ -1,14 +1,19 @@
 //! Websocket functionality
 
 use crate::channel::ChannelId;
+#[cfg(feature = "unstable")]
+use crate::clock::ContextClock;
 use crate::cow_vec::CowVec;
+use crate::metrics::{self, Metric, Metrics};
 pub(crate) use crate::websocket::protocol::client::{
     ClientChannel, ClientChannelId, ClientMessage, Subscription, SubscriptionId,
 };
 pub use crate::websocket::protocol::server::{
     Capability, Parameter, ParameterType, ParameterValue, Status, StatusLevel,
 };
-use crate::{get_runtime_handle, Channel, FoxgloveError, LogSink, Metadata};
+use crate::{
+    get_runtime_handle, Channel, ChannelBuilder, FoxgloveError, LogContext, LogSink, Metadata,
+};
 use bimap::BiHashMap;
 use bytes::{BufMut, BytesMut};
 use flume::TrySendError;
@@ -16,8 +21,10 @@ use futures_util::{stream::SplitSink, SinkExt, StreamExt};
 use std::collections::hash_map::Entry;
 use std::collections::HashSet;
 use std::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed};
-use std::sync::atomic::{AtomicBool, AtomicU32};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64};
 use std::sync::Weak;
+#[cfg(feature = "unstable")]
+use std::time::Duration;
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::{collections::HashMap, net::SocketAddr, sync::Arc};
 use thiserror::Error;
@@ -32,6 +39,7 @@ use tokio_tungstenite::{
 };
 use tokio_util::sync::CancellationToken;
 
+mod parameter_store;
 mod protocol;
 pub mod service;
 #[cfg(test)]
@@ -39,6 +47,7 @@ mod tests;
 #[cfg(all(test, feature = "unstable"))]
 mod unstable_tests;
 
+pub use parameter_store::{ParameterDescriptor, ParameterStore};
 use service::{CallId, Service, ServiceId};
 
 /// Identifies a client connection. Unique for the duration of the server's lifetime.
@@ -120,6 +129,11 @@ pub(crate) struct ServerOptions {
     pub services: HashMap<String, Service>,
     pub supported_encodings: Option<HashSet<String>>,
     pub runtime: Option<Handle>,
+    pub forward_client_channels: bool,
+    pub service_calls_per_client: Option<usize>,
+    pub parameter_store: Option<ParameterStore>,
+    #[cfg(feature = "unstable")]
+    pub clock_broadcast: Option<(Arc<ContextClock>, Duration)>,
 }
 
 impl std::fmt::Debug for ServerOptions {
@@ -129,6 +143,9 @@ impl std::fmt::Debug for ServerOptions {
             .field("name", &self.name)
             .field("message_backlog_size", &self.message_backlog_size)
             .field("services", &self.services)
+            .field("forward_client_channels", &self.forward_client_channels)
+            .field("service_calls_per_client", &self.service_calls_per_client)
+            .field("parameter_store", &self.parameter_store)
             .finish()
     }
 }
@@ -161,6 +178,15 @@ pub(crate) struct Server {
     cancellation_token: CancellationToken,
     /// Registered services.
     services: parking_lot::RwLock<HashMap<ServiceId, Arc<Service>>>,
+    /// Whether client channels are registered as channels in the log context.
+    forward_client_channels: bool,
+    /// Maximum number of concurrent service calls per client.
+    service_calls_per_client: usize,
+    /// Managed parameters, if the server answers parameter requests itself.
+    parameter_store: Option<ParameterStore>,
+    /// Clock whose time is periodically broadcast to clients, and the broadcast interval.
+    #[cfg(feature = "unstable")]
+    clock_broadcast: Option<(Arc<ContextClock>, Duration)>,
 }
 
 /// Provides a mechanism for registering callbacks for handling client message events.
@@ -189,6 +215,7 @@ pub trait ServerListener: Send + Sync {
     fn on_client_unadvertise(&self, _client: Client, _channel: ClientChannelView) {}
     /// Callback invoked when a client requests parameters. Requires [`Capability::Parameters`].
     /// Should return the named paramters, or all paramters if param_names is empty.
+    /// Not invoked if the server is configured with a [`ParameterStore`].
     fn on_get_parameters(
         &self,
         _client: Client,
@@ -201,6 +228,7 @@ pub trait ServerListener: Send + Sync {
     /// Should return the updated parameters for the passed parameters.
     /// The implementation could return the modified parameters.
     /// All clients subscribed to updates for the _returned_ parameters will be notified.
+    /// Not invoked if the server is configured with a [`ParameterStore`].
     fn on_set_parameters(
         &self,
         _client: Client,
@@ -224,13 +252,21 @@ pub(crate) struct ConnectedClient {
     sender: Mutex<WebsocketSender>,
     data_plane_tx: flume::Sender<Message>,
     data_plane_rx: flume::Receiver<Message>,
+    /// Number of data plane messages dropped because the client fell behind
+    dropped_messages: AtomicU64,
     control_plane_tx: flume::Sender<Message>,
     control_plane_rx: flume::Receiver<Message>,
     service_call_sem: service::Semaphore,
+    /// Runtime for spawning service call tasks
+    runtime: Handle,
+    /// Token for cancelling this client's tasks, such as outstanding service calls
+    cancellation_token: CancellationToken,
     /// Subscriptions from this client
     subscriptions: parking_lot::Mutex<BiHashMap<ChannelId, SubscriptionId>>,
     /// Channels advertised by this client
     advertised_channels: parking_lot::Mutex<HashMap<ClientChannelId, Arc<ClientChannel>>>,
+    /// Log context channels for client channels, if the server forwards client channels
+    forwarded_channels: parking_lot::Mutex<HashMap<ClientChannelId, Arc<Channel>>>,
     /// Parameters subscribed to by this client
     parameter_subscriptions: parking_lot::Mutex<HashSet<String>>,
     /// Optional callback handler for a server implementation
@@ -304,13 +340,23 @@ impl ConnectedClient {
 
     /// Send the message on the data plane, dropping up to retries older messages to make room, if necessary.
     fn send_data_lossy(&self, message: Message, retries: usize) -> SendLossyResult {
-        send_lossy(
+        let result = send_lossy(
             &self.addr,
             &self.data_plane_tx,
             &self.data_plane_rx,
             message,
             retries,
-        )
+        );
+        let dropped = match result {
+            SendLossyResult::Sent => 0,
+            SendLossyResult::SentLossy(dropped) => dropped,
+            // The new message is dropped as well as the older ones.
+            SendLossyResult::ExhaustedRetries => retries + 1,
+        };
+        if dropped > 0 {
+            self.dropped_messages.fetch_add(dropped as u64, Relaxed);
+        }
+        result
     }
 
     /// Send the message on the control plane, disconnecting the client if the channel is full.
@@ -327,6 +373,15 @@ impl ConnectedClient {
     }
 
     fn on_disconnect(&self, server: &Arc<Server>) {
+        // Cancel any outstanding service calls.
+        self.cancellation_token.cancel();
+
+        // Remove any channels we registered on behalf of this client.
+        let forwarded_channels = std::mem::take(&mut *self.forwarded_channels.lock());
+        for channel in forwarded_channels.into_values() {
+            remove_forwarded_channel(&channel);
+        }
+
         // If we track paramter subscriptions, unsubscribe this clients subscriptions
         // and notify the handler, if necessary
         if !server
@@ -381,6 +436,11 @@ impl ConnectedClient {
                 &payload,
             );
         }
+        // Forward the message to the log context, if we registered a channel for it
+        let forwarded_channel = self.forwarded_channels.lock().get(&channel_id).cloned();
+        if let Some(channel) = forwarded_channel {
+            channel.log(&payload);
+        }
     }
 
     fn on_unadvertise(&self, mut channel_ids: Vec<ClientChannelId>) {
@@ -404,6 +464,12 @@ impl ConnectedClient {
                 i += 1;
             }
         }
+        for id in channel_ids.iter() {
+            let forwarded_channel = self.forwarded_channels.lock().remove(id);
+            if let Some(channel) = forwarded_channel {
+                remove_forwarded_channel(&channel);
+            }
+        }
         // Call the handler after releasing the advertised_channels lock
         if let Some(handler) = self.server_listener.as_ref() {
             for (id, client_channel) in channel_ids.iter().cloned().zip(client_channels) {
@@ -443,6 +509,10 @@ impl ConnectedClient {
                 }
             };
 
+            if server.forward_client_channels {
+                self.forward_client_channel(&client_channel);
+            }
+
             // Call the handler after releasing the advertised_channels lock
             if let Some(handler) = self.server_listener.as_ref() {
                 handler.on_client_advertise(
@@ -456,6 +526,49 @@ impl ConnectedClient {
         }
     }
 
+    /// Registers a channel in the log context for the client channel, so that messages published
+    /// by the client are logged to all sinks.
+    fn forward_client_channel(&self, client_channel: &ClientChannel) {
+        let schema = match client_channel.decode_schema() {
+            Ok(schema) => schema,
+            Err(err) => {
+                tracing::error!(
+                    "Invalid schema for client channel {} from {}: {err}",
+                    client_channel.id,
+                    self.addr
+                );
+                self.send_error(format!(
+                    "Invalid schema for client channel {}: {err}",
+                    client_channel.id
+                ));
+                return;
+            }
+        };
+        let result = ChannelBuilder::new(&client_channel.topic)
+            .message_encoding(&client_channel.encoding)
+            .schema(schema)
+            .build();
+        match result {
+            Ok(channel) => {
+                tracing::debug!(
+                    "Forwarding client channel {} from {} to topic {}",
+                    client_channel.id,
+                    self.addr,
+                    channel.topic
+                );
+                self.forwarded_channels
+                    .lock()
+                    .insert(client_channel.id, channel);
+            }
+            Err(err) => {
+                self.send_warning(format!(
+                    "Cannot forward client channel {}: {err}",
+                    client_channel.id
+                ));
+            }
+        }
+    }
+
     fn on_unsubscribe(&self, server: Arc<Server>, subscription_ids: Vec<SubscriptionId>) {
         let mut unsubscribed_channel_ids = Vec::with_capacity(subscription_ids.len());
         // First gather the unsubscribed channel ids while holding the subscriptions lock
@@ -574,8 +687,12 @@ impl ConnectedClient {
             return;
         }
 
-        if let Some(handler) = self.server_listener.as_ref() {
-            let request_id = request_id.as_deref();
+        let request_id = request_id.as_deref();
+        if let Some(store) = server.parameter_store.as_ref() {
+            let parameters = store.get_parameters(&param_names);
+            let message = protocol::server::parameters_json(&parameters, request_id);
+            self.send_control_msg(Message::text(message));
+        } else if let Some(handler) = self.server_listener.as_ref() {
             let parameters = handler.on_get_parameters(Client(self), param_names, request_id);
             let message = protocol::server::parameters_json(&parameters, request_id);
             let _ = self.control_plane_tx.try_send(Message::text(message));
@@ -593,6 +710,20 @@ impl ConnectedClient {
             return;
         }
 
+        if let Some(store) = server.parameter_store.as_ref() {
+            // The store publishes applied values to subscribers.
+            let (current_parameters, errors) = store.set_from_client(parameters);
+            for err in errors {
+                self.send_warning(err.to_string());
+            }
+            if request_id.is_some() {
+                let message =
+                    protocol::server::parameters_json(&current_parameters, request_id.as_deref());
+                self.send_control_msg(Message::text(message));
+            }
+            return;
+        }
+
         let updated_parameters = if let Some(handler) = self.server_listener.as_ref() {
             let request_id = request_id.as_deref();
             let updated_parameters =
@@ -746,11 +877,21 @@ impl ConnectedClient {
             return;
         }
 
-        // Acquire the semaphore, or reject if there are too many concurrenct requests.
+        // Acquire the client's semaphore and the service's semaphore, or reject if there are too
+        // many concurrent requests.
+        let mut guards = Vec::with_capacity(2);
         let Some(guard) = self.service_call_sem.try_acquire() else {
             self.send_service_call_failure(service_id, call_id, "Too many requests");
             return;
         };
+        guards.push(guard);
+        if let Some(sem) = service.concurrency_limit() {
+            let Some(guard) = sem.try_acquire() else {
+                self.send_service_call_failure(service_id, call_id, "Too many requests");
+                return;
+            };
+            guards.push(guard);
+        }
 
         // Prepare the responder and the request.
         let responder = service::Responder::new(
@@ -758,8 +899,11 @@ impl ConnectedClient {
             service.id(),
             call_id,
             service.response_encoding().unwrap_or(&req.encoding),
-            guard,
+            guards,
         );
+        if let Some(timeout) = service.timeout() {
+            responder.start_timeout(timeout);
+        }
         let request = service::Request::new(service.clone(), call_id, req.encoding, req.payload);
 
         // Invoke the handler.
@@ -823,6 +967,25 @@ impl Server {
         let mut capabilities = opts.capabilities.unwrap_or_default();
         let mut supported_encodings = opts.supported_encodings.unwrap_or_default();
 
+        // If the server manages parameters, it must support getting, setting, and subscribing to
+        // them.
+        if let Some(store) = opts.parameter_store.as_ref() {
+            capabilities.insert(Capability::Parameters);
+            capabilities.insert(Capability::ParametersSubscribe);
+            store.attach(weak_self.clone());
+        }
+
+        // If the server broadcasts a clock, it must advertise the time capability.
+        #[cfg(feature = "unstable")]
+        if opts.clock_broadcast.is_some() {
+            capabilities.insert(Capability::Time);
+        }
+
+        // If the server forwards client channels, it must accept client channels.
+        if opts.forward_client_channels {
+            capabilities.insert(Capability::ClientPublish);
+        }
+
         // If the server was declared with services, automatically add the "services" capability
         // and the set of supported request encodings.
         if !opts.services.is_empty() {
@@ -858,6 +1021,13 @@ impl Server {
                     .map(|s| (s.id(), Arc::new(s)))
                     .collect(),
             ),
+            forward_client_channels: opts.forward_client_channels,
+            service_calls_per_client: opts
+                .service_calls_per_client
+                .unwrap_or(DEFAULT_SERVICE_CALLS_PER_CLIENT),
+            parameter_store: opts.parameter_store,
+            #[cfg(feature = "unstable")]
+            clock_broadcast: opts.clock_broadcast,
         }
     }
 
@@ -900,6 +1070,21 @@ impl Server {
             }
         });
 
+        #[cfg(feature = "unstable")]
+        if let Some((clock, interval)) = self.clock_broadcast.clone() {
+            let cancellation_token = self.cancellation_token.clone();
+            let server = self.arc().clone();
+            self.runtime.spawn(async move {
+                let mut ticker = tokio::time::interval(interval);
+                loop {
+                    tokio::select! {
+                        _ = ticker.tick() => server.broadcast_time(clock.now()).await,
+                        () = cancellation_token.cancelled() => break,
+                    }
+                }
+            });
+        }
+
         tracing::info!("Started server on {}", bound_addr);
 
         Ok(bound_addr)
@@ -923,6 +1108,20 @@ impl Server {
         self.cancellation_token.cancel();
     }
 
+    /// Returns the advertisement for a channel.
+    ///
+    /// The protocol requires each advertised channel to include its schema, but the schema data
+    /// is only encoded once for each unique schema in the channel's context, and shared between
+    /// channels and servers.
+    fn advertisement(&self, channel: &Channel) -> Result<String, FoxgloveError> {
+        let schema = channel
+            .schema
+            .as_ref()
+            .ok_or(FoxgloveError::SchemaRequired)?;
+        let schema_data = schema.advertised_data(protocol::server::encode_schema)?;
+        protocol::server::advertisement(channel, &schema_data)
+    }
+
     async fn advertise_channel(&self, channel: Arc<Channel>) {
         if channel.schema.is_none() {
             tracing::error!(
@@ -934,7 +1133,7 @@ impl Server {
 
         self.channels.write().insert(channel.id, channel.clone());
 
-        let message = match protocol::server::advertisement(&channel) {
+        let message = match self.advertisement(&channel) {
             Ok(message) => message,
             Err(err) => {
                 tracing::error!("Error creating advertise channel message to client: {err}");
@@ -1095,11 +1294,15 @@ impl Server {
             sender: Mutex::new(ws_sender),
             data_plane_tx: data_tx,
             data_plane_rx: data_rx,
+            dropped_messages: AtomicU64::new(0),
             control_plane_tx: ctrl_tx,
             control_plane_rx: ctrl_rx,
-            service_call_sem: service::Semaphore::new(DEFAULT_SERVICE_CALLS_PER_CLIENT),
+            service_call_sem: service::Semaphore::new(self.service_calls_per_client),
+            runtime: self.runtime.clone(),
+            cancellation_token: self.cancellation_token.child_token(),
             subscriptions: parking_lot::Mutex::new(BiHashMap::new()),
             advertised_channels: parking_lot::Mutex::new(HashMap::new()),
+            forwarded_channels: parking_lot::Mutex::new(HashMap::new()),
             parameter_subscriptions: parking_lot::Mutex::new(HashSet::new()),
             server_listener: self.listener.clone(),
             server: self.weak_self.clone(),
@@ -1191,7 +1394,7 @@ impl Server {
         );
 
         for channel in channels.into_iter() {
-            let message = match protocol::server::advertisement(&channel) {
+            let message = match self.advertisement(&channel) {
                 Ok(message) => message,
                 Err(err) => {
                     tracing::error!("Error creating advertise channel message to client: {err}");
@@ -1335,10 +1538,21 @@ impl Server {
     }
 }
 
+/// Removes a forwarded client channel from the log context.
+fn remove_forwarded_channel(channel: &Arc<Channel>) {
+    let context = LogContext::global();
+    // Only remove the channel if the topic is still registered to it.
+    if context
+        .get_channel_by_topic(&channel.topic)
+        .is_some_and(|c| Arc::ptr_eq(&c, channel))
+    {
+        context.remove_channel_for_topic(&channel.topic);
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum SendLossyResult {
     Sent,
-    #[allow(dead_code)]
     SentLossy(usize),
     ExhaustedRetries,
 }
@@ -1418,6 +1632,38 @@ impl LogSink for Server {
         Ok(())
     }
 
+    fn name(&self) -> String {
+        "WebSocketServer".to_string()
+    }
+
+    /// Reports the queue depth and dropped messages of each connected client.
+    fn metrics(&self, metrics: &mut Metrics) {
+        metrics.push(Metric::gauge(
+            metrics::WS_CLIENTS,
+            self.clients.get().len() as u64,
+        ));
+        for client in self.clients.get().iter() {
+            let id = client.id.0.to_string();
+            let addr = client.addr.to_string();
+            metrics.push(
+                Metric::gauge(
+                    metrics::WS_CLIENT_QUEUE_DEPTH,
+                    client.data_plane_rx.len() as u64,
+                )
+                .with_label("client_id", &id)
+                .with_label("client_addr", &addr),
+            );
+            metrics.push(
+                Metric::counter(
+                    metrics::WS_CLIENT_DROPPED_MESSAGES,
+                    client.dropped_messages.load(Relaxed),
+                )
+                .with_label("client_id", id)
+                .with_label("client_addr", addr),
+            );
+        }
+    }
+
     /// Server has an available channel. Advertise to all clients.
     fn add_channel(&self, channel: &Arc<Channel>) {
         let server = self.arc();
@@ -1449,6 +1695,7 @@ async fn handle_connections(server: Arc<Server>, listener: TcpListener) {
 /// Add the subprotocol header to the response if the client requested one we support.
 /// If the client doesn't support our protocol, do not include the protocol header in the response;
 /// the client must fail the connection. [WebSocket RFC](https://www.rfc-editor.org/rfc/rfc6455#section-4)
+#[allow(clippy::result_large_err)]
 async fn do_handshake(stream: TcpStream) -> Result<WebSocketStream<TcpStream>, tungstenite::Error> {
     tokio_tungstenite::accept_hdr_async(
         stream,
@@ -0,0 +1,360 @@
+//! A managed store of typed parameters.
+
+use std::collections::BTreeMap;
+use std::mem::discriminant;
+use std::sync::{Arc, Weak};
+
+use tokio::sync::watch;
+
+use super::{Parameter, ParameterType, ParameterValue, Server};
+use crate::FoxgloveError;
+
+mod persistence;
+#[cfg(test)]
+mod tests;
+
+/// A callback invoked when parameters change.
+type ChangeCallback = Arc<dyn Fn(&[Parameter]) + Send + Sync>;
+
+/// Declares a parameter in a [`ParameterStore`].
+///
+/// The type of the parameter is inferred from its default value. Values set by clients or by
+/// [`ParameterStore::set`] must have the same type as the default value, and must fall within the
+/// declared range, if any.
+#[derive(Debug, Clone)]
+pub struct ParameterDescriptor {
+    name: String,
+    default: ParameterValue,
+    description: Option<String>,
+    range: Option<(f64, f64)>,
+    read_only: bool,
+}
+
+impl ParameterDescriptor {
+    /// Creates a new parameter descriptor with the given name and default value.
+    pub fn new(name: impl Into<String>, default: impl Into<ParameterValue>) -> Self {
+        Self {
+            name: name.into(),
+            default: default.into(),
+            description: None,
+            range: None,
+            read_only: false,
+        }
+    }
+
+    /// Sets a human-readable description of the parameter.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the inclusive range of allowed values.
+    ///
+    /// The range applies to number parameters, and to each element of number array parameters.
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Marks the parameter as read-only, so that it cannot be set by clients.
+    ///
+    /// Read-only parameters can still be updated with [`ParameterStore::set`].
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// The parameter name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The default value of the parameter.
+    pub fn default_value(&self) -> &ParameterValue {
+        &self.default
+    }
+
+    /// The description of the parameter, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// The inclusive range of allowed values, if any.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.range
+    }
+
+    /// Whether the parameter is read-only for clients.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// The parameter type advertised to clients.
+    fn parameter_type(&self) -> Option<ParameterType> {
+        match &self.default {
+            ParameterValue::Number(_) => Some(ParameterType::Float64),
+            ParameterValue::String(_) => Some(ParameterType::ByteArray),
+            ParameterValue::Array(values) if is_number_array(values) => {
+                Some(ParameterType::Float64Array)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks that the value has the same type as the default, and is within range.
+    fn validate(&self, value: &ParameterValue) -> Result<(), FoxgloveError> {
+        let invalid = |reason: String| {
+            Err(FoxgloveError::InvalidParameterValue(
+                self.name.clone(),
+                reason,
+            ))
+        };
+        if discriminant(value) != discriminant(&self.default) {
+            return invalid("type mismatch".to_string());
+        }
+        let numbers: Vec<f64> = match (value, self.parameter_type()) {
+            (ParameterValue::Number(n), _) => vec![*n],
+            (ParameterValue::Array(values), Some(ParameterType::Float64Array)) => {
+                if !is_number_array(values) {
+                    return invalid("expected an array of numbers".to_string());
+                }
+                values
+                    .iter()
+                    .filter_map(|v| match v {
+                        ParameterValue::Number(n) => Some(*n),
+                        _ => None,
+                    })
+                    .collect()
+            }
+            _ => return Ok(()),
+        };
+        if let Some((min, max)) = self.range {
+            if let Some(n) = numbers.iter().find(|n| !(min..=max).contains(*n)) {
+                return invalid(format!("{n} is outside of the range [{min}, {max}]"));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_number_array(values: &[ParameterValue]) -> bool {
+    values
+        .iter()
+        .all(|v| matches!(v, ParameterValue::Number(_)))
+}
+
+/// A declared parameter and its current value.
+struct Entry {
+    descriptor: ParameterDescriptor,
+    value: watch::Sender<ParameterValue>,
+}
+
+impl Entry {
+    fn parameter(&self) -> Parameter {
+        Parameter {
+            name: self.descriptor.name.clone(),
+            r#type: self.descriptor.parameter_type(),
+            value: Some(self.value.borrow().clone()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Inner {
+    entries: parking_lot::RwLock<BTreeMap<String, Entry>>,
+    /// Servers which publish parameter updates to subscribed clients.
+    servers: parking_lot::Mutex<Vec<Weak<Server>>>,
+    /// Callbacks registered with [`ParameterStore::on_change`].
+    callbacks: parking_lot::Mutex<Vec<ChangeCallback>>,
+}
+
+/// A managed store of typed parameters.
+///
+/// When a store is configured with
+/// [`WebSocketServer::parameter_store`](crate::WebSocketServer::parameter_store), the server
+/// answers client requests to get, set, and subscribe to parameters from the store. Values set by
+/// clients are validated against the parameter's [`ParameterDescriptor`] before being applied.
+///
+/// Application code can read and update parameters with [`ParameterStore::get`] and
+/// [`ParameterStore::set`], and can be notified of changes with [`ParameterStore::watch`].
+///
+/// The store is cheap to clone; clones share the same parameters.
+#[derive(Clone, Default)]
+pub struct ParameterStore(Arc<Inner>);
+
+impl std::fmt::Debug for ParameterStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParameterStore")
+            .field("parameters", &self.0.entries.read().keys())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Validates and applies a new value to an entry, returning the updated parameter.
+fn apply(
+    entries: &BTreeMap<String, Entry>,
+    name: &str,
+    value: ParameterValue,
+) -> Result<Parameter, FoxgloveError> {
+    let Some(entry) = entries.get(name) else {
+        return Err(FoxgloveError::UnknownParameter(name.to_string()));
+    };
+    entry.descriptor.validate(&value)?;
+    entry.value.send_replace(value);
+    Ok(entry.parameter())
+}
+
+impl ParameterStore {
+    /// Creates a new, empty parameter store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares a parameter, initialized to its default value.
+    ///
+    /// Returns an error if a parameter with the same name was already declared, or if the default
+    /// value is outside of the declared range.
+    pub fn declare(&self, descriptor: ParameterDescriptor) -> Result<(), FoxgloveError> {
+        descriptor.validate(&descriptor.default)?;
+        let mut entries = self.0.entries.write();
+        if entries.contains_key(&descriptor.name) {
+            return Err(FoxgloveError::DuplicateParameter(descriptor.name));
+        }
+        let (value, _) = watch::channel(descriptor.default.clone());
+        entries.insert(descriptor.name.clone(), Entry { descriptor, value });
+        Ok(())
+    }
+
+    /// Returns the descriptor for the named parameter.
+    pub fn descriptor(&self, name: &str) -> Option<ParameterDescriptor> {
+        self.0
+            .entries
+            .read()
+            .get(name)
+            .map(|e| e.descriptor.clone())
+    }
+
+    /// Returns the current value of the named parameter.
+    pub fn get(&self, name: &str) -> Option<ParameterValue> {
+        self.0
+            .entries
+            .read()
+            .get(name)
+            .map(|e| e.value.borrow().clone())
+    }
+
+    /// Returns the current values of all parameters, ordered by name.
+    pub fn parameters(&self) -> Vec<Parameter> {
+        self.0
+            .entries
+            .read()
+            .values()
+            .map(Entry::parameter)
+            .collect()
+    }
+
+    /// Sets the value of the named parameter, and publishes the new value to subscribed clients.
+    ///
+    /// Returns an error if the parameter has not been declared, or if the value is not valid for
+    /// the parameter.
+    pub fn set(&self, name: &str, value: impl Into<ParameterValue>) -> Result<(), FoxgloveError> {
+        let parameter = self.update(name, value.into())?;
+        self.publish(vec![parameter]);
+        Ok(())
+    }
+
+    /// Returns a receiver which is notified whenever the named parameter changes.
+    ///
+    /// Returns `None` if the parameter has not been declared.
+    pub fn watch(&self, name: &str) -> Option<watch::Receiver<ParameterValue>> {
+        self.0.entries.read().get(name).map(|e| e.value.subscribe())
+    }
+
+    /// Validates and applies a new value, returning the updated parameter.
+    fn update(&self, name: &str, value: ParameterValue) -> Result<Parameter, FoxgloveError> {
+        apply(&self.0.entries.read(), name, value)
+    }
+
+    /// Registers a callback which is invoked with the updated parameters whenever parameters
+    /// change, whether they are set by clients or by the application.
+    ///
+    /// The callback is invoked synchronously from the context that changed the parameters, which
+    /// may be a client's main poll loop. It must not block.
+    pub fn on_change(&self, callback: impl Fn(&[Parameter]) + Send + Sync + 'static) {
+        self.0.callbacks.lock().push(Arc::new(callback));
+    }
+
+    /// Publishes parameter values to clients of attached servers, and invokes change callbacks.
+    fn publish(&self, parameters: Vec<Parameter>) {
+        if parameters.is_empty() {
+            return;
+        }
+        let callbacks = self.0.callbacks.lock().clone();
+        for callback in callbacks {
+            callback(&parameters);
+        }
+        let servers: Vec<_> = {
+            let mut servers = self.0.servers.lock();
+            servers.retain(|s| s.strong_count() > 0);
+            servers.iter().filter_map(Weak::upgrade).collect()
+        };
+        for server in servers {
+            server.publish_parameter_values(parameters.clone());
+        }
+    }
+
+    /// Attaches a server, so that parameter changes are published to its clients.
+    pub(crate) fn attach(&self, server: Weak<Server>) {
+        self.0.servers.lock().push(server);
+    }
+
+    /// Returns the named parameters, or all parameters if `names` is empty.
+    ///
+    /// Unknown parameter names are ignored.
+    pub(crate) fn get_parameters(&self, names: &[String]) -> Vec<Parameter> {
+        if names.is_empty() {
+            return self.parameters();
+        }
+        let entries = self.0.entries.read();
+        names
+            .iter()
+            .filter_map(|name| entries.get(name).map(Entry::parameter))
+            .collect()
+    }
+
+    /// Applies parameter values set by a client.
+    ///
+    /// Publishes the values that were applied to subscribed clients, and returns the current
+    /// values of the known parameters, along with errors for the values that were rejected.
+    pub(crate) fn set_from_client(
+        &self,
+        parameters: Vec<Parameter>,
+    ) -> (Vec<Parameter>, Vec<FoxgloveError>) {
+        let mut current = Vec::with_capacity(parameters.len());
+        let mut updated = Vec::with_capacity(parameters.len());
+        let mut errors = Vec::new();
+        for Parameter { name, value, .. } in parameters {
+            let result = match (self.descriptor(&name), value) {
+                (None, _) => Err(FoxgloveError::UnknownParameter(name.clone())),
+                (Some(d), _) if d.read_only => Err(FoxgloveError::ReadOnlyParameter(name.clone())),
+                (Some(_), None) => Err(FoxgloveError::InvalidParameterValue(
+                    name.clone(),
+                    "missing value".to_string(),
+                )),
+                (Some(_), Some(value)) => self.update(&name, value),
+            };
+            match result {
+                Ok(parameter) => {
+                    current.push(parameter.clone());
+                    updated.push(parameter);
+                }
+                Err(err) => {
+                    current.extend(self.get_parameters(std::slice::from_ref(&name)));
+                    errors.push(err);
+                }
+            }
+        }
+        self.publish(updated);
+        (current, errors)
+    }
+}
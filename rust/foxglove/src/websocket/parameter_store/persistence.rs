@@ -0,0 +1,183 @@
+//! Saving, restoring, and recording parameter values.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde_json::json;
+
+use super::{apply, ParameterStore};
+use crate::websocket::{Parameter, ParameterValue};
+use crate::{ChannelBuilder, FoxgloveError, Schema};
+
+/// The JSON schema for messages logged by [`ParameterStore::log_changes`].
+const PARAMETER_VALUES_SCHEMA: &str = r#"{
+  "type": "object",
+  "properties": {
+    "parameters": {
+      "type": "array",
+      "items": {
+        "type": "object",
+        "properties": {
+          "name": { "type": "string" },
+          "type": { "type": "string", "enum": ["byte_array", "float64", "float64_array"] },
+          "value": {}
+        },
+        "required": ["name"]
+      }
+    }
+  },
+  "required": ["parameters"]
+}"#;
+
+/// A file format for parameter snapshots, determined by the file extension.
+enum Format {
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+impl Format {
+    fn from_path(path: &Path) -> Result<Self, FoxgloveError> {
+        match path.extension().and_then(|e| e.to_str()) {
+            #[cfg(feature = "yaml")]
+            Some("yaml" | "yml") => Ok(Self::Yaml),
+            #[cfg(not(feature = "yaml"))]
+            Some("yaml" | "yml") => Err(FoxgloveError::Unspecified(
+                "YAML parameter files require the `yaml` feature".into(),
+            )),
+            _ => Ok(Self::Json),
+        }
+    }
+
+    fn serialize(
+        &self,
+        snapshot: &BTreeMap<String, ParameterValue>,
+    ) -> Result<Vec<u8>, FoxgloveError> {
+        match self {
+            Self::Json => serde_json::to_vec_pretty(snapshot)
+                .map_err(|e| FoxgloveError::Unspecified(e.into())),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => serde_yaml::to_string(snapshot)
+                .map(String::into_bytes)
+                .map_err(|e| FoxgloveError::Unspecified(e.into())),
+        }
+    }
+
+    fn deserialize(&self, data: &[u8]) -> Result<BTreeMap<String, ParameterValue>, FoxgloveError> {
+        match self {
+            Self::Json => {
+                serde_json::from_slice(data).map_err(|e| FoxgloveError::Unspecified(e.into()))
+            }
+            #[cfg(feature = "yaml")]
+            Self::Yaml => {
+                serde_yaml::from_slice(data).map_err(|e| FoxgloveError::Unspecified(e.into()))
+            }
+        }
+    }
+}
+
+impl ParameterStore {
+    /// Returns the current values of all parameters, keyed by name.
+    pub fn snapshot(&self) -> BTreeMap<String, ParameterValue> {
+        self.0
+            .entries
+            .read()
+            .iter()
+            .map(|(name, entry)| (name.clone(), entry.value.borrow().clone()))
+            .collect()
+    }
+
+    /// Restores parameter values from a snapshot, and publishes them to subscribed clients.
+    ///
+    /// Values for parameters that have not been declared are ignored. If any value is not valid
+    /// for its parameter, an error is returned and no values are restored.
+    pub fn restore(&self, snapshot: BTreeMap<String, ParameterValue>) -> Result<(), FoxgloveError> {
+        // Hold the write lock while validating and applying, so that the snapshot is restored
+        // atomically with respect to concurrent updates.
+        let updated = {
+            let entries = self.0.entries.write();
+            let mut known = Vec::with_capacity(snapshot.len());
+            for (name, value) in snapshot {
+                match entries.get(&name) {
+                    Some(entry) => {
+                        entry.descriptor.validate(&value)?;
+                        known.push((name, value));
+                    }
+                    None => tracing::warn!("Ignoring value for unknown parameter {name}"),
+                }
+            }
+            known
+                .into_iter()
+                .map(|(name, value)| apply(&entries, &name, value))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        self.publish(updated);
+        Ok(())
+    }
+
+    /// Loads parameter values from a file, as with [`ParameterStore::restore`].
+    ///
+    /// The file contains an object mapping parameter names to values. Files with a `.yaml` or
+    /// `.yml` extension are parsed as YAML, which requires the `yaml` feature. Other files are
+    /// parsed as JSON.
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<(), FoxgloveError> {
+        let path = path.as_ref();
+        let format = Format::from_path(path)?;
+        let data = std::fs::read(path)?;
+        self.restore(format.deserialize(&data)?)
+    }
+
+    /// Saves the current parameter values to a file, in the format described by
+    /// [`ParameterStore::load`].
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), FoxgloveError> {
+        let path = path.as_ref();
+        let data = Format::from_path(path)?.serialize(&self.snapshot())?;
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+
+    /// Saves the current parameter values to a file whenever parameters change.
+    ///
+    /// The file is written synchronously, as described in [`ParameterStore::on_change`]. Errors
+    /// are logged and otherwise ignored.
+    pub fn save_on_change(&self, path: impl Into<PathBuf>) -> Result<(), FoxgloveError> {
+        let path = path.into();
+        // Validate the format up front, rather than on every change.
+        Format::from_path(&path)?;
+        let store = Arc::downgrade(&self.0);
+        self.on_change(move |_| {
+            let Some(inner) = store.upgrade() else {
+                return;
+            };
+            if let Err(err) = ParameterStore(inner).save(&path) {
+                tracing::warn!("Failed to save parameters to {}: {err}", path.display());
+            }
+        });
+        Ok(())
+    }
+
+    /// Logs parameter values to a channel with the specified topic, so that they are recorded by
+    /// sinks such as an [`McapWriter`](crate::McapWriter).
+    ///
+    /// The current values of all parameters are logged immediately, and updated values are logged
+    /// whenever parameters change. Messages are JSON-encoded objects with a `parameters` array,
+    /// in the same form as the parameter values sent to websocket clients.
+    pub fn log_changes(&self, topic: impl Into<String>) -> Result<(), FoxgloveError> {
+        let channel = ChannelBuilder::new(topic)
+            .message_encoding("json")
+            .schema(Schema::new(
+                "ParameterValues",
+                "jsonschema",
+                PARAMETER_VALUES_SCHEMA.as_bytes(),
+            ))
+            .build()?;
+        let log = move |parameters: &[Parameter]| {
+            let msg = json!({ "parameters": parameters }).to_string();
+            channel.log(msg.as_bytes());
+        };
+        log(&self.parameters());
+        self.on_change(log);
+        Ok(())
+    }
+}
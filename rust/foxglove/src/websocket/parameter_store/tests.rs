@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use assert_matches::assert_matches;
+use serde_json::{json, Value};
+
+use super::{ParameterDescriptor, ParameterStore};
+use crate::testutil::{GlobalContextTest, RecordingSink};
+use crate::websocket::{Parameter, ParameterType, ParameterValue};
+use crate::{FoxgloveError, LogContext};
+
+fn make_store() -> ParameterStore {
+    let store = ParameterStore::new();
+    store
+        .declare(
+            ParameterDescriptor::new("gain", 1.0)
+                .with_description("Controller gain")
+                .with_range(0.0, 10.0),
+        )
+        .unwrap();
+    store
+        .declare(ParameterDescriptor::new("enabled", true))
+        .unwrap();
+    store
+        .declare(ParameterDescriptor::new("weights", vec![1.0, 2.0]).with_range(0.0, 5.0))
+        .unwrap();
+    store
+        .declare(ParameterDescriptor::new("version", 3.0).with_read_only(true))
+        .unwrap();
+    store
+}
+
+#[test]
+fn test_declare() {
+    let store = make_store();
+    assert_matches!(
+        store.declare(ParameterDescriptor::new("gain", 2.0)),
+        Err(FoxgloveError::DuplicateParameter(name)) if name == "gain"
+    );
+    assert_matches!(
+        store.declare(ParameterDescriptor::new("bad", 20.0).with_range(0.0, 10.0)),
+        Err(FoxgloveError::InvalidParameterValue(name, _)) if name == "bad"
+    );
+
+    let descriptor = store.descriptor("gain").unwrap();
+    assert_eq!(descriptor.description(), Some("Controller gain"));
+    assert_eq!(descriptor.range(), Some((0.0, 10.0)));
+    assert!(!descriptor.is_read_only());
+    assert!(store.descriptor("version").unwrap().is_read_only());
+
+    assert_eq!(
+        store.parameters(),
+        vec![
+            Parameter {
+                name: "enabled".to_string(),
+                r#type: None,
+                value: Some(ParameterValue::Bool(true)),
+            },
+            Parameter {
+                name: "gain".to_string(),
+                r#type: Some(ParameterType::Float64),
+                value: Some(ParameterValue::Number(1.0)),
+            },
+            Parameter {
+                name: "version".to_string(),
+                r#type: Some(ParameterType::Float64),
+                value: Some(ParameterValue::Number(3.0)),
+            },
+            Parameter {
+                name: "weights".to_string(),
+                r#type: Some(ParameterType::Float64Array),
+                value: Some(vec![1.0, 2.0].into()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_set_and_validate() {
+    let store = make_store();
+    store.set("gain", 2.5).unwrap();
+    assert_eq!(store.get("gain"), Some(ParameterValue::Number(2.5)));
+
+    // Read-only parameters can be set by the application.
+    store.set("version", 4.0).unwrap();
+
+    assert_matches!(
+        store.set("missing", 1.0),
+        Err(FoxgloveError::UnknownParameter(name)) if name == "missing"
+    );
+    assert_matches!(
+        store.set("gain", true),
+        Err(FoxgloveError::InvalidParameterValue(name, _)) if name == "gain"
+    );
+    assert_matches!(
+        store.set("gain", 11.0),
+        Err(FoxgloveError::InvalidParameterValue(name, _)) if name == "gain"
+    );
+    assert_matches!(
+        store.set("weights", vec![1.0, 6.0]),
+        Err(FoxgloveError::InvalidParameterValue(name, _)) if name == "weights"
+    );
+    assert_matches!(
+        store.set("weights", ParameterValue::Array(vec![ParameterValue::Bool(true)])),
+        Err(FoxgloveError::InvalidParameterValue(name, _)) if name == "weights"
+    );
+    assert_eq!(store.get("gain"), Some(ParameterValue::Number(2.5)));
+    assert_eq!(store.get("weights"), Some(vec![1.0, 2.0].into()));
+}
+
+#[test]
+fn test_set_from_client() {
+    let store = make_store();
+    let (current, errors) = store.set_from_client(vec![
+        Parameter {
+            name: "gain".to_string(),
+            r#type: None,
+            value: Some(ParameterValue::Number(5.0)),
+        },
+        Parameter {
+            name: "version".to_string(),
+            r#type: None,
+            value: Some(ParameterValue::Number(5.0)),
+        },
+        Parameter {
+            name: "enabled".to_string(),
+            r#type: None,
+            value: Some(ParameterValue::Number(5.0)),
+        },
+        Parameter {
+            name: "missing".to_string(),
+            r#type: None,
+            value: Some(ParameterValue::Number(5.0)),
+        },
+    ]);
+
+    // Rejected values are reported with their current value.
+    assert_eq!(
+        current
+            .into_iter()
+            .map(|p| (p.name, p.value.unwrap()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("gain".to_string(), ParameterValue::Number(5.0)),
+            ("version".to_string(), ParameterValue::Number(3.0)),
+            ("enabled".to_string(), ParameterValue::Bool(true)),
+        ]
+    );
+    assert_eq!(errors.len(), 3);
+    assert_matches!(&errors[0], FoxgloveError::ReadOnlyParameter(name) if name == "version");
+    assert_matches!(&errors[1], FoxgloveError::InvalidParameterValue(name, _) if name == "enabled");
+    assert_matches!(&errors[2], FoxgloveError::UnknownParameter(name) if name == "missing");
+}
+
+#[tokio::test]
+async fn test_watch() {
+    let store = make_store();
+    let mut rx = store.watch("gain").unwrap();
+    assert!(store.watch("missing").is_none());
+    assert_eq!(*rx.borrow_and_update(), ParameterValue::Number(1.0));
+
+    let task = tokio::spawn(async move {
+        rx.changed().await.unwrap();
+        rx.borrow_and_update().clone()
+    });
+    store.set("gain", 7.0).unwrap();
+    assert_eq!(task.await.unwrap(), ParameterValue::Number(7.0));
+}
+
+#[test]
+fn test_snapshot_restore() {
+    let store = make_store();
+    store.set("gain", 4.0).unwrap();
+    let snapshot = store.snapshot();
+    assert_eq!(snapshot.len(), 4);
+    assert_eq!(snapshot["gain"], ParameterValue::Number(4.0));
+
+    let restored = make_store();
+    restored.restore(snapshot).unwrap();
+    assert_eq!(restored.parameters(), store.parameters());
+
+    // Unknown parameters are ignored.
+    restored
+        .restore(BTreeMap::from([(
+            "missing".to_string(),
+            ParameterValue::Number(1.0),
+        )]))
+        .unwrap();
+
+    // Invalid snapshots are rejected without restoring any values.
+    let result = restored.restore(BTreeMap::from([
+        ("enabled".to_string(), ParameterValue::Bool(false)),
+        ("gain".to_string(), ParameterValue::Number(100.0)),
+    ]));
+    assert_matches!(result, Err(FoxgloveError::InvalidParameterValue(name, _)) if name == "gain");
+    assert_eq!(restored.get("enabled"), Some(ParameterValue::Bool(true)));
+}
+
+#[test]
+fn test_load_save_json() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("params.json");
+    std::fs::write(&path, r#"{"gain": 2, "enabled": false, "weights": [3, 4]}"#).unwrap();
+
+    let store = make_store();
+    store.load(&path).unwrap();
+    assert_eq!(store.get("gain"), Some(ParameterValue::Number(2.0)));
+    assert_eq!(store.get("enabled"), Some(ParameterValue::Bool(false)));
+    assert_eq!(store.get("weights"), Some(vec![3.0, 4.0].into()));
+
+    let saved = dir.path().join("saved.json");
+    store.save(&saved).unwrap();
+    let value: Value = serde_json::from_slice(&std::fs::read(&saved).unwrap()).unwrap();
+    assert_eq!(
+        value,
+        json!({"enabled": false, "gain": 2.0, "version": 3.0, "weights": [3.0, 4.0]})
+    );
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_load_save_yaml() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("params.yaml");
+    std::fs::write(&path, "gain: 2\nweights: [3, 4.5]\n").unwrap();
+
+    let store = make_store();
+    store.load(&path).unwrap();
+    assert_eq!(store.get("gain"), Some(ParameterValue::Number(2.0)));
+    assert_eq!(store.get("weights"), Some(vec![3.0, 4.5].into()));
+
+    let saved = dir.path().join("saved.yml");
+    store.save(&saved).unwrap();
+    let restored = make_store();
+    restored.load(&saved).unwrap();
+    assert_eq!(restored.parameters(), store.parameters());
+}
+
+#[test]
+fn test_save_on_change() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("params.json");
+
+    let store = make_store();
+    store.save_on_change(&path).unwrap();
+    assert!(!path.exists());
+
+    store.set("gain", 6.0).unwrap();
+    let restored = make_store();
+    restored.load(&path).unwrap();
+    assert_eq!(restored.get("gain"), Some(ParameterValue::Number(6.0)));
+}
+
+#[test]
+fn test_log_changes() {
+    let _cleanup = GlobalContextTest::new();
+    let recording_sink = Arc::new(RecordingSink::new());
+    assert!(LogContext::global().add_sink(recording_sink.clone()));
+
+    let store = make_store();
+    store.log_changes("/parameters").unwrap();
+    store.set("gain", 2.0).unwrap();
+
+    let recorded = recording_sink.recorded.lock();
+    assert_eq!(recorded.len(), 2);
+    let schema = recorded[0].channel.schema().unwrap();
+    assert_eq!(schema.name, "ParameterValues");
+    assert_eq!(schema.encoding, "jsonschema");
+    assert_eq!(recorded[0].channel.topic(), "/parameters");
+
+    let initial: Value = serde_json::from_slice(&recorded[0].msg).unwrap();
+    assert_eq!(initial["parameters"].as_array().unwrap().len(), 4);
+    let update: Value = serde_json::from_slice(&recorded[1].msg).unwrap();
+    assert_eq!(
+        update,
+        json!({"parameters": [{"name": "gain", "type": "float64", "value": 2.0}]})
+    );
+}
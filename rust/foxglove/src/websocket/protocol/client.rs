@@ -4,7 +4,9 @@
 use crate::{
     channel::ChannelId,
     websocket::service::{CallId, ServiceId},
+    Schema,
 };
+use base64::prelude::*;
 use bytes::{Buf, Bytes};
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +22,8 @@ pub(crate) enum ParseError {
     Utf8(#[from] std::str::Utf8Error),
     #[error(transparent)]
     Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Base64(#[from] base64::DecodeError),
 }
 
 #[derive(Debug, PartialEq)]
@@ -208,6 +212,23 @@ pub(crate) struct ClientChannel {
     pub schema_encoding: Option<String>,
     pub schema: Option<String>,
 }
+impl ClientChannel {
+    /// Returns the schema advertised by the client, or `None` if the client did not provide one.
+    ///
+    /// Binary schemas (protobuf and flatbuffer) are base64-encoded on the wire, like the server's own
+    /// advertisements.
+    pub fn decode_schema(&self) -> Result<Option<Schema>, ParseError> {
+        let Some(schema) = self.schema.as_deref() else {
+            return Ok(None);
+        };
+        let encoding = self.schema_encoding.clone().unwrap_or_default();
+        let data = match encoding.as_str() {
+            "protobuf" | "flatbuffer" => BASE64_STANDARD.decode(schema)?,
+            _ => schema.as_bytes().to_vec(),
+        };
+        Ok(Some(Schema::new(&self.schema_name, encoding, data)))
+    }
+}
 
 // https://github.com/foxglove/ws-protocol/blob/main/docs/spec.md#client-unadvertise
 #[derive(Debug, Deserialize, PartialEq)]
@@ -394,6 +415,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_client_channel_schema() {
+        let mut channel = ClientChannel {
+            id: ClientChannelId::new(1),
+            topic: "/test".to_string(),
+            encoding: "json".to_string(),
+            schema_name: "test".to_string(),
+            schema: None,
+            schema_encoding: None,
+        };
+        assert_eq!(channel.decode_schema().unwrap(), None);
+
+        channel.schema_encoding = Some("jsonschema".to_string());
+        channel.schema = Some(r#"{"type":"object"}"#.to_string());
+        assert_eq!(
+            channel.decode_schema().unwrap(),
+            Some(Schema::new(
+                "test",
+                "jsonschema",
+                br#"{"type":"object"}"#.to_vec()
+            ))
+        );
+
+        channel.encoding = "protobuf".to_string();
+        channel.schema_encoding = Some("protobuf".to_string());
+        channel.schema = Some(BASE64_STANDARD.encode(b"\x01\x02\x03"));
+        assert_eq!(
+            channel.decode_schema().unwrap(),
+            Some(Schema::new("test", "protobuf", b"\x01\x02\x03".to_vec()))
+        );
+
+        channel.schema = Some("not base64!".to_string());
+        assert_matches!(channel.decode_schema(), Err(ParseError::Base64(_)));
+    }
+
     #[test]
     fn test_parse_unadvertise() {
         let msg = json!({
@@ -3,7 +3,7 @@ use crate::channel::ChannelId;
 use crate::websocket::service::CallId;
 use crate::websocket::service::ServiceId;
 use crate::websocket::service::{self, Service};
-use crate::FoxgloveError;
+use crate::{FoxgloveError, Schema};
 use base64::prelude::*;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
@@ -28,7 +28,7 @@ pub struct Advertisement<'a> {
     pub topic: &'a str,
     pub encoding: &'a str,
     pub schema_name: &'a str,
-    pub schema: String,
+    pub schema: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema_encoding: Option<&'a str>,
 }
@@ -62,6 +62,30 @@ pub enum ParameterValue {
     Dict(HashMap<String, ParameterValue>),
 }
 
+impl From<f64> for ParameterValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<bool> for ParameterValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<Vec<u8>> for ParameterValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Vec<f64>> for ParameterValue {
+    fn from(value: Vec<f64>) -> Self {
+        Self::Array(value.into_iter().map(Self::Number).collect())
+    }
+}
+
 /// Informs the client about a parameter.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Parameter {
@@ -177,20 +201,25 @@ pub fn server_info(
     .to_string()
 }
 
+/// Encodes schema data for an advertisement.
+///
+/// Binary schemas (protobuf and flatbuffer) are base64-encoded, and other schemas must be valid
+/// UTF-8.
+pub fn encode_schema(schema: &Schema) -> Result<String, FoxgloveError> {
+    match schema.encoding.as_str() {
+        "protobuf" | "flatbuffer" => Ok(BASE64_STANDARD.encode(&schema.data)),
+        _ => String::from_utf8(schema.data.to_vec())
+            .map_err(|e| FoxgloveError::Unspecified(e.into())),
+    }
+}
+
 // https://github.com/foxglove/ws-protocol/blob/main/docs/spec.md#advertise
-// Caller must check that the channel has a schema, otherwise this will panic.
-pub fn advertisement(channel: &Channel) -> Result<String, FoxgloveError> {
-    let schema = &channel
-        .schema
-        .as_ref()
+// The schema data must have been encoded with `encode_schema`.
+pub fn advertisement(channel: &Channel, schema_data: &str) -> Result<String, FoxgloveError> {
+    let schema = channel
+        .schema()
         .ok_or_else(|| FoxgloveError::SchemaRequired)?;
 
-    let schema_data = match schema.encoding.as_str() {
-        "protobuf" => BASE64_STANDARD.encode(&schema.data),
-        _ => String::from_utf8(schema.data.to_vec())
-            .map_err(|e| FoxgloveError::Unspecified(e.into()))?,
-    };
-
     Ok(json!({
         "op": "advertise",
         "channels": [Advertisement{
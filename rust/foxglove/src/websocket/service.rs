@@ -1,26 +1,31 @@
 //! Websocket services.
 
 use std::fmt::Display;
+use std::future::Future;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
 use crate::websocket::Client;
+use crate::{Decode, Encode};
 
 mod handler;
 mod request;
 mod response;
 mod schema;
 mod semaphore;
+mod typed;
+use handler::{AsyncHandlerFn, HandlerFn, SyncHandlerFn};
 pub use handler::{Handler, SyncHandler};
-use handler::{HandlerFn, SyncHandlerFn};
 pub use request::Request;
 pub use response::Responder;
 pub(crate) use schema::MessageSchema;
 pub use schema::ServiceSchema;
 pub(crate) use semaphore::Semaphore;
+pub use typed::TypedServiceBuilder;
 
 /// A service ID, which uniquely identifies a service hosted by the server.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize, Serialize)]
@@ -75,6 +80,8 @@ pub struct ServiceBuilder {
     id: ServiceId,
     name: String,
     schema: ServiceSchema,
+    timeout: Option<Duration>,
+    concurrency_limit: Option<usize>,
 }
 impl ServiceBuilder {
     /// Creates a new builder for a websocket service.
@@ -85,6 +92,8 @@ impl ServiceBuilder {
             id: ServiceId::new(id),
             name: name.into(),
             schema,
+            timeout: None,
+            concurrency_limit: None,
         }
     }
 
@@ -95,12 +104,38 @@ impl ServiceBuilder {
         self
     }
 
+    /// Sets a timeout for service calls.
+    ///
+    /// If the handler does not respond within the timeout, the server responds to the client with
+    /// a service call failure, and the call is cancelled. Any later response from the handler is
+    /// discarded.
+    ///
+    /// By default, service calls do not time out.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of concurrent calls to this service, across all clients.
+    ///
+    /// Calls in excess of this limit are rejected with a service call failure. This is in addition
+    /// to the per-client limit configured with
+    /// [`WebSocketServer::service_calls_per_client`](crate::WebSocketServer::service_calls_per_client).
+    ///
+    /// By default, there is no limit on concurrent calls to the service.
+    pub fn concurrency_limit(mut self, limit: usize) -> Self {
+        self.concurrency_limit = Some(limit);
+        self
+    }
+
     /// Configures a handler and returns the constructed [`Service`].
     pub fn handler<H: Handler + 'static>(self, handler: H) -> Service {
         Service {
             id: self.id,
             name: self.name,
             schema: self.schema,
+            timeout: self.timeout,
+            concurrency_limit: self.concurrency_limit.map(Semaphore::new),
             handler: Arc::new(handler),
         }
     }
@@ -125,6 +160,20 @@ impl ServiceBuilder {
     {
         self.handler(SyncHandlerFn(call))
     }
+
+    /// Configures an asynchronous handler function and returns the constructed [`Service`].
+    ///
+    /// The `call` function is invoked from the client's main poll loop, and the future it returns
+    /// is spawned on the server's runtime. The response is sent to the client when the future
+    /// completes. If the call times out or the client disconnects, the future is dropped.
+    pub fn async_handler_fn<F, Fut, E>(self, call: F) -> Service
+    where
+        F: Fn(Client, Request) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Bytes, E>> + Send + 'static,
+        E: Display + 'static,
+    {
+        self.handler(AsyncHandlerFn(call))
+    }
 }
 
 /// A websocket service.
@@ -133,6 +182,8 @@ pub struct Service {
     id: ServiceId,
     name: String,
     schema: ServiceSchema,
+    timeout: Option<Duration>,
+    concurrency_limit: Option<Semaphore>,
     handler: Arc<dyn Handler>,
 }
 
@@ -142,6 +193,7 @@ impl std::fmt::Debug for Service {
             .field("id", &self.id)
             .field("name", &self.name)
             .field("schema", &self.schema)
+            .field("timeout", &self.timeout)
             .finish_non_exhaustive()
     }
 }
@@ -152,6 +204,21 @@ impl Service {
         ServiceBuilder::new(name, schema)
     }
 
+    /// Creates a new builder for a websocket service with typed requests and responses.
+    ///
+    /// The service schema is named `schema_name`, and the request and response schemas and
+    /// encodings are derived from the [`Encode`] implementations of `Req` and `Resp`.
+    pub fn typed_builder<Req, Resp>(
+        name: impl Into<String>,
+        schema_name: impl Into<String>,
+    ) -> TypedServiceBuilder<Req, Resp>
+    where
+        Req: Encode + Decode + Send + 'static,
+        Resp: Encode + Send + 'static,
+    {
+        TypedServiceBuilder::new(name, schema_name)
+    }
+
     /// Returns the service's ID.
     pub fn id(&self) -> ServiceId {
         self.id
@@ -167,6 +234,16 @@ impl Service {
         &self.schema
     }
 
+    /// Returns the service call timeout, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// The semaphore limiting concurrent calls to this service, if any.
+    pub(crate) fn concurrency_limit(&self) -> Option<&Semaphore> {
+        self.concurrency_limit.as_ref()
+    }
+
     /// The declared request encoding.
     pub(crate) fn request_encoding(&self) -> Option<&str> {
         self.schema().request().map(|rs| rs.encoding.as_str())
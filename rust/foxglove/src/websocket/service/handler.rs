@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::future::Future;
 
 use bytes::Bytes;
 
@@ -13,7 +14,8 @@ pub trait Handler: Send + Sync {
     ///
     /// This method is invoked from the client's main poll loop and must not block. If blocking or
     /// long-running behavior is required, the implementation should use [`tokio::task::spawn`] (or
-    /// [`tokio::task::spawn_blocking`]) to handle the request asynchronously.
+    /// [`tokio::task::spawn_blocking`]) to handle the request asynchronously, or use
+    /// [`ServiceBuilder::async_handler_fn`](super::ServiceBuilder::async_handler_fn).
     ///
     /// The implementation is responsible for completing the request with [`Responder::respond`],
     /// otherwise no response will be sent to the client.
@@ -74,3 +76,18 @@ where
         self.0(client, request)
     }
 }
+
+/// A wrapper around a function that serves as an asynchronous service call handler.
+pub(crate) struct AsyncHandlerFn<F>(pub F);
+
+impl<F, Fut, E> Handler for AsyncHandlerFn<F>
+where
+    F: Fn(Client, Request) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Bytes, E>> + Send + 'static,
+    E: Display + 'static,
+{
+    fn call(&self, client: Client, request: Request, responder: Responder) {
+        let future = self.0(client, request);
+        responder.spawn(async move { future.await.map_err(|e| e.to_string()) });
+    }
+}
@@ -1,42 +1,81 @@
 //! Service call response handling.
 
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use bytes::Bytes;
+use parking_lot::Mutex;
 use tokio_tungstenite::tungstenite::Message;
+use tokio_util::sync::CancellationToken;
 
 use super::semaphore::SemaphoreGuard;
 use super::{CallId, ServiceId};
 use crate::websocket::{protocol, ConnectedClient};
 
+/// State shared between a [`Responder`] and the tasks that supervise the service call.
+#[derive(Debug)]
+struct CallState {
+    /// Set when a response (or failure) has been sent to the client.
+    completed: AtomicBool,
+    /// Cancelled when the call completes, times out, or the client disconnects.
+    cancellation_token: CancellationToken,
+    /// Concurrency slots held by the call, released when the call completes.
+    guards: Mutex<Vec<SemaphoreGuard>>,
+}
+
+impl CallState {
+    /// Marks the call as completed, releasing its concurrency slots. Returns false if it was
+    /// already completed.
+    fn complete(&self) -> bool {
+        let first = !self.completed.swap(true, Ordering::AcqRel);
+        self.release();
+        first
+    }
+
+    /// Releases the call's concurrency slots.
+    fn release(&self) {
+        self.guards.lock().clear();
+    }
+}
+
 /// A handle for completing a service call.
 ///
 /// If you're holding one of these, you're responsible for eventually calling
 /// [`Responder::respond`]. If you drop the responder without responding, the client will never
-/// receive a response for its request.
+/// receive a response for its request, unless the service was configured with a
+/// [timeout](super::ServiceBuilder::timeout).
 #[must_use]
 pub struct Responder {
     client: Arc<ConnectedClient>,
     service_id: ServiceId,
     call_id: CallId,
     encoding: String,
-    _guard: SemaphoreGuard,
+    state: Arc<CallState>,
 }
 impl Responder {
     /// Creates a new responder.
+    ///
+    /// The call is cancelled when the client's cancellation token is cancelled.
     pub(crate) fn new(
         client: Arc<ConnectedClient>,
         service_id: ServiceId,
         call_id: CallId,
         encoding: impl Into<String>,
-        _guard: SemaphoreGuard,
+        guards: Vec<SemaphoreGuard>,
     ) -> Self {
+        let state = Arc::new(CallState {
+            completed: AtomicBool::new(false),
+            cancellation_token: client.cancellation_token.child_token(),
+            guards: Mutex::new(guards),
+        });
         Self {
             client,
             service_id,
             call_id,
             encoding: encoding.into(),
-            _guard,
+            state,
         }
     }
 
@@ -49,14 +88,33 @@ impl Responder {
         self.encoding = encoding.into();
     }
 
+    /// Returns true if the call was cancelled, because it timed out or because the client
+    /// disconnected.
+    ///
+    /// Responding to a cancelled call has no effect.
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancellation_token.is_cancelled()
+    }
+
     /// Completes the request by sending a response to the client.
-    pub fn respond(self, result: Result<Bytes, String>) {
+    pub fn respond(mut self, result: Result<Bytes, String>) {
+        if !self.state.complete() {
+            tracing::debug!(
+                "Dropping response for service {} call {}: call already completed",
+                self.service_id,
+                self.call_id
+            );
+            return;
+        }
+        // Stop the timeout task, if any.
+        self.state.cancellation_token.cancel();
+
         let message = match result {
             Ok(payload) => Message::binary(
                 protocol::server::ServiceCallResponse::new(
                     self.service_id,
                     self.call_id,
-                    self.encoding,
+                    std::mem::take(&mut self.encoding),
                     payload,
                 )
                 .encode(),
@@ -71,4 +129,58 @@ impl Responder {
         // Callee logs errors.
         let _ = self.client.send_control_msg(message);
     }
+
+    /// Spawns a task on the server's runtime which responds with the output of the future.
+    ///
+    /// The future is dropped without responding if the call is cancelled.
+    pub(crate) fn spawn<F>(self, future: F)
+    where
+        F: Future<Output = Result<Bytes, String>> + Send + 'static,
+    {
+        let token = self.state.cancellation_token.clone();
+        self.client.runtime.clone().spawn(async move {
+            tokio::select! {
+                result = future => self.respond(result),
+                () = token.cancelled() => {
+                    tracing::debug!(
+                        "Service {} call {} was cancelled",
+                        self.service_id,
+                        self.call_id
+                    );
+                }
+            }
+        });
+    }
+
+    /// Spawns a task on the server's runtime which fails the call if it has not completed
+    /// within the specified timeout.
+    pub(crate) fn start_timeout(&self, timeout: Duration) {
+        let client = self.client.clone();
+        let state = self.state.clone();
+        let service_id = self.service_id;
+        let call_id = self.call_id;
+        self.client.runtime.spawn(async move {
+            tokio::select! {
+                () = tokio::time::sleep(timeout) => {
+                    if state.complete() {
+                        tracing::debug!("Service {service_id} call {call_id} timed out");
+                        client.send_service_call_failure(
+                            service_id,
+                            call_id,
+                            "Service call timed out",
+                        );
+                    }
+                    state.cancellation_token.cancel();
+                }
+                () = state.cancellation_token.cancelled() => (),
+            }
+        });
+    }
+}
+
+impl Drop for Responder {
+    fn drop(&mut self) {
+        // A responder dropped without responding no longer occupies a concurrency slot.
+        self.state.release();
+    }
 }
@@ -0,0 +1,170 @@
+//! Typed websocket services.
+
+use std::fmt::Display;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+
+use super::{Handler, Request, Responder, Service, ServiceBuilder, ServiceSchema, SyncHandler};
+use crate::websocket::Client;
+use crate::{Decode, Encode};
+
+/// A builder for a websocket service with typed requests and responses.
+///
+/// The request and response schemas and encodings are derived from the [`Encode`]
+/// implementations of `Req` and `Resp`. Requests are decoded with [`Decode`] before being passed
+/// to the handler, and responses are encoded with [`Encode`] before being sent to the client.
+///
+/// Use [`Service::typed_builder`] to create a typed service builder.
+#[must_use]
+#[derive(Debug)]
+pub struct TypedServiceBuilder<Req, Resp> {
+    inner: ServiceBuilder,
+    _phantom: PhantomData<fn(Req) -> Resp>,
+}
+
+impl<Req, Resp> TypedServiceBuilder<Req, Resp>
+where
+    Req: Encode + Decode + Send + 'static,
+    Resp: Encode + Send + 'static,
+{
+    /// Creates a new builder for a typed websocket service.
+    pub(super) fn new(name: impl Into<String>, schema_name: impl Into<String>) -> Self {
+        let mut schema = ServiceSchema::new(schema_name);
+        if let Some(request_schema) = Req::get_schema() {
+            schema = schema.with_request(Req::get_message_encoding(), request_schema);
+        }
+        if let Some(response_schema) = Resp::get_schema() {
+            schema = schema.with_response(Resp::get_message_encoding(), response_schema);
+        }
+        Self {
+            inner: ServiceBuilder::new(name, schema),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Allow overriding the ID for deterministic tests.
+    #[cfg(test)]
+    pub(crate) fn with_id(mut self, id: super::ServiceId) -> Self {
+        self.inner = self.inner.with_id(id);
+        self
+    }
+
+    /// Sets a timeout for service calls.
+    ///
+    /// See [`ServiceBuilder::timeout`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.timeout(timeout);
+        self
+    }
+
+    /// Sets the maximum number of concurrent calls to this service, across all clients.
+    ///
+    /// See [`ServiceBuilder::concurrency_limit`].
+    pub fn concurrency_limit(mut self, limit: usize) -> Self {
+        self.inner = self.inner.concurrency_limit(limit);
+        self
+    }
+
+    /// Configures a synchronous handler function and returns the constructed [`Service`].
+    ///
+    /// The handler is invoked from the client's main poll loop and must not block. If blocking or
+    /// long-running behavior is required, use [`TypedServiceBuilder::async_handler_fn`] instead.
+    pub fn handler_fn<F, E>(self, call: F) -> Service
+    where
+        F: Fn(Req) -> Result<Resp, E> + Send + Sync + 'static,
+        E: Display + 'static,
+    {
+        self.inner.handler(TypedHandlerFn {
+            call,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Configures an asynchronous handler function and returns the constructed [`Service`].
+    ///
+    /// The future returned by the handler is spawned on the server's runtime, and the response is
+    /// sent to the client when it completes. If the call times out or the client disconnects, the
+    /// future is dropped.
+    pub fn async_handler_fn<F, Fut, E>(self, call: F) -> Service
+    where
+        F: Fn(Req) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Resp, E>> + Send + 'static,
+        E: Display + 'static,
+    {
+        self.inner.handler(AsyncTypedHandlerFn {
+            call,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// Decodes a request payload.
+fn decode_request<Req: Decode>(request: &Request) -> Result<Req, String> {
+    Req::decode(request.payload()).map_err(|e| format!("Failed to decode request: {e}"))
+}
+
+/// Encodes a response payload.
+fn encode_response<Resp: Encode>(response: &Resp) -> Result<Bytes, String> {
+    let mut buf = BytesMut::with_capacity(response.encoded_len().unwrap_or_default());
+    response
+        .encode(&mut buf)
+        .map_err(|e| format!("Failed to encode response: {e}"))?;
+    Ok(buf.freeze())
+}
+
+/// A wrapper around a function that serves as a synchronous typed service call handler.
+struct TypedHandlerFn<F, Req, Resp, E> {
+    call: F,
+    _phantom: PhantomData<fn(Req) -> Result<Resp, E>>,
+}
+
+impl<F, Req, Resp, E> SyncHandler for TypedHandlerFn<F, Req, Resp, E>
+where
+    F: Fn(Req) -> Result<Resp, E> + Send + Sync,
+    Req: Decode,
+    Resp: Encode,
+    E: Display,
+{
+    type Error = String;
+
+    fn call(&self, _client: Client, request: Request) -> Result<Bytes, Self::Error> {
+        let request = decode_request(&request)?;
+        let response = (self.call)(request).map_err(|e| e.to_string())?;
+        encode_response(&response)
+    }
+}
+
+/// A wrapper around a function that serves as an asynchronous typed service call handler.
+struct AsyncTypedHandlerFn<F, Req, Resp, E> {
+    call: F,
+    _phantom: PhantomData<fn(Req) -> Result<Resp, E>>,
+}
+
+impl<F, Fut, Req, Resp, E> Handler for AsyncTypedHandlerFn<F, Req, Resp, E>
+where
+    F: Fn(Req) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Resp, E>> + Send + 'static,
+    Req: Decode,
+    Resp: Encode + Send + 'static,
+    E: Display + 'static,
+{
+    fn call(&self, _client: Client, request: Request, responder: Responder) {
+        let request = match decode_request(&request) {
+            Ok(request) => request,
+            Err(err) => {
+                responder.respond(Err(err));
+                return;
+            }
+        };
+        let future = (self.call)(request);
+        responder.spawn(async move {
+            future
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|response| encode_response(&response))
+        });
+    }
+}
@@ -1,20 +1,24 @@
 use assert_matches::assert_matches;
-use bytes::{BufMut, BytesMut};
+use bytes::{BufMut, Bytes, BytesMut};
 use futures_util::{FutureExt, SinkExt, StreamExt};
-use serde::Deserialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio_tungstenite::tungstenite::{self, http::HeaderValue, Message};
 use tracing_test::traced_test;
 use tungstenite::client::IntoClientRequest;
 
 use super::{create_server, send_lossy, SendLossyResult, ServerOptions, SUBPROTOCOL};
-use crate::testutil::RecordingServerListener;
+use crate::metrics::{self, Metrics};
+use crate::testutil::{GlobalContextTest, RecordingServerListener, RecordingSink};
 use crate::websocket::service::{CallId, Service, ServiceId, ServiceSchema};
 use crate::websocket::{
-    Capability, ClientChannelId, Parameter, ParameterType, ParameterValue, Status, StatusLevel,
+    Capability, ClientChannelId, Parameter, ParameterDescriptor, ParameterStore, ParameterType,
+    ParameterValue, Status, StatusLevel,
 };
 use crate::{
     collection, Channel, ChannelBuilder, FoxgloveError, LogContext, LogSink, Metadata, Schema,
@@ -113,6 +117,37 @@ async fn test_client_connect() {
     server.stop().await;
 }
 
+#[tokio::test]
+async fn test_client_metrics() {
+    let server = create_server(ServerOptions::default());
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut client_stream = connect_client(addr).await;
+    _ = client_stream.next().await.expect("No serverInfo sent");
+
+    // The client is registered after the server info is sent.
+    let mut metrics = Metrics::default();
+    for _ in 0..100 {
+        metrics = Metrics::default();
+        server.metrics(&mut metrics);
+        if metrics.get(metrics::WS_CLIENTS, &[]) == Some(1) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(metrics.get(metrics::WS_CLIENTS, &[]), Some(1));
+    assert_eq!(metrics.get(metrics::WS_CLIENT_QUEUE_DEPTH, &[]), Some(0));
+    assert_eq!(
+        metrics.get(metrics::WS_CLIENT_DROPPED_MESSAGES, &[]),
+        Some(0)
+    );
+
+    server.stop().await;
+}
+
 #[traced_test]
 #[tokio::test]
 async fn test_handshake_with_unknown_subprotocol_fails_on_client() {
@@ -280,6 +315,56 @@ async fn test_advertise_to_client() {
     server.stop().await;
 }
 
+#[cfg(feature = "flatbuffer")]
+#[traced_test]
+#[tokio::test]
+async fn test_advertise_flatbuffer_channel() {
+    use crate::flatbuffer::schemas::Log;
+    use crate::flatbuffer::Flatbuffer;
+    use crate::Encode;
+    use base64::prelude::*;
+
+    let server = create_server(ServerOptions::default());
+    let ctx = LogContext::new();
+    ctx.add_sink(server.clone());
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut client = connect_client(addr).await;
+    client.next().await.expect("No serverInfo sent").unwrap();
+
+    let ch = ChannelBuilder::new("/log")
+        .with_context(&ctx)
+        .build_typed::<Flatbuffer<Log>>()
+        .expect("Failed to create channel");
+    let schema = Flatbuffer::<Log>::get_schema().unwrap();
+    assert!(std::str::from_utf8(&schema.data).is_err());
+
+    let msg = tokio::time::timeout(Duration::from_secs(1), client.next())
+        .await
+        .expect("No advertisement sent")
+        .unwrap()
+        .unwrap();
+    let msg: Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
+    assert_eq!(msg["op"], "advertise");
+    let channel = &msg["channels"][0];
+    assert_eq!(
+        channel["id"].as_u64().unwrap(),
+        u64::from(ctx.get_channel_by_topic(ch.topic()).unwrap().id())
+    );
+    assert_eq!(channel["encoding"], "flatbuffer");
+    assert_eq!(channel["schemaEncoding"], "flatbuffer");
+    assert_eq!(channel["schemaName"], schema.name.as_str());
+    let data = BASE64_STANDARD
+        .decode(channel["schema"].as_str().unwrap())
+        .unwrap();
+    assert_eq!(data, schema.data.as_ref());
+
+    server.stop().await;
+}
+
 #[traced_test]
 #[tokio::test]
 async fn test_log_only_to_subscribers() {
@@ -790,6 +875,159 @@ async fn test_client_advertising() {
     server.stop().await;
 }
 
+#[traced_test]
+#[tokio::test]
+async fn test_forward_client_channels() {
+    let _cleanup = GlobalContextTest::new();
+    let recording_sink = Arc::new(RecordingSink::new());
+    assert!(LogContext::global().add_sink(recording_sink.clone()));
+
+    let server = create_server(ServerOptions {
+        forward_client_channels: true,
+        ..Default::default()
+    });
+
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut ws_client = connect_client(addr).await;
+
+    let msg = ws_client
+        .next()
+        .await
+        .expect("No serverInfo sent")
+        .expect("Failed to parse message");
+    let server_info: Value =
+        serde_json::from_str(msg.to_text().expect("Failed to get message text"))
+            .expect("Failed to parse server info");
+    assert_eq!(server_info["capabilities"], json!(["clientPublish"]));
+
+    let channel_id = 1;
+    let advertise = json!({
+        "op": "advertise",
+        "channels": [
+            {
+                "id": channel_id,
+                "topic": "/client",
+                "encoding": "json",
+                "schemaName": "test",
+                "schemaEncoding": "jsonschema",
+                "schema": r#"{"type":"object"}"#,
+            }
+        ]
+    });
+    ws_client
+        .send(Message::text(advertise.to_string()))
+        .await
+        .expect("Failed to send advertisement");
+
+    let msg_bytes = {
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(0x01); // message data opcode
+        bytes.put_u32_le(channel_id);
+        bytes.put_slice(json!({ "a": 1 }).to_string().as_bytes());
+        bytes
+    };
+    ws_client
+        .send(Message::binary(msg_bytes))
+        .await
+        .expect("Failed to send binary message");
+
+    // FG-10395 replace this with something more precise
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let channel = LogContext::global()
+        .get_channel_by_topic("/client")
+        .expect("Client channel was not forwarded");
+    assert_eq!(channel.message_encoding, "json");
+    assert_eq!(
+        channel.schema(),
+        Some(&Schema::new("test", "jsonschema", br#"{"type":"object"}"#))
+    );
+
+    {
+        let recorded = recording_sink.recorded.lock();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].channel.id(), channel.id());
+        assert_eq!(recorded[0].msg, b"{\"a\":1}");
+    }
+
+    let unadvertise = json!({
+        "op": "unadvertise",
+        "channelIds": [channel_id]
+    });
+    ws_client
+        .send(Message::text(unadvertise.to_string()))
+        .await
+        .expect("Failed to send unadvertise");
+
+    // FG-10395 replace this with something more precise
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    assert!(LogContext::global()
+        .get_channel_by_topic("/client")
+        .is_none());
+
+    ws_client.close(None).await.unwrap();
+    server.stop().await;
+}
+
+#[traced_test]
+#[tokio::test]
+async fn test_forward_client_channels_removed_on_disconnect() {
+    let _cleanup = GlobalContextTest::new();
+
+    let server = create_server(ServerOptions {
+        forward_client_channels: true,
+        ..Default::default()
+    });
+
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut ws_client = connect_client(addr).await;
+    _ = ws_client.next().await.expect("No serverInfo sent");
+
+    let advertise = json!({
+        "op": "advertise",
+        "channels": [
+            {
+                "id": 1,
+                "topic": "/client",
+                "encoding": "json",
+                "schemaName": "test",
+            }
+        ]
+    });
+    ws_client
+        .send(Message::text(advertise.to_string()))
+        .await
+        .expect("Failed to send advertisement");
+
+    // FG-10395 replace this with something more precise
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    let channel = LogContext::global()
+        .get_channel_by_topic("/client")
+        .expect("channel not forwarded");
+    // The client did not provide a schema.
+    assert_eq!(channel.schema(), None);
+    drop(channel);
+
+    ws_client.close(None).await.unwrap();
+
+    // FG-10395 replace this with something more precise
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert!(LogContext::global()
+        .get_channel_by_topic("/client")
+        .is_none());
+
+    server.stop().await;
+}
+
 #[traced_test]
 #[tokio::test]
 async fn test_parameter_values() {
@@ -843,6 +1081,117 @@ async fn test_parameter_values() {
     server.stop().await;
 }
 
+#[traced_test]
+#[tokio::test]
+async fn test_parameter_store() {
+    let store = ParameterStore::new();
+    store
+        .declare(ParameterDescriptor::new("gain", 1.0).with_range(0.0, 10.0))
+        .unwrap();
+    store
+        .declare(ParameterDescriptor::new("version", 3.0).with_read_only(true))
+        .unwrap();
+    let mut gain_rx = store.watch("gain").unwrap();
+
+    let server = create_server(ServerOptions {
+        parameter_store: Some(store.clone()),
+        ..Default::default()
+    });
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut ws_client = connect_client(addr).await;
+    let msg = ws_client.next().await.expect("No serverInfo sent").unwrap();
+    let server_info: Value =
+        serde_json::from_str(msg.to_text().expect("utf8")).expect("Failed to parse server info");
+    let capabilities = server_info["capabilities"]
+        .as_array()
+        .expect("capabilities");
+    assert!(capabilities.contains(&json!("parameters")));
+    assert!(capabilities.contains(&json!("parametersSubscribe")));
+
+    // Parameters are served from the store.
+    ws_client
+        .send(Message::text(
+            r#"{"op":"getParameters","parameterNames":[],"id":"get"}"#,
+        ))
+        .await
+        .expect("Failed to send");
+    assert_eq!(
+        next_json(&mut ws_client).await,
+        json!({
+            "op": "parameterValues",
+            "id": "get",
+            "parameters": [
+                {"name": "gain", "type": "float64", "value": 1.0},
+                {"name": "version", "type": "float64", "value": 3.0},
+            ],
+        })
+    );
+
+    ws_client
+        .send(Message::text(
+            r#"{"op":"subscribeParameterUpdates","parameterNames":["gain"]}"#,
+        ))
+        .await
+        .expect("Failed to send");
+
+    // Invalid updates are rejected with a warning, and the current values are returned.
+    ws_client
+        .send(Message::text(
+            r#"{"op":"setParameters","parameters":[{"name":"gain","value":20},{"name":"version","value":4}],"id":"set"}"#,
+        ))
+        .await
+        .expect("Failed to send");
+    for _ in 0..2 {
+        let msg = next_json(&mut ws_client).await;
+        assert_eq!(msg["op"], "status");
+        assert_eq!(msg["level"], 1);
+    }
+    assert_eq!(
+        next_json(&mut ws_client).await,
+        json!({
+            "op": "parameterValues",
+            "id": "set",
+            "parameters": [
+                {"name": "gain", "type": "float64", "value": 1.0},
+                {"name": "version", "type": "float64", "value": 3.0},
+            ],
+        })
+    );
+
+    // Valid updates are applied, published to subscribers, and observed by watchers.
+    ws_client
+        .send(Message::text(
+            r#"{"op":"setParameters","parameters":[{"name":"gain","value":2}]}"#,
+        ))
+        .await
+        .expect("Failed to send");
+    assert_eq!(
+        next_json(&mut ws_client).await,
+        json!({
+            "op": "parameterValues",
+            "parameters": [{"name": "gain", "type": "float64", "value": 2.0}],
+        })
+    );
+    gain_rx.changed().await.unwrap();
+    assert_eq!(*gain_rx.borrow_and_update(), ParameterValue::Number(2.0));
+
+    // Updates from the application are published to subscribers.
+    store.set("gain", 3.0).unwrap();
+    assert_eq!(
+        next_json(&mut ws_client).await,
+        json!({
+            "op": "parameterValues",
+            "parameters": [{"name": "gain", "type": "float64", "value": 3.0}],
+        })
+    );
+
+    server.stop().await;
+}
+
 #[traced_test]
 #[tokio::test]
 async fn test_parameter_unsubscribe_no_updates() {
@@ -1275,6 +1624,367 @@ async fn test_services() {
     );
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+struct AddRequest {
+    a: u32,
+    b: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+struct AddResponse {
+    sum: u32,
+}
+
+fn service_call_request(service_id: u32, call_id: u32, encoding: &str, payload: &[u8]) -> Message {
+    let mut buf = BytesMut::new();
+    buf.put_u8(2); // opcode
+    buf.put_u32_le(service_id);
+    buf.put_u32_le(call_id);
+    buf.put_u32_le(encoding.len() as u32);
+    buf.put(encoding.as_bytes());
+    buf.put(payload);
+    Message::binary(buf.freeze())
+}
+
+#[traced_test]
+#[tokio::test]
+async fn test_typed_services() {
+    let sync_svc = Service::typed_builder::<AddRequest, AddResponse>("/add", "AddTwoInts")
+        .with_id(ServiceId::new(1))
+        .handler_fn(|req| {
+            req.a
+                .checked_add(req.b)
+                .map(|sum| AddResponse { sum })
+                .ok_or("overflow")
+        });
+    let async_svc = Service::typed_builder::<AddRequest, AddResponse>("/add_async", "AddTwoInts")
+        .with_id(ServiceId::new(2))
+        .async_handler_fn(|req| async move {
+            tokio::task::yield_now().await;
+            Ok::<_, String>(AddResponse { sum: req.a + req.b })
+        });
+
+    let server = create_server(ServerOptions {
+        services: [sync_svc, async_svc]
+            .into_iter()
+            .map(|s| (s.name().to_string(), s))
+            .collect(),
+        ..Default::default()
+    });
+
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut client = connect_client(addr).await;
+    let msg = client.next().await.expect("No serverInfo sent").unwrap();
+    let server_info: Value =
+        serde_json::from_str(msg.to_text().expect("utf8")).expect("Failed to parse server info");
+    assert_eq!(server_info["supportedEncodings"], json!(["json"]));
+
+    let msg = client
+        .next()
+        .await
+        .expect("No service advertisement sent")
+        .unwrap();
+    let value: Value = serde_json::from_str(msg.to_text().expect("utf8")).expect("json");
+    let adv_services = value["services"].as_array().expect("services key");
+    assert_eq!(adv_services.len(), 2);
+    for service in adv_services {
+        assert_eq!(service["type"], "AddTwoInts");
+        assert_eq!(service["request"]["encoding"], "json");
+        assert_eq!(service["request"]["schemaEncoding"], "jsonschema");
+        assert_eq!(service["response"]["encoding"], "json");
+        assert_eq!(service["response"]["schemaEncoding"], "jsonschema");
+    }
+
+    for service_id in [1, 2] {
+        client
+            .send(service_call_request(
+                service_id,
+                10,
+                "json",
+                br#"{"a": 1, "b": 2}"#,
+            ))
+            .await
+            .expect("Failed to send");
+        let msg = client
+            .next()
+            .await
+            .expect("No service call response")
+            .expect("Failed to parse response");
+        let mut buf = BytesMut::new();
+        buf.put_u8(3); // opcode
+        buf.put_u32_le(service_id);
+        buf.put_u32_le(10); // call id
+        buf.put_u32_le(4); // encoding length
+        buf.put(b"json".as_slice());
+        buf.put(br#"{"sum":3}"#.as_slice());
+        assert_eq!(msg.into_data(), buf);
+    }
+
+    // Handler errors are reported as failures.
+    client
+        .send(service_call_request(
+            1,
+            11,
+            "json",
+            json!({"a": u32::MAX, "b": 1}).to_string().as_bytes(),
+        ))
+        .await
+        .expect("Failed to send");
+    let msg = client
+        .next()
+        .await
+        .expect("No service call response")
+        .expect("Failed to parse response");
+    assert_eq!(
+        msg.into_text().expect("Expected utf8").as_str(),
+        json!({
+            "op": "serviceCallFailure",
+            "serviceId": 1,
+            "callId": 11,
+            "message": "overflow",
+        })
+        .to_string()
+    );
+
+    // Requests that cannot be decoded are reported as failures.
+    for service_id in [1, 2] {
+        client
+            .send(service_call_request(service_id, 12, "json", b"{}"))
+            .await
+            .expect("Failed to send");
+        let msg = client
+            .next()
+            .await
+            .expect("No service call response")
+            .expect("Failed to parse response");
+        let value: Value = serde_json::from_str(msg.to_text().expect("utf8")).expect("json");
+        assert_eq!(value["op"], "serviceCallFailure");
+        assert_eq!(value["serviceId"], service_id);
+        assert!(value["message"]
+            .as_str()
+            .expect("message")
+            .starts_with("Failed to decode request"));
+    }
+
+    server.stop().await;
+}
+
+#[traced_test]
+#[tokio::test]
+async fn test_service_timeouts_and_limits() {
+    let slow_svc = Service::builder("/slow", ServiceSchema::new("plain"))
+        .with_id(ServiceId::new(1))
+        .timeout(Duration::from_millis(50))
+        .async_handler_fn(|_, _| std::future::pending::<Result<Bytes, String>>());
+
+    let held = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let limited_svc = Service::builder("/limited", ServiceSchema::new("plain"))
+        .with_id(ServiceId::new(2))
+        .concurrency_limit(1)
+        .handler_fn({
+            let held = held.clone();
+            move |_, _, responder| held.lock().push(responder)
+        });
+
+    let server = create_server(ServerOptions {
+        services: [slow_svc, limited_svc]
+            .into_iter()
+            .map(|s| (s.name().to_string(), s))
+            .collect(),
+        supported_encodings: Some(HashSet::from(["raw".to_string()])),
+        service_calls_per_client: Some(2),
+        ..Default::default()
+    });
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut client = connect_client(addr).await;
+    let _ = client.next().await.expect("No serverInfo sent");
+    let _ = client.next().await.expect("No service advertisement sent");
+
+    let expect_failure = |msg: Message, service_id: u32, call_id: u32, message: &str| {
+        assert_eq!(
+            msg.into_text().expect("Expected utf8").as_str(),
+            json!({
+                "op": "serviceCallFailure",
+                "serviceId": service_id,
+                "callId": call_id,
+                "message": message,
+            })
+            .to_string()
+        );
+    };
+
+    // The first call to the limited service is held; the second exceeds the service's limit.
+    for call_id in [1, 2] {
+        client
+            .send(service_call_request(2, call_id, "raw", b"x"))
+            .await
+            .expect("Failed to send");
+    }
+    let msg = client.next().await.expect("No response").expect("Failed");
+    expect_failure(msg, 2, 2, "Too many requests");
+
+    // The first call to the slow service is outstanding; the second exceeds the client's limit.
+    for call_id in [3, 4] {
+        client
+            .send(service_call_request(1, call_id, "raw", b"x"))
+            .await
+            .expect("Failed to send");
+    }
+    let msg = client.next().await.expect("No response").expect("Failed");
+    expect_failure(msg, 1, 4, "Too many requests");
+
+    // The outstanding call to the slow service times out.
+    let msg = client.next().await.expect("No response").expect("Failed");
+    expect_failure(msg, 1, 3, "Service call timed out");
+
+    // The timed-out call released its slot, so the client can call the slow service again.
+    client
+        .send(service_call_request(1, 5, "raw", b"x"))
+        .await
+        .expect("Failed to send");
+    let msg = client.next().await.expect("No response").expect("Failed");
+    expect_failure(msg, 1, 5, "Service call timed out");
+
+    // Complete the held call.
+    let responder = held.lock().pop().expect("held responder");
+    responder.respond(Ok(Bytes::from_static(b"done")));
+    let msg = client.next().await.expect("No response").expect("Failed");
+    let mut buf = BytesMut::new();
+    buf.put_u8(3); // opcode
+    buf.put_u32_le(2); // service id
+    buf.put_u32_le(1); // call id
+    buf.put_u32_le(3); // encoding length
+    buf.put(b"raw".as_slice());
+    buf.put(b"done".as_slice());
+    assert_eq!(msg.into_data(), buf);
+
+    server.stop().await;
+}
+
+#[traced_test]
+#[tokio::test]
+async fn test_service_timeout_releases_leaked_responder() {
+    // The handler never responds, and never drops its responder.
+    let held = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let leaky_svc = Service::builder("/leaky", ServiceSchema::new("plain"))
+        .with_id(ServiceId::new(1))
+        .timeout(Duration::from_millis(50))
+        .concurrency_limit(1)
+        .handler_fn({
+            let held = held.clone();
+            move |_, _, responder| held.lock().push(responder)
+        });
+
+    let server = create_server(ServerOptions {
+        services: HashMap::from([(leaky_svc.name().to_string(), leaky_svc)]),
+        supported_encodings: Some(HashSet::from(["raw".to_string()])),
+        service_calls_per_client: Some(1),
+        ..Default::default()
+    });
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut client = connect_client(addr).await;
+    let _ = client.next().await.expect("No serverInfo sent");
+    let _ = client.next().await.expect("No service advertisement sent");
+
+    // Each call times out, rather than being rejected because an earlier call still holds the
+    // per-client and per-service slots.
+    for call_id in [1, 2] {
+        client
+            .send(service_call_request(1, call_id, "raw", b"x"))
+            .await
+            .expect("Failed to send");
+        let msg = client.next().await.expect("No response").expect("Failed");
+        assert_eq!(
+            msg.into_text().expect("Expected utf8").as_str(),
+            json!({
+                "op": "serviceCallFailure",
+                "serviceId": 1,
+                "callId": call_id,
+                "message": "Service call timed out",
+            })
+            .to_string()
+        );
+    }
+    assert_eq!(held.lock().len(), 2);
+
+    server.stop().await;
+}
+
+#[traced_test]
+#[tokio::test]
+async fn test_service_call_cancelled_on_disconnect() {
+    let (started_tx, started_rx) = tokio::sync::oneshot::channel();
+    let started_tx = parking_lot::Mutex::new(Some(started_tx));
+    let (dropped_tx, dropped_rx) = tokio::sync::oneshot::channel::<()>();
+    let dropped_tx = parking_lot::Mutex::new(Some(dropped_tx));
+    let svc = Service::builder("/forever", ServiceSchema::new("plain"))
+        .with_id(ServiceId::new(1))
+        .async_handler_fn(move |_, _| {
+            let started_tx = started_tx.lock().take();
+            let dropped_tx = dropped_tx.lock().take();
+            async move {
+                // The sender is dropped when the future is dropped.
+                let _dropped_tx = dropped_tx;
+                if let Some(tx) = started_tx {
+                    let _ = tx.send(());
+                }
+                std::future::pending::<Result<Bytes, String>>().await
+            }
+        });
+
+    let server = create_server(ServerOptions {
+        services: HashMap::from([(svc.name().to_string(), svc)]),
+        supported_encodings: Some(HashSet::from(["raw".to_string()])),
+        ..Default::default()
+    });
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut client = connect_client(addr).await;
+    let _ = client.next().await.expect("No serverInfo sent");
+    let _ = client.next().await.expect("No service advertisement sent");
+    client
+        .send(service_call_request(1, 1, "raw", b"x"))
+        .await
+        .expect("Failed to send");
+    tokio::time::timeout(Duration::from_secs(1), started_rx)
+        .await
+        .expect("Handler not started")
+        .expect("Handler not started");
+
+    client.close(None).await.expect("Failed to close");
+    tokio::time::timeout(Duration::from_secs(1), dropped_rx)
+        .await
+        .expect("Service call not cancelled")
+        .expect_err("Future should have been dropped");
+
+    server.stop().await;
+}
+
+/// Receive the next message from the client WS stream, and parse it as JSON
+async fn next_json(
+    client: &mut tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+) -> Value {
+    let msg = client.next().await.expect("No message").expect("Failed");
+    serde_json::from_str(msg.to_text().expect("utf8")).expect("json")
+}
+
 /// Connect to a server, ensuring the protocol header is set, and return the client WS stream
 pub async fn connect_client(
     addr: String,
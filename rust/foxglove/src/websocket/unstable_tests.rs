@@ -1,7 +1,10 @@
 use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
 
 use super::tests::connect_client;
 use super::{create_server, protocol, Capability, ServerOptions};
+use crate::clock::{ContextClock, ManualClock};
 use bytes::Buf;
 use futures_util::StreamExt;
 use tokio_tungstenite::tungstenite::Message;
@@ -32,3 +35,35 @@ async fn test_broadcast_time() {
     assert_eq!(buf.get_u8(), protocol::server::BinaryOpcode::TimeData as u8);
     assert_eq!(buf.get_u64_le(), 42);
 }
+
+#[tokio::test]
+async fn test_broadcast_clock() {
+    let clock = Arc::new(ContextClock::default());
+    clock.set(ManualClock::new(42));
+    let server = create_server(ServerOptions {
+        clock_broadcast: Some((clock, Duration::from_millis(10))),
+        ..Default::default()
+    });
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    let mut ws_client = connect_client(addr).await;
+    let msg = ws_client.next().await.expect("serverInfo").unwrap();
+    let server_info: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
+    let capabilities = server_info["capabilities"].as_array().unwrap();
+    assert!(capabilities.contains(&"time".into()));
+
+    let msg = ws_client
+        .next()
+        .await
+        .expect("no message received")
+        .expect("failed to parse message");
+    let Message::Binary(mut buf) = msg else {
+        panic!("unexpected message type");
+    };
+    assert_eq!(buf.get_u8(), protocol::server::BinaryOpcode::TimeData as u8);
+    assert_eq!(buf.get_u64_le(), 42);
+    server.stop().await;
+}
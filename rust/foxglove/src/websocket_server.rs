@@ -2,9 +2,13 @@
 
 use std::fmt::Debug;
 use std::sync::Arc;
+#[cfg(feature = "unstable")]
+use std::time::Duration;
 
 use crate::websocket::service::{Service, ServiceId};
-use crate::websocket::{create_server, Capability, Parameter, Server, ServerOptions, Status};
+use crate::websocket::{
+    create_server, Capability, Parameter, ParameterStore, Server, ServerOptions, Status,
+};
 use crate::{get_runtime_handle, FoxgloveError, LogContext, LogSink};
 use tokio::runtime::Handle;
 use tracing::warn;
@@ -97,6 +101,52 @@ impl WebSocketServer {
         self
     }
 
+    /// Forward messages published by clients to the log context.
+    ///
+    /// When enabled, each channel advertised by a client is registered as a
+    /// [`Channel`](crate::Channel) with the client's topic, message encoding, and schema, and
+    /// messages published by the client are logged to it. This allows client-published messages
+    /// to be recorded by other sinks, such as an [`McapWriter`](crate::McapWriter), and relayed to
+    /// other connected clients. The [`ServerListener`](crate::websocket::ServerListener) is still
+    /// notified of each message.
+    ///
+    /// The channel is removed when the client unadvertises it or disconnects. Client channels
+    /// whose topic is already in use by another channel are not forwarded.
+    ///
+    /// Automatically adds [`Capability::ClientPublish`] to the set of advertised capabilities.
+    ///
+    /// By default, client-published messages are not forwarded.
+    pub fn forward_client_channels(mut self, forward: bool) -> Self {
+        self.options.forward_client_channels = forward;
+        self
+    }
+
+    /// Set the maximum number of concurrent service calls per client.
+    ///
+    /// Calls in excess of this limit are rejected with a service call failure. Per-service limits
+    /// can be configured with [`ServiceBuilder::concurrency_limit`][limit].
+    ///
+    /// By default, each client may have up to 32 outstanding service calls.
+    ///
+    /// [limit]: crate::websocket::service::ServiceBuilder::concurrency_limit
+    pub fn service_calls_per_client(mut self, limit: usize) -> Self {
+        self.options.service_calls_per_client = Some(limit);
+        self
+    }
+
+    /// Configure a managed parameter store.
+    ///
+    /// The server answers client requests to get, set, and subscribe to parameters from the store,
+    /// rather than invoking the corresponding [`ServerListener`](crate::websocket::ServerListener)
+    /// callbacks. Changes made with [`ParameterStore::set`] are published to subscribed clients.
+    ///
+    /// Automatically adds [`Capability::Parameters`] and [`Capability::ParametersSubscribe`] to
+    /// the set of advertised capabilities.
+    pub fn parameter_store(mut self, store: ParameterStore) -> Self {
+        self.options.parameter_store = Some(store);
+        self
+    }
+
     /// Configure the set of supported encodings for client requests.
     ///
     /// This is used for both client-side publishing as well as service call request/responses.
@@ -131,6 +181,20 @@ impl WebSocketServer {
         self
     }
 
+    /// Periodically broadcast the time of the global log context's clock to clients.
+    ///
+    /// This allows a simulator which drives the clock, e.g. with a
+    /// [`ManualClock`](crate::clock::ManualClock), to drive the playback time in the app as well
+    /// as the log time of messages.
+    ///
+    /// Automatically adds [`Capability::Time`] to the set of advertised capabilities.
+    #[doc(hidden)]
+    #[cfg(feature = "unstable")]
+    pub fn broadcast_clock(mut self, interval: Duration) -> Self {
+        self.options.clock_broadcast = Some((LogContext::global().clock.clone(), interval));
+        self
+    }
+
     /// Starts the websocket server.
     ///
     /// Returns a handle that can optionally be used to gracefully shutdown the server. The caller